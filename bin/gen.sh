@@ -11,6 +11,8 @@ if [ ! -x "$(which flatc)" ]; then
   exit 1
 fi
 
+BONSOL_REGENERATE_SCHEMA=1 cargo test -p bonsol-schema --test generated -- --ignored
+
 flatc --ts --gen-object-api --gen-mutable --reflect-names -o schemas-ts schemas/flatbuffers/*.fbs
 sed -i.bak '
   /mutate_writable(value:boolean):boolean {/,/}/ {
@@ -39,23 +39,6 @@ Linux)
         fi
     fi
 
-    # Install flatc if not present
-    if ! command -v flatc >/dev/null 2>&1; then
-        echo "Installing flatc 24.3.25..."
-        CURRENT_DIR=$(pwd)
-        TEMP_DIR=$(mktemp -d)
-        cd "$TEMP_DIR"
-        git clone https://github.com/google/flatbuffers.git
-        cd flatbuffers
-        git checkout v24.3.25
-        cmake -G "Unix Makefiles" -DCMAKE_BUILD_TYPE=Release
-        make
-        sudo make install
-        cd "$CURRENT_DIR"
-        rm -rf "$TEMP_DIR"
-        echo "flatc installation complete"
-    fi
-
     # check if nvidia-smi exists and nvcc is available
     if command -v nvidia-smi >/dev/null 2>&1 && command -v nvcc >/dev/null 2>&1; then
         echo "installing with cuda support"
@@ -67,34 +50,6 @@ Linux)
     fi
     ;;
 Darwin)
-    # Install flatc if not present
-    if ! command -v flatc >/dev/null 2>&1; then
-        echo "Installing flatc 24.3.25..."
-        brew update
-        brew install cmake
-        cmake --version   # Should show version 3.28.3 or later
-        brew install make gcc
-        make --version   # Should show version 3.81 or later
-        # Create a temporary directory for building
-        cd /tmp
-        # Clone the FlatBuffers repository
-        git clone https://github.com/google/flatbuffers.git
-        # Enter the repository directory
-        cd flatbuffers
-        # Checkout the specific version
-        git checkout v24.3.25
-        # Build FlatBuffers
-        cmake -G "Unix Makefiles" -DCMAKE_BUILD_TYPE=Release
-        make
-        # Move flatc compiler to /usr/local/bin
-        sudo mv flatc /usr/local/bin/
-        # Clean up
-        cd ..
-        rm -rf flatbuffers
-        # Verify the installation
-        flatc --version   # Should show version 24.3.25
-        echo "flatc installation complete"
-    fi
     cargo install bonsol-cli --git https://github.com/bonsol-collective/bonsol --features mac --locked --force
     ;;
 *)
@@ -232,7 +232,7 @@ async fn bix_thread(
                 for (pix, accounts, last_known_block) in parsed_bixes {
                     let (execution_id, image_id, max_bh, new_status) = match pix.ix_type() {
                         ChannelInstructionIxType::ExecuteV1 => {
-                            let ix = some_or_continue!(pix.execute_v1_nested_flatbuffer());
+                            let ix = some_or_continue!(pix.execute_v1_verified());
                             (
                                 some_or_continue!(ix.execution_id()),
                                 ix.image_id().map(str::to_owned),
@@ -241,7 +241,7 @@ async fn bix_thread(
                             )
                         }
                         ChannelInstructionIxType::ClaimV1 => {
-                            let ix = some_or_continue!(pix.claim_v1_nested_flatbuffer());
+                            let ix = some_or_continue!(pix.claim_v1_verified());
                             (
                                 some_or_continue!(ix.execution_id()),
                                 None,
@@ -250,7 +250,7 @@ async fn bix_thread(
                             )
                         }
                         ChannelInstructionIxType::StatusV1 => {
-                            let ix = some_or_continue!(pix.status_v1_nested_flatbuffer());
+                            let ix = some_or_continue!(pix.status_v1_verified());
                             (
                                 some_or_continue!(ix.execution_id()),
                                 None,
@@ -5,10 +5,7 @@ use bincode::{Decode, Encode};
 use futures::{Sink, SinkExt, Stream, StreamExt};
 use rand::RngCore;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use solana_sdk::{
-    signature::{Keypair, Signature},
-    signer::Signer,
-};
+use solana_sdk::{signature::Signature, signer::Signer};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
 
@@ -43,13 +40,13 @@ impl Challenge {
         Self { id, nonce }
     }
 
-    pub fn sign(&self, keypair: &Keypair) -> ChallengeResponse {
-        let signed = keypair.sign_message(&self.nonce);
-        ChallengeResponse {
-            public_key: keypair.pubkey().to_bytes(),
+    pub fn sign(&self, signer: &dyn Signer) -> Result<ChallengeResponse> {
+        let signed = signer.try_sign_message(&self.nonce)?;
+        Ok(ChallengeResponse {
+            public_key: signer.try_pubkey()?.to_bytes(),
             challenge_id: self.id,
             signature: signed.as_array().to_owned(),
-        }
+        })
     }
 
     pub fn verify(&self, response: &ChallengeResponse) -> bool {
@@ -182,9 +182,20 @@ pub enum Command {
     },
 
     Execute {
-        #[arg(short = 'f', long)]
+        #[arg(
+            short = 'f',
+            long,
+            help = "The execution request in the bonsol-schema JSON form"
+        )]
         execution_request_file: Option<String>,
 
+        #[arg(
+            long,
+            conflicts_with = "execution_request_file",
+            help = "Deprecated: convert and send a request file in the format used before the bonsol-schema JSON form"
+        )]
+        legacy_request_file: Option<String>,
+
         // overridable settings
         #[arg(short = 'p', long)]
         program_id: Option<String>,
@@ -253,4 +264,30 @@ pub enum Command {
         #[arg(help = "Filter logs by execution id", short = 'e', long)]
         execution_id: Option<String>,
     },
+
+    #[command(about = "Decode the bonsol instructions of a transaction or raw instruction data")]
+    Inspect {
+        #[arg(
+            help = "The signature of a transaction to fetch and decode",
+            required_unless_present = "data"
+        )]
+        signature: Option<String>,
+
+        #[arg(
+            help = "Raw instruction data, hex or base58, to decode instead of a transaction",
+            short = 'd',
+            long,
+            conflicts_with = "signature"
+        )]
+        data: Option<String>,
+
+        #[arg(
+            help = "The comma separated instruction accounts that go with --data",
+            short = 'a',
+            long,
+            value_delimiter = ',',
+            requires = "data"
+        )]
+        accounts: Vec<String>,
+    },
 }
@@ -5,14 +5,12 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use bonsol_prover::input_resolver::{ProgramInput, ResolvedInput};
-use bonsol_sdk::instructions::CallbackConfig;
 use bonsol_sdk::{InputT, InputType, ProgramInputType};
 use clap::Args;
 use rand::distributions::Alphanumeric;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use solana_rpc_client::nonblocking::rpc_client;
-use solana_sdk::instruction::AccountMeta;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::error::{BonsolCliError, ParseConfigError};
@@ -78,67 +76,6 @@ impl FromStr for CliInputType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ExecutionRequestFile {
-    pub image_id: Option<String>,
-    pub execution_config: CliExecutionConfig,
-    pub execution_id: Option<String>,
-    pub tip: Option<u64>,
-    pub expiry: Option<u64>,
-    pub inputs: Option<Vec<CliInput>>,
-    pub callback_config: Option<CliCallbackConfig>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CliExecutionConfig {
-    pub verify_input_hash: Option<bool>,
-    pub input_hash: Option<String>,
-    pub forward_output: Option<bool>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct CliCallbackConfig {
-    #[serde(with = "bonsol_sdk::instructions::serde_helpers::optpubkey")]
-    pub program_id: Option<Pubkey>,
-    pub instruction_prefix: Option<Vec<u8>>,
-    pub extra_accounts: Option<Vec<CliAccountMeta>>,
-}
-
-impl From<CliCallbackConfig> for CallbackConfig {
-    fn from(val: CliCallbackConfig) -> Self {
-        CallbackConfig {
-            program_id: val.program_id.unwrap_or_default(),
-            instruction_prefix: val.instruction_prefix.unwrap_or_default(),
-            extra_accounts: val
-                .extra_accounts
-                .map(|v| v.into_iter().map(|a| a.into()).collect())
-                .unwrap_or_default(),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct CliAccountMeta {
-    #[serde(default, with = "bonsol_sdk::instructions::serde_helpers::pubkey")]
-    pub pubkey: Pubkey,
-    pub is_signer: bool,
-    pub is_writable: bool,
-}
-
-impl From<CliAccountMeta> for AccountMeta {
-    fn from(val: CliAccountMeta) -> Self {
-        AccountMeta {
-            pubkey: val.pubkey,
-            is_signer: val.is_signer,
-            is_writable: val.is_writable,
-        }
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InputFile {
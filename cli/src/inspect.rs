@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use bonsol_interface::decoder::{
+    decode_instruction_data, decode_instruction_with_keys, DecodedInstruction,
+};
+use serde::Serialize;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_sdk::bs58;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{UiInstruction, UiLoadedAddresses, UiTransactionEncoding};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InspectedInstruction {
+    /// Position of the instruction in the transaction, inner instructions also carry the index of
+    /// the top level instruction that invoked them.
+    index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inner_index: Option<usize>,
+    #[serde(flatten)]
+    decoded: DecodedInstruction,
+}
+
+pub async fn inspect(
+    rpc_url: Option<String>,
+    signature: Option<String>,
+    data: Option<String>,
+    accounts: Vec<String>,
+) -> Result<()> {
+    let output = match (signature, data) {
+        (_, Some(data)) => {
+            let data = parse_instruction_data(&data)?;
+            let accounts = accounts
+                .iter()
+                .map(|a| Pubkey::from_str(a).with_context(|| format!("invalid account {a}")))
+                .collect::<Result<Vec<_>>>()?;
+            if accounts.is_empty() {
+                serde_json::to_string_pretty(&decode_instruction_data(&data)?)?
+            } else {
+                serde_json::to_string_pretty(&decode_instruction_with_keys(&data, &accounts)?)?
+            }
+        }
+        (Some(signature), None) => {
+            let rpc_url =
+                rpc_url.ok_or_else(|| anyhow!("an rpc url is needed to fetch {signature}"))?;
+            let signature = Signature::from_str(&signature).context("invalid signature")?;
+            let instructions = inspect_transaction(rpc_url, &signature).await?;
+            if instructions.is_empty() {
+                return Err(anyhow!(
+                    "transaction {signature} has no bonsol instructions"
+                ));
+            }
+            serde_json::to_string_pretty(&instructions)?
+        }
+        (None, None) => return Err(anyhow!("either a signature or --data is required")),
+    };
+    println!("{output}");
+    Ok(())
+}
+
+/// Instruction data as explorers show it, hex with an optional `0x` prefix, or base58 as rpc
+/// nodes return it in json encoded transactions.
+fn parse_instruction_data(data: &str) -> Result<Vec<u8>> {
+    let data = data.trim();
+    if let Some(hex) = data.strip_prefix("0x") {
+        return hex::decode(hex).context("invalid hex instruction data");
+    }
+    hex::decode(data)
+        .or_else(|_| bs58::decode(data).into_vec())
+        .context("instruction data is neither hex nor base58")
+}
+
+async fn inspect_transaction(
+    rpc_url: String,
+    signature: &Signature,
+) -> Result<Vec<InspectedInstruction>> {
+    let client = RpcClient::new(rpc_url);
+    let tx = client
+        .get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .await
+        .with_context(|| format!("failed to fetch transaction {signature}"))?;
+    let meta = tx.transaction.meta;
+    let vtx = tx
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| anyhow!("failed to decode transaction {signature}"))?;
+    let message = &vtx.message;
+
+    let loaded = match meta.as_ref().map(|m| &m.loaded_addresses) {
+        Some(OptionSerializer::Some(loaded)) => loaded.clone(),
+        _ => UiLoadedAddresses::default(),
+    };
+    let keys = account_keys(message, &loaded)?;
+
+    let mut res = vec![];
+    for (index, ix) in message.instructions().iter().enumerate() {
+        if keys.get(ix.program_id_index as usize).map(|k| k.0) != Some(bonsol_interface::ID) {
+            continue;
+        }
+        res.push(InspectedInstruction {
+            index,
+            inner_index: None,
+            decoded: decode_with_flags(&ix.data, &ix.accounts, &keys)?,
+        });
+    }
+    if let Some(OptionSerializer::Some(groups)) = meta.map(|m| m.inner_instructions) {
+        for group in groups {
+            for (inner_index, ix) in group.instructions.into_iter().enumerate() {
+                let UiInstruction::Compiled(ix) = ix else {
+                    continue;
+                };
+                if keys.get(ix.program_id_index as usize).map(|k| k.0) != Some(bonsol_interface::ID)
+                {
+                    continue;
+                }
+                let data = bs58::decode(&ix.data)
+                    .into_vec()
+                    .context("invalid base58 inner instruction data")?;
+                res.push(InspectedInstruction {
+                    index: group.index as usize,
+                    inner_index: Some(inner_index),
+                    decoded: decode_with_flags(&data, &ix.accounts, &keys)?,
+                });
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Every account the transaction can reference with its signer and writable flags, static keys
+/// first and then the lookup table addresses, writable before readonly.
+fn account_keys(
+    message: &VersionedMessage,
+    loaded: &UiLoadedAddresses,
+) -> Result<Vec<(Pubkey, bool, bool)>> {
+    let header = message.header();
+    let static_keys = message.static_account_keys();
+    let signers = header.num_required_signatures as usize;
+    let writable_signers = signers - header.num_readonly_signed_accounts as usize;
+    let writable_unsigned = static_keys.len() - header.num_readonly_unsigned_accounts as usize;
+    let mut keys: Vec<(Pubkey, bool, bool)> = static_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let writable = if i < signers {
+                i < writable_signers
+            } else {
+                i < writable_unsigned
+            };
+            (*key, i < signers, writable)
+        })
+        .collect();
+    for (addresses, writable) in [(&loaded.writable, true), (&loaded.readonly, false)] {
+        for address in addresses {
+            let key = Pubkey::from_str(address)
+                .with_context(|| format!("invalid loaded address {address}"))?;
+            keys.push((key, false, writable));
+        }
+    }
+    Ok(keys)
+}
+
+fn decode_with_flags(
+    data: &[u8],
+    indices: &[u8],
+    keys: &[(Pubkey, bool, bool)],
+) -> Result<DecodedInstruction> {
+    let accounts = indices
+        .iter()
+        .map(|i| {
+            keys.get(*i as usize)
+                .copied()
+                .ok_or_else(|| anyhow!("account index {i} is out of range"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let pubkeys: Vec<Pubkey> = accounts.iter().map(|a| a.0).collect();
+    let mut decoded = decode_instruction_with_keys(data, &pubkeys)?;
+    for (account, (_, is_signer, is_writable)) in decoded.accounts.iter_mut().zip(accounts) {
+        account.is_signer = Some(is_signer);
+        account.is_writable = Some(is_writable);
+    }
+    Ok(decoded)
+}
@@ -0,0 +1,198 @@
+//! The request file format `bonsol execute` read before it moved to the bonsol-schema JSON form
+//! of `ExecutionRequestV1T`. Files in this format are only read through `--legacy-request-file`,
+//! which converts them to the schema form, and support for them will be removed.
+
+use anyhow::Result;
+use bonsol_interface::bonsol_schema::AccountT;
+use bonsol_sdk::{ExecutionRequestV1T, InputT, InputType, ProofSystem};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{execute_transform_cli_inputs, CliInput};
+
+pub const DEPRECATION_WARNING: &str = "warning: legacy request files are deprecated and will not \
+    be read by a future release, write the request in the bonsol-schema JSON form and pass it \
+    with --execution-request-file";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionRequestFile {
+    pub image_id: Option<String>,
+    pub execution_config: CliExecutionConfig,
+    pub execution_id: Option<String>,
+    pub tip: Option<u64>,
+    pub expiry: Option<u64>,
+    pub inputs: Option<Vec<CliInput>>,
+    pub callback_config: Option<CliCallbackConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliExecutionConfig {
+    pub verify_input_hash: Option<bool>,
+    pub input_hash: Option<String>,
+    pub forward_output: Option<bool>,
+    pub proof_system: Option<String>,
+    pub compact_storage: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CliCallbackConfig {
+    #[serde(with = "bonsol_sdk::instructions::serde_helpers::optpubkey")]
+    pub program_id: Option<Pubkey>,
+    pub instruction_prefix: Option<Vec<u8>>,
+    pub extra_accounts: Option<Vec<CliAccountMeta>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CliAccountMeta {
+    #[serde(default, with = "bonsol_sdk::instructions::serde_helpers::pubkey")]
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ExecutionRequestFile {
+    /// Converts the file to the request it used to send, with `inputs` standing in when the file
+    /// has none. The expiry of a legacy file is relative to the current slot so it is returned
+    /// next to the request instead of being set as its `max_block_height`.
+    pub fn into_request(self, inputs: Vec<CliInput>) -> Result<(ExecutionRequestV1T, Option<u64>)> {
+        let config = self.execution_config;
+        let mut request = ExecutionRequestV1T::default();
+        request.image_id = self.image_id;
+        request.execution_id = self.execution_id;
+        request.tip = self.tip.unwrap_or_default();
+        request.input = Some(concatenate_public_data(execute_transform_cli_inputs(
+            self.inputs.unwrap_or(inputs),
+        )?));
+        request.input_digest = config
+            .input_hash
+            .map(|digest| {
+                hex::decode(digest)
+                    .map_err(|_| anyhow::anyhow!("Invalid input hash, must be hex encoded"))
+            })
+            .transpose()?;
+        request.verify_input_hash = config.verify_input_hash.unwrap_or(false);
+        request.forward_output = config.forward_output.unwrap_or(false);
+        request.compact_storage = config.compact_storage.unwrap_or(false);
+        if let Some(name) = config.proof_system {
+            request.proof_system = ProofSystem::ENUM_VALUES
+                .iter()
+                .find(|ps| ps.variant_name() == Some(name.as_str()))
+                .copied()
+                .ok_or(anyhow::anyhow!("Unknown proof system {}", name))?;
+        }
+        if let Some(callback) = self.callback_config {
+            request.callback_program_id =
+                Some(callback.program_id.unwrap_or_default().to_bytes().to_vec());
+            request.callback_instruction_prefix = callback.instruction_prefix;
+            request.callback_extra_accounts = callback.extra_accounts.map(|accounts| {
+                accounts
+                    .into_iter()
+                    .map(|a| AccountT {
+                        pubkey: a.pubkey.to_bytes(),
+                        writable: a.is_writable as u8,
+                    })
+                    .collect()
+            });
+        }
+        Ok((request, self.expiry))
+    }
+}
+
+// legacy files with only public data inputs were sent as a single input of their concatenation
+fn concatenate_public_data(inputs: Vec<InputT>) -> Vec<InputT> {
+    if inputs.is_empty() || inputs.iter().any(|i| i.input_type != InputType::PublicData) {
+        return inputs;
+    }
+    let data: Vec<u8> = inputs
+        .iter()
+        .flat_map(|i| i.data.iter().flatten())
+        .copied()
+        .collect();
+    if data.is_empty() {
+        return inputs;
+    }
+    vec![InputT::public(data)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_request() {
+        let file: ExecutionRequestFile = serde_json::from_str(
+            r#"{
+                "imageId": "image",
+                "executionConfig": {
+                    "verifyInputHash": true,
+                    "inputHash": "0102",
+                    "proofSystem": "Sp1Groth16"
+                },
+                "tip": 12,
+                "expiry": 1000,
+                "inputs": [
+                    { "inputType": "PublicData", "data": "0x0a0b" },
+                    { "inputType": "PublicData", "data": "0x0c" }
+                ],
+                "callbackConfig": {
+                    "programId": "11111111111111111111111111111112",
+                    "instructionPrefix": [1, 2],
+                    "extraAccounts": [
+                        {
+                            "pubkey": "11111111111111111111111111111113",
+                            "isSigner": false,
+                            "isWritable": true
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        let (request, expiry) = file.into_request(vec![]).unwrap();
+        assert_eq!(expiry, Some(1000));
+        assert_eq!(request.max_block_height, 0);
+        assert_eq!(request.image_id.as_deref(), Some("image"));
+        assert_eq!(request.execution_id, None);
+        assert_eq!(request.tip, 12);
+        assert_eq!(request.input, Some(vec![InputT::public(vec![10, 11, 12])]));
+        assert_eq!(request.input_digest, Some(vec![1, 2]));
+        assert!(request.verify_input_hash);
+        assert_eq!(request.proof_system, ProofSystem::Sp1Groth16);
+        assert_eq!(request.callback_instruction_prefix, Some(vec![1, 2]));
+        let accounts = request.callback_extra_accounts.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].writable, 1);
+    }
+
+    #[test]
+    fn test_into_request_defaults() {
+        let file: ExecutionRequestFile =
+            serde_json::from_str(r#"{ "executionConfig": {} }"#).unwrap();
+        let inputs = vec![
+            CliInput {
+                input_type: "PublicData".to_string(),
+                data: "0x01".to_string(),
+            },
+            CliInput {
+                input_type: "Private".to_string(),
+                data: "https://example.com/input".to_string(),
+            },
+        ];
+        let (request, expiry) = file.into_request(inputs).unwrap();
+        assert_eq!(expiry, None);
+        assert!(!request.verify_input_hash);
+        assert_eq!(request.proof_system, ProofSystem::Risc0Groth16);
+        assert_eq!(request.input.unwrap().len(), 2);
+        assert!(request.callback_program_id.is_none());
+
+        let file: ExecutionRequestFile = serde_json::from_str(
+            r#"{ "executionConfig": { "proofSystem": "Other" }, "inputs": [] }"#,
+        )
+        .unwrap();
+        assert!(file.into_request(vec![]).is_err());
+    }
+}
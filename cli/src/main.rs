@@ -19,6 +19,8 @@ mod deploy;
 mod estimate;
 mod execute;
 mod init;
+mod inspect;
+mod legacy;
 mod logs;
 mod prove;
 mod read_receipt;
@@ -95,6 +97,7 @@ async fn main() -> anyhow::Result<()> {
         }
         Command::Execute {
             execution_request_file,
+            legacy_request_file,
             program_id,
             execution_id,
             expiry,
@@ -121,6 +124,7 @@ async fn main() -> anyhow::Result<()> {
                 rpc_url,
                 &keypair,
                 execution_request_file,
+                legacy_request_file,
                 program_id,
                 execution_id,
                 timeout,
@@ -170,5 +174,16 @@ async fn main() -> anyhow::Result<()> {
             image_id,
             execution_id,
         } => logs::logs(bonfire_url, image_id, execution_id).await,
+        Command::Inspect {
+            signature,
+            data,
+            accounts,
+        } => {
+            let rpc_url = match signature {
+                Some(_) => Some(load_solana_config(config, rpc_url, keypair)?.0),
+                None => None,
+            };
+            inspect::inspect(rpc_url, signature, data, accounts).await
+        }
     }
 }
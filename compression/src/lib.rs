@@ -0,0 +1,192 @@
+//! Wraps a risc0 succinct receipt in Groth16 with the stark compression tools, `stark_verify`
+//! computes the witness of the stark verifier circuit and `rapidsnark` proves it. Shared by the
+//! node and the tests that check the result against the bonsol program.
+
+use {
+    anyhow::{anyhow, Context, Result},
+    iop::*,
+    num_bigint::BigUint,
+    num_traits::Num,
+    risc0_core::field::baby_bear::BabyBearElem,
+    risc0_groth16::{ProofJson, Seal},
+    risc0_zkp::core::{
+        digest::{Digest, DIGEST_WORDS},
+        hash::poseidon_254::digest_to_fr,
+    },
+    risc0_zkvm::{sha::Digestible, ExitCode, MaybePruned, ReceiptClaim, SuccinctReceipt},
+    std::{env::consts::ARCH, io::Cursor, io::Write, path::Path},
+    tempfile::tempdir,
+    thiserror::Error,
+    tokio::{
+        fs::File,
+        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+        process::Command,
+    },
+    tracing::{error, info},
+};
+
+#[derive(Debug, Error)]
+pub enum CompressionError {
+    #[error("Error with proof compression")]
+    ProofCompressionError,
+}
+
+static TOOLS_ENTRIES: [&str; 4] = [
+    "stark_verify",
+    "stark_verify.dat",
+    "stark_verify_final.zkey",
+    "rapidsnark",
+];
+
+pub const fn check_x86_64arch() -> bool {
+    cfg!(target_arch = "x86_64")
+}
+
+pub fn check_stark_compression_tools_path(path: &str) -> Result<()> {
+    let tools_path = Path::new(path);
+    for entry in TOOLS_ENTRIES.iter() {
+        let entry_path = tools_path.join(entry);
+        if !entry_path.exists() {
+            return Err(anyhow!(
+                "Error: Stark compression tools not found at {}",
+                entry_path.to_string_lossy()
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub async fn async_to_json<
+    R: AsyncRead + std::marker::Unpin,
+    W: AsyncWrite + std::marker::Unpin,
+>(
+    mut reader: R,
+    mut writer: W,
+) -> Result<()> {
+    let mut iop = vec![0u32; K_SEAL_WORDS];
+    reader
+        .read_exact(bytemuck::cast_slice_mut(&mut iop))
+        .await?;
+    let mut mem = Vec::new();
+    writeln!(mem, "{{\n  \"iop\": [")?;
+
+    let mut pos = 0;
+    for seal_type in K_SEAL_TYPES.iter().take(K_SEAL_ELEMS) {
+        if pos != 0 {
+            writeln!(mem, ",")?;
+        }
+        match seal_type {
+            IopType::Fp => {
+                let value = BabyBearElem::new_raw(iop[pos]).as_u32();
+                pos += 1;
+                writeln!(mem, "    \"{value}\"")?;
+            }
+            _ => {
+                let digest = Digest::try_from(&iop[pos..pos + DIGEST_WORDS])?;
+                let value = digest_to_decimal(&digest)?;
+                pos += 8;
+                writeln!(mem, "    \"{value}\"")?;
+            }
+        }
+    }
+    write!(mem, "  ]\n}}")?;
+    writer.write_all(mem.as_slice()).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+fn digest_to_decimal(digest: &Digest) -> Result<String> {
+    to_decimal(&format!("{:?}", digest_to_fr(digest))).context("digest_to_decimal failed")
+}
+
+fn to_decimal(s: &str) -> Option<String> {
+    s.strip_prefix("Fr(0x")
+        .and_then(|s| s.strip_suffix(')'))
+        .and_then(|stripped| BigUint::from_str_radix(stripped, 16).ok())
+        .map(|n| n.to_str_radix(10))
+}
+
+pub struct CompressedReceipt {
+    pub execution_digest: Vec<u8>,
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
+    pub proof: Vec<u8>,
+}
+
+/// Compresses the proof to be sent to the blockchain
+/// This is a temporary solution until the wasm groth16 prover or a rust impl is working
+pub async fn risc0_compress_proof(
+    tools_path: &str,
+    succinct_receipt: SuccinctReceipt<ReceiptClaim>,
+) -> Result<CompressedReceipt> {
+    let sealbytes = succinct_receipt.get_seal_bytes();
+    if !(ARCH == "x86_64" || ARCH == "x86") {
+        panic!("X86 only");
+    }
+    let tmp = tempdir()?;
+    let prove_dir = tmp.path();
+    let root_path = Path::new(tools_path);
+    let mut cursor = Cursor::new(&sealbytes);
+    let inputs = prove_dir.join("input.json");
+    let witness = prove_dir.join("out.wtns");
+    let input_file = File::create(&inputs).await?;
+    async_to_json(&mut cursor, input_file).await?;
+    let zkey = root_path.join("stark_verify_final.zkey");
+    let proof_out = prove_dir.join("proof.json");
+    let public = prove_dir.join("public.json");
+    let status = Command::new(root_path.join("stark_verify"))
+        .arg(inputs.clone())
+        .arg(witness.clone())
+        .output()
+        .await?;
+    if !status.status.success() {
+        info!("witness {:?}", status);
+        return Err(CompressionError::ProofCompressionError.into());
+    }
+    let snark_status = Command::new(root_path.join("rapidsnark"))
+        .arg(zkey)
+        .arg(witness)
+        .arg(proof_out.clone())
+        .arg(public)
+        .output()
+        .await?;
+    if !snark_status.status.success() {
+        info!("snark {:?}", snark_status);
+        return Err(CompressionError::ProofCompressionError.into());
+    }
+
+    let mut proof_fd = File::open(proof_out).await?;
+    let mt = proof_fd.metadata().await?;
+    let mut bytes = Vec::with_capacity(mt.len() as usize);
+    proof_fd.read_to_end(&mut bytes).await?;
+    let proof: ProofJson = serde_json::from_slice(&bytes)?;
+    let seal: Seal = proof.try_into()?;
+    let claim = succinct_receipt.claim;
+    if let MaybePruned::Value(rc) = claim {
+        let (system, user) = match rc.exit_code {
+            ExitCode::Halted(user_exit) => (0, user_exit),
+            ExitCode::Paused(user_exit) => (1, user_exit),
+            ExitCode::SystemSplit => (2, 0),
+            ExitCode::SessionLimit => (2, 2),
+            _ => {
+                // Log a warning or error for debugging
+                error!(
+                    "Encountered unexpected ExitCode variant: {:?}",
+                    rc.exit_code
+                );
+                // Return an error indicating that this specific variant is not supported by this function
+                return Err(anyhow!(
+                    "Unsupported ExitCode variant encountered during compression"
+                ));
+            }
+        };
+        Ok(CompressedReceipt {
+            execution_digest: rc.post.digest().as_bytes().to_vec(),
+            exit_code_system: system,
+            exit_code_user: user,
+            proof: seal.to_vec(),
+        })
+    } else {
+        Err(CompressionError::ProofCompressionError.into())
+    }
+}
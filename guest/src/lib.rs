@@ -0,0 +1,83 @@
+//! Guest side helpers for Bonsol zk programs.
+//!
+//! Execution requests made with `verify_input_hash` only settle when the guest commits the
+//! canonical input digest, the sha256 of every input it reads in request order, as the first 32
+//! bytes of its journal. [`InputReader`] records the inputs as they are read so the digest matches
+//! what the requester computed with `bonsol_interface::util::input_digest`.
+
+use risc0_zkvm::sha::{Impl, Sha256};
+
+#[cfg(target_os = "zkvm")]
+use risc0_zkvm::guest::env;
+
+/// The canonical input digest over already read inputs, in request order.
+pub fn input_digest(inputs: &[&[u8]]) -> [u8; 32] {
+    let digest = Impl::hash_bytes(&inputs.concat());
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+/// Reads execution inputs and records them for the input digest.
+#[derive(Default)]
+pub struct InputReader {
+    read: Vec<u8>,
+}
+
+impl InputReader {
+    pub const fn new() -> Self {
+        InputReader { read: Vec::new() }
+    }
+
+    /// Fills `buf` with the next input bytes, see `env::read_slice`.
+    #[cfg(target_os = "zkvm")]
+    pub fn read_slice(&mut self, buf: &mut [u8]) {
+        env::read_slice(buf);
+        self.record(buf);
+    }
+
+    /// Commits the input digest to the journal, call this before committing any outputs.
+    #[cfg(target_os = "zkvm")]
+    pub fn commit_digest(self) -> [u8; 32] {
+        let digest = self.digest();
+        env::commit_slice(&digest);
+        digest
+    }
+
+    pub fn digest(&self) -> [u8; 32] {
+        input_digest(&[&self.read])
+    }
+
+    #[cfg(any(target_os = "zkvm", test))]
+    fn record(&mut self, data: &[u8]) {
+        self.read.extend_from_slice(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // shared with the `input_digest` vectors in bonsol-interface and bonsol-prover
+    const DIGEST: [u8; 32] = [
+        0x53, 0xc3, 0xed, 0x98, 0x28, 0xd0, 0x7f, 0x91, 0xa1, 0x3b, 0x1f, 0x59, 0xb6, 0xd9, 0x54,
+        0xee, 0x4d, 0xa5, 0x5f, 0x37, 0xa4, 0xf5, 0xcf, 0xdb, 0x18, 0x5a, 0xde, 0xea, 0xe6, 0x6f,
+        0x77, 0x5d,
+    ];
+
+    #[test]
+    fn test_input_digest() {
+        assert_eq!(
+            input_digest(&[b"{\"attestation\":\"test\"}", b"test"]),
+            DIGEST
+        );
+    }
+
+    #[test]
+    fn test_reader_digest() {
+        let mut reader = InputReader::new();
+        reader.record(b"{\"attestation\":\"test\"}");
+        reader.record(b"test");
+        assert_eq!(reader.digest(), DIGEST);
+    }
+}
@@ -27,14 +27,40 @@ pub enum IngesterConfig {
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum TransactionSenderConfig {
-    Rpc { rpc_url: String },
-    //--- below not implemented yet
-    Tpu,
+    Rpc {
+        rpc_url: String,
+    },
+    /// Sends transactions to the leaders' TPU over QUIC, falling back to `rpc_url` when that
+    /// fails. `rpc_url` also serves the leader schedule and `websocket_url` the slot updates.
+    Tpu {
+        rpc_url: String,
+        websocket_url: String,
+        #[serde(default = "default_tpu_fanout_slots")]
+        fanout_slots: u64,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum SignerConfig {
-    KeypairFile { path: String }, //--- below not implemented yet maybe hsm, signer server or some weird sig agg shiz
+    KeypairFile {
+        path: String,
+    },
+    /// A keypair encrypted with a passphrase, see `signer::keystore`. The passphrase is read from
+    /// `passphrase_file` if set, otherwise from the `passphrase_env` variable, which defaults to
+    /// `BONSOL_KEYSTORE_PASSPHRASE`.
+    EncryptedKeystore {
+        path: String,
+        passphrase_env: Option<String>,
+        passphrase_file: Option<String>,
+    },
+    /// A signer over HTTP, see `signer::remote`. The bearer token is read from the
+    /// `auth_token_env` variable.
+    Remote {
+        url: String,
+        auth_token_env: Option<String>,
+        #[serde(default = "default_remote_signer_timeout_secs")]
+        timeout_secs: u32,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -45,6 +71,62 @@ pub enum MissingImageStrategy {
     Fail,
 }
 
+/// Settings for the built in claim policies, see `risc0_runner::claim_policy`. Lists are empty
+/// and minimums are zero by default, which claims everything the node has capacity for.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct ClaimPolicyConfig {
+    /// Lamports a request must tip at least.
+    pub min_tip: u64,
+    /// Lamports a request must tip per million estimated cycles.
+    pub min_tip_per_million_cycles: u64,
+    /// Image ids to claim, all images when empty.
+    pub allowed_images: Vec<String>,
+    pub denied_images: Vec<String>,
+    /// Requester addresses to claim for, all requesters when empty.
+    pub allowed_requesters: Vec<String>,
+    pub denied_requesters: Vec<String>,
+    /// Requests expiring within this many blocks are skipped.
+    pub min_blocks_to_expiry: u64,
+    /// No requests are claimed while the signer holds fewer lamports than this.
+    pub min_balance_lamports: u64,
+}
+
+/// How the node prices and sizes its claim and proof transactions, see
+/// `transaction_sender::submit`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct TransactionFeeConfig {
+    /// Percentile of the fees recently paid on the written accounts to pay.
+    pub priority_fee_percentile: u8,
+    pub min_priority_fee_micro_lamports: u64,
+    pub max_priority_fee_micro_lamports: u64,
+    /// Lamports of priority fee a single transaction may pay at most.
+    pub max_priority_fee_lamports: u64,
+    /// Compute units requested above what the simulation used, in percent.
+    pub compute_unit_margin_percent: u32,
+}
+
+impl Default for TransactionFeeConfig {
+    fn default() -> Self {
+        TransactionFeeConfig {
+            priority_fee_percentile: 75,
+            min_priority_fee_micro_lamports: 0,
+            max_priority_fee_micro_lamports: 1_000_000,
+            max_priority_fee_lamports: 1_000_000,
+            compute_unit_margin_percent: 20,
+        }
+    }
+}
+
+/// Proves SP1 requests with an external prover command, see `risc0_runner::sp1`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Sp1Config {
+    pub prover_command: String,
+    #[serde(default = "default_sp1_image_folder")]
+    pub image_folder: String,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ProverNodeConfig {
     pub env: Option<String>,
@@ -56,6 +138,11 @@ pub struct ProverNodeConfig {
     pub max_image_size_mb: u32,
     #[serde(default = "default_image_compression_ttl_hours")]
     pub image_compression_ttl_hours: u32,
+    #[serde(default = "default_image_memory_budget_mb")]
+    pub image_memory_budget_mb: u32,
+    /// Megabytes of image files kept on disk, 0 for no limit.
+    #[serde(default = "default_image_disk_quota_mb")]
+    pub image_disk_quota_mb: u32,
     #[serde(default = "default_max_input_size_mb")]
     pub max_input_size_mb: u32,
     #[serde(default = "default_image_download_timeout_secs")]
@@ -76,6 +163,30 @@ pub struct ProverNodeConfig {
     pub metrics_config: MetricsConfig,
     #[serde(default)]
     pub missing_image_strategy: MissingImageStrategy,
+    #[serde(default = "default_job_store_path")]
+    pub job_store_path: String,
+    #[serde(default)]
+    pub claim_policy: ClaimPolicyConfig,
+    #[serde(default)]
+    pub transaction_fees: TransactionFeeConfig,
+    /// Cycles a guest may run for when it is executed before claiming, 0 disables dry runs.
+    #[serde(default = "default_dry_run_cycle_limit")]
+    pub dry_run_cycle_limit: u64,
+    /// Dry run results kept per image and input digest.
+    #[serde(default = "default_dry_run_cache_size")]
+    pub dry_run_cache_size: u32,
+    /// Where the measured proving throughput is saved.
+    #[serde(default = "default_throughput_model_path")]
+    pub throughput_model_path: String,
+    /// Factor applied to the expected proving time when committing to a block.
+    #[serde(default = "default_commitment_safety_margin")]
+    pub commitment_safety_margin: f64,
+    /// A guest that runs without inputs, proven at startup to calibrate an uncalibrated node.
+    #[serde(default)]
+    pub benchmark_image: Option<String>,
+    /// SP1 requests are only claimed when this is set.
+    #[serde(default)]
+    pub sp1: Option<Sp1Config>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -106,6 +217,10 @@ fn default_risc0_image_folder() -> String {
     "./elf".to_string()
 }
 
+fn default_sp1_image_folder() -> String {
+    "./sp1-elf".to_string()
+}
+
 const fn default_max_image_size_mb() -> u32 {
     10
 }
@@ -114,6 +229,14 @@ const fn default_image_compression_ttl_hours() -> u32 {
     5
 }
 
+const fn default_image_memory_budget_mb() -> u32 {
+    2048
+}
+
+const fn default_image_disk_quota_mb() -> u32 {
+    0
+}
+
 const fn default_max_input_size_mb() -> u32 {
     1
 }
@@ -130,18 +253,46 @@ const fn default_maximum_concurrent_proofs() -> u32 {
     100
 }
 
+const fn default_dry_run_cycle_limit() -> u64 {
+    1 << 30
+}
+
+const fn default_dry_run_cache_size() -> u32 {
+    1024
+}
+
+fn default_throughput_model_path() -> String {
+    "./throughput.json".to_string()
+}
+
+const fn default_commitment_safety_margin() -> f64 {
+    1.5
+}
+
+fn default_job_store_path() -> String {
+    "./jobs".to_string()
+}
+
 fn default_ingester_config() -> IngesterConfig {
     IngesterConfig::RpcBlockSubscription {
         wss_rpc_url: "ws://localhost:8900".to_string(),
     }
 }
 
+const fn default_tpu_fanout_slots() -> u64 {
+    12
+}
+
 fn default_transaction_sender_config() -> TransactionSenderConfig {
     TransactionSenderConfig::Rpc {
         rpc_url: "http://localhost:8899".to_string(),
     }
 }
 
+const fn default_remote_signer_timeout_secs() -> u32 {
+    10
+}
+
 fn default_signer_config() -> SignerConfig {
     SignerConfig::KeypairFile {
         path: "./node-keypair.json".to_string(),
@@ -156,6 +307,8 @@ impl Default for ProverNodeConfig {
             risc0_image_folder: default_risc0_image_folder(),
             max_image_size_mb: default_max_image_size_mb(),
             image_compression_ttl_hours: default_image_compression_ttl_hours(),
+            image_memory_budget_mb: default_image_memory_budget_mb(),
+            image_disk_quota_mb: default_image_disk_quota_mb(),
             max_input_size_mb: default_max_input_size_mb(),
             image_download_timeout_secs: default_image_download_timeout_secs(),
             input_download_timeout_secs: default_input_download_timeout_secs(),
@@ -166,6 +319,15 @@ impl Default for ProverNodeConfig {
             stark_compression_tools_path: default_stark_compression_tools_path(),
             metrics_config: default_metrics_config(),
             missing_image_strategy: MissingImageStrategy::default(),
+            job_store_path: default_job_store_path(),
+            claim_policy: ClaimPolicyConfig::default(),
+            transaction_fees: TransactionFeeConfig::default(),
+            dry_run_cycle_limit: default_dry_run_cycle_limit(),
+            dry_run_cache_size: default_dry_run_cache_size(),
+            throughput_model_path: default_throughput_model_path(),
+            commitment_safety_margin: default_commitment_safety_margin(),
+            benchmark_image: None,
+            sp1: None,
         }
     }
 }
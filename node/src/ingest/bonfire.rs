@@ -14,7 +14,7 @@ use quinn::{
     },
     ClientConfig, Endpoint,
 };
-use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use solana_sdk::pubkey::Pubkey;
 use sysinfo::System;
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedSender},
@@ -24,7 +24,10 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
-use crate::ingest::{Ingester, TxChannel};
+use crate::{
+    ingest::{Ingester, TxChannel},
+    signer::NodeSigner,
+};
 
 impl From<bonsol_bonfire::BonsolInstruction> for crate::types::BonsolInstruction {
     fn from(other: bonsol_bonfire::BonsolInstruction) -> crate::types::BonsolInstruction {
@@ -47,7 +50,7 @@ pub struct BonfireIngester {
     server_addr: String,
     ca_cert: Option<String>,
     op_handle: Option<tokio::task::JoinHandle<Result<()>>>,
-    keypair: Arc<Keypair>,
+    signer: NodeSigner,
     logs_rx: Arc<Mutex<LogEventStream>>,
 }
 
@@ -55,7 +58,7 @@ impl BonfireIngester {
     pub fn new(
         server_addr: String,
         ca_cert: Option<String>,
-        keypair: Arc<Keypair>,
+        signer: NodeSigner,
         stdout: EventChannelRx,
         stderr: EventChannelRx,
     ) -> Result<BonfireIngester> {
@@ -85,7 +88,7 @@ impl BonfireIngester {
             server_addr,
             ca_cert,
             op_handle: None,
-            keypair,
+            signer,
             logs_rx: Arc::new(Mutex::new(Box::pin(
                 UnboundedReceiverStream::new(logs_rx).peekable(),
             ))),
@@ -97,7 +100,7 @@ impl BonfireIngester {
         ca_cert: Option<String>,
         logs_rx: Arc<Mutex<LogEventStream>>,
         server_addr: String,
-        keypair: Arc<Keypair>,
+        signer: NodeSigner,
     ) -> Result<()> {
         let server_name = server_addr
             .split(":")
@@ -137,8 +140,8 @@ impl BonfireIngester {
             .clone();
         debug!("Got challenge {:?}", challenge);
 
-        // 2) Sign challenge with ephemeral keypair and send login
-        let response = challenge.sign(&*keypair);
+        // 2) Sign challenge with the node's signer and send login
+        let response = challenge.sign(&*signer)?;
         sig_writer.send(response.into()).await?;
         debug!("Response sent");
 
@@ -286,7 +289,7 @@ impl Ingester for BonfireIngester {
     fn start(&mut self, _program: Pubkey) -> Result<TxChannel> {
         let (txchan, rx) = unbounded_channel();
 
-        let keypair = self.keypair.clone();
+        let signer = self.signer.clone();
         let server_addr = self.server_addr.clone();
         let logs_rx = self.logs_rx.clone();
         let ca_cert = self.ca_cert.clone();
@@ -298,7 +301,7 @@ impl Ingester for BonfireIngester {
                     ca_cert.clone(),
                     logs_rx.clone(),
                     server_addr.clone(),
-                    keypair.clone(),
+                    signer.clone(),
                 )
                 .await;
                 warn!("Disconnected! Reason: {:?}\nTrying to reconnect...", r);
@@ -0,0 +1,222 @@
+//! Durable record of every execution the node works on. The runner keeps its working state in
+//! memory, this store is what survives a restart: each job holds the request it was created from
+//! and how far it got, so claimed jobs can be proven or submitted again instead of forfeiting the
+//! stake.
+
+use {
+    anyhow::Result,
+    serde::{Deserialize, Serialize},
+    solana_sdk::{pubkey::Pubkey, signature::Signature},
+    std::path::Path,
+};
+
+/// How far a job got, in lifecycle order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum JobStage {
+    /// The request was accepted and a claim is about to be sent.
+    Seen,
+    /// A claim transaction was sent.
+    Claimed,
+    /// The claim landed and all inputs, private ones included, were downloaded.
+    InputsResolved,
+    Proving,
+    /// The proof is compressed and stored with the job, ready to submit.
+    Proved,
+    /// A status transaction is being sent.
+    Submitted,
+    /// The status transaction confirmed.
+    Confirmed,
+}
+
+/// Everything needed to submit a proof again without proving it again.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRecord {
+    pub proof: Vec<u8>,
+    pub execution_digest: Vec<u8>,
+    pub input_digest: Vec<u8>,
+    pub assumption_digest: Vec<u8>,
+    pub committed_outputs: Vec<u8>,
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Job {
+    pub execution_id: String,
+    pub stage: JobStage,
+    /// The `ExecutionRequestV1` bytes as received.
+    pub request: Vec<u8>,
+    /// The accounts of the `ExecuteV1` instruction, the requester first.
+    pub accounts: Vec<Pubkey>,
+    pub expiry: u64,
+    pub claim_signature: Option<Signature>,
+    pub submission_signature: Option<Signature>,
+    pub proof: Option<ProofRecord>,
+}
+
+impl Job {
+    pub const fn new(
+        execution_id: String,
+        request: Vec<u8>,
+        accounts: Vec<Pubkey>,
+        expiry: u64,
+    ) -> Self {
+        Self {
+            execution_id,
+            stage: JobStage::Seen,
+            request,
+            accounts,
+            expiry,
+            claim_signature: None,
+            submission_signature: None,
+            proof: None,
+        }
+    }
+}
+
+/// Jobs keyed by execution id in an embedded sled database. Every write is flushed before it
+/// returns.
+pub struct JobStore {
+    db: sled::Db,
+}
+
+impl JobStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self {
+            db: sled::open(path)?,
+        })
+    }
+
+    /// A store that is deleted when dropped, for tests.
+    #[cfg(test)]
+    pub fn temporary() -> Result<Self> {
+        Ok(Self {
+            db: sled::Config::new().temporary(true).open()?,
+        })
+    }
+
+    pub fn insert(&self, job: &Job) -> Result<()> {
+        self.db
+            .insert(job.execution_id.as_bytes(), serde_json::to_vec(job)?)?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    pub fn get(&self, execution_id: &str) -> Result<Option<Job>> {
+        self.db
+            .get(execution_id.as_bytes())?
+            .map(|v| serde_json::from_slice(&v).map_err(Into::into))
+            .transpose()
+    }
+
+    /// Applies `f` to a stored job, returning the updated job or `None` if there is none.
+    pub fn update(&self, execution_id: &str, f: impl FnOnce(&mut Job)) -> Result<Option<Job>> {
+        let Some(mut job) = self.get(execution_id)? else {
+            return Ok(None);
+        };
+        f(&mut job);
+        self.insert(&job)?;
+        Ok(Some(job))
+    }
+
+    pub fn advance(&self, execution_id: &str, stage: JobStage) -> Result<Option<Job>> {
+        self.update(execution_id, |job| job.stage = stage)
+    }
+
+    pub fn remove(&self, execution_id: &str) -> Result<()> {
+        self.db.remove(execution_id.as_bytes())?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    pub fn jobs(&self) -> Result<Vec<Job>> {
+        self.db
+            .iter()
+            .values()
+            .map(|v| Ok(serde_json::from_slice(&v?)?))
+            .collect()
+    }
+
+    /// Jobs that have not confirmed yet, what the runner resumes on startup.
+    pub fn unfinished(&self) -> Result<Vec<Job>> {
+        Ok(self
+            .jobs()?
+            .into_iter()
+            .filter(|job| job.stage < JobStage::Confirmed)
+            .collect())
+    }
+
+    /// Removes jobs whose request expired before `current_block`, finished or not, and returns
+    /// how many were removed.
+    pub fn prune(&self, current_block: u64) -> Result<usize> {
+        let expired: Vec<_> = self
+            .jobs()?
+            .into_iter()
+            .filter(|job| job.expiry < current_block)
+            .collect();
+        for job in &expired {
+            self.db.remove(job.execution_id.as_bytes())?;
+        }
+        self.db.flush()?;
+        Ok(expired.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(execution_id: &str, expiry: u64) -> Job {
+        Job::new(
+            execution_id.to_string(),
+            vec![1, 2, 3],
+            vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            expiry,
+        )
+    }
+
+    #[test]
+    fn test_lifecycle_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let store = JobStore::open(dir.path()).unwrap();
+            store.insert(&job("a", 100)).unwrap();
+            store.insert(&job("b", 100)).unwrap();
+            store
+                .update("a", |job| {
+                    job.stage = JobStage::Proved;
+                    job.proof = Some(ProofRecord {
+                        proof: vec![7; 256],
+                        execution_digest: vec![1; 32],
+                        input_digest: vec![2; 32],
+                        assumption_digest: vec![0; 32],
+                        committed_outputs: vec![3],
+                        exit_code_system: 0,
+                        exit_code_user: 0,
+                    });
+                })
+                .unwrap();
+            store.advance("b", JobStage::Confirmed).unwrap();
+            assert!(store.advance("c", JobStage::Claimed).unwrap().is_none());
+        }
+        let store = JobStore::open(dir.path()).unwrap();
+        let unfinished = store.unfinished().unwrap();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].execution_id, "a");
+        assert_eq!(unfinished[0].stage, JobStage::Proved);
+        assert_eq!(unfinished[0].proof.as_ref().unwrap().proof, vec![7; 256]);
+        assert_eq!(store.get("b").unwrap().unwrap().stage, JobStage::Confirmed);
+    }
+
+    #[test]
+    fn test_prune_expired() {
+        let store = JobStore::temporary().unwrap();
+        store.insert(&job("old", 10)).unwrap();
+        store.insert(&job("new", 30)).unwrap();
+        assert_eq!(store.prune(20).unwrap(), 1);
+        assert!(store.get("old").unwrap().is_none());
+        assert!(store.get("new").unwrap().is_some());
+        store.remove("new").unwrap();
+        assert!(store.jobs().unwrap().is_empty());
+    }
+}
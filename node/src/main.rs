@@ -2,9 +2,11 @@ pub mod types;
 #[macro_use]
 pub mod observe;
 mod ingest;
+mod job_store;
 
 pub mod config;
 mod risc0_runner;
+mod signer;
 mod transaction_sender;
 use {
     crate::ingest::BonfireIngester,
@@ -17,6 +19,10 @@ use {
     observe::MetricEvents,
     risc0_runner::Risc0Runner,
     rlimit::Resource,
+    signer::{
+        keystore::{read_passphrase, Keystore, DEFAULT_ITERATIONS},
+        load_signer,
+    },
     solana_rpc_client::nonblocking::rpc_client::RpcClient,
     solana_sdk::{pubkey::Pubkey, signature::read_keypair_file, signer::Signer},
     std::{net::ToSocketAddrs, process::exit, str::FromStr, sync::Arc, time::Duration},
@@ -24,7 +30,9 @@ use {
     tokio::{select, signal},
     tracing::{error, info},
     tracing_subscriber,
-    transaction_sender::{rpc::RpcTransactionSender, transaction_sender::TransactionSender},
+    transaction_sender::{
+        rpc::RpcTransactionSender, tpu::TpuBroadcaster, transaction_sender::TransactionSender,
+    },
 };
 
 #[derive(Error, Debug)]
@@ -48,15 +56,7 @@ pub enum CliError {
 
 async fn node(config: ProverNodeConfig) -> Result<()> {
     let program = Pubkey::from_str(&config.bonsol_program)?;
-    //todo use traits for signer
-    let signer = match config.signer_config.clone() {
-        SignerConfig::KeypairFile { path } => {
-            info!("Using Keypair File");
-            read_keypair_file(&path).map_err(|_| CliError::InvalidSigner)?
-        }
-    };
-    let signer = Arc::new(signer);
-
+    let signer = load_signer(&config.signer_config).await?;
     let signer_identity = signer.pubkey();
     //Todo traitify ingester
 
@@ -99,10 +99,31 @@ async fn node(config: ProverNodeConfig) -> Result<()> {
     let (mut transaction_sender, solana_rpc_client) = match config.transaction_sender_config.clone()
     {
         TransactionSenderConfig::Rpc { rpc_url } => (
-            RpcTransactionSender::new(rpc_url.clone(), program, signer),
+            RpcTransactionSender::new(
+                rpc_url.clone(),
+                program,
+                signer,
+                config.transaction_fees.clone(),
+            ),
             RpcClient::new(rpc_url),
         ),
-        _ => return Err(CliError::InvalidRpcUrl.into()),
+        TransactionSenderConfig::Tpu {
+            rpc_url,
+            websocket_url,
+            fanout_slots,
+        } => {
+            info!("Using TPU transaction sender");
+            let sender = RpcTransactionSender::new(
+                rpc_url.clone(),
+                program,
+                signer,
+                config.transaction_fees.clone(),
+            );
+            let tpu =
+                TpuBroadcaster::connect(sender.rpc_client.clone(), &websocket_url, fanout_slots)
+                    .await?;
+            (sender.with_tpu(tpu), RpcClient::new(rpc_url))
+        }
     };
     transaction_sender.start();
     let input_resolver = DefaultInputResolver::new_with_opts(
@@ -143,6 +164,20 @@ async fn node(config: ProverNodeConfig) -> Result<()> {
     Ok(())
 }
 
+/// Seals a keypair file into an encrypted keystore, with the passphrase in
+/// `BONSOL_KEYSTORE_PASSPHRASE`.
+fn encrypt_keypair(keypair_path: &str, keystore_path: &str) -> Result<()> {
+    let keypair = read_keypair_file(keypair_path).map_err(|_| CliError::InvalidSigner)?;
+    let passphrase = read_passphrase(None, None)?;
+    Keystore::encrypt(&keypair, &passphrase, DEFAULT_ITERATIONS)?.write(keystore_path)?;
+    info!(
+        "Wrote keystore for {} to {}",
+        keypair.pubkey(),
+        keystore_path
+    );
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Set the stack size to unlimited
@@ -156,8 +191,12 @@ async fn main() -> Result<()> {
         .with_timer(tracing_subscriber::fmt::time::UtcTime::rfc_3339())
         .init();
     let args: Vec<String> = std::env::args().collect();
+    if args.len() == 4 && args[1] == "encrypt-keypair" {
+        return encrypt_keypair(&args[2], &args[3]);
+    }
     if args.len() < 3 || args[1] != "-f" {
         error!("Usage: bonsol-node -f <config_file>");
+        error!("       bonsol-node encrypt-keypair <keypair_file> <keystore_file>");
         return Ok(());
     }
     let config_file = &args[2];
@@ -11,12 +11,14 @@ pub enum MetricEvents {
     ProvingSucceeded,
     ClaimAttempt,
     ClaimMissed,
+    ClaimSkipped,
     ClaimReceived,
     ImageDeployment,
     ImageDownload,
     ImageCompressed,
     ImageLoaded,
     ImageComputeEstimate,
+    DryRun,
     ExecutionRequest,
     ProofGeneration,
     ProofCompression,
@@ -27,8 +29,12 @@ pub enum MetricEvents {
     BonsolStartup,
     SignaturesInFlight,
     IncompatibleProverVersion,
+    UnsupportedProofSystem,
     ProofSubmissionError,
     TransactionExpired,
+    TpuSendFailed,
+    PriorityFee,
+    TransactionResubmitted,
 }
 
 macro_rules! emit_event {
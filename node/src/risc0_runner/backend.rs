@@ -0,0 +1,232 @@
+//! Prover backends, one per proof system the node proves. The runner claims, stages inputs and
+//! submits the same way for every proof system and hands the image and proving to the backend of
+//! the proof system the execution request was made with.
+
+use std::{collections::HashMap, sync::Arc, time::Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use bonsol_interface::bonsol_schema::{ExecutionRequestV1, ProofSystem};
+use bonsol_prover::{input_resolver::ProgramInput, util::LogShipper};
+use bytes::Bytes;
+use tracing::info;
+
+use super::{
+    can_execute,
+    estimate::{CycleEstimator, DryRun},
+    image_cache::ImageCache,
+    risc0_compress_proof, risc0_prove,
+    throughput::ThroughputModel,
+    Risc0RunnerError,
+};
+
+/// A proven execution in the form the status transaction carries it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenExecution {
+    pub proof: Vec<u8>,
+    pub execution_digest: Vec<u8>,
+    pub assumption_digest: Vec<u8>,
+    /// The input digest followed by the committed outputs.
+    pub journal: Vec<u8>,
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
+}
+
+#[async_trait]
+pub trait ProverBackend: Send + Sync {
+    fn proof_system(&self) -> ProofSystem;
+    /// Whether the request can be proven beyond its proof system, such as its prover version.
+    fn can_prove(&self, exec: &ExecutionRequestV1) -> bool;
+    fn has_image(&self, image_id: &str) -> bool;
+    /// Stores an image downloaded from its deployment.
+    fn store_image(&self, image_id: &str, size: u64, bytes: Bytes) -> Result<()>;
+    /// Executes the image before claiming, backends that cannot return `DryRun::Unavailable`.
+    async fn estimate(&self, image_id: &str, inputs: &[ProgramInput]) -> Result<DryRun>;
+    async fn prove(
+        &self,
+        image_id: &str,
+        inputs: Vec<ProgramInput>,
+        stdout: LogShipper,
+        stderr: LogShipper,
+    ) -> Result<ProvenExecution>;
+}
+
+/// The backends of a node by proof system, requests for any other proof system are not claimed.
+#[derive(Clone, Default)]
+pub struct ProverBackends {
+    backends: HashMap<ProofSystem, Arc<dyn ProverBackend>>,
+}
+
+impl ProverBackends {
+    pub fn new(backends: impl IntoIterator<Item = Arc<dyn ProverBackend>>) -> Self {
+        ProverBackends {
+            backends: backends
+                .into_iter()
+                .map(|backend| (backend.proof_system(), backend))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, proof_system: ProofSystem) -> Option<Arc<dyn ProverBackend>> {
+        self.backends.get(&proof_system).cloned()
+    }
+}
+
+/// Proves risc0 guests in process and wraps the succinct receipt in Groth16 with the stark
+/// compression tools.
+pub struct Risc0Groth16Backend {
+    stark_compression_tools_path: String,
+    loaded_images: Arc<ImageCache>,
+    cycle_estimator: Arc<CycleEstimator>,
+    throughput: Arc<ThroughputModel>,
+}
+
+impl Risc0Groth16Backend {
+    pub fn new(
+        stark_compression_tools_path: String,
+        loaded_images: Arc<ImageCache>,
+        cycle_estimator: Arc<CycleEstimator>,
+        throughput: Arc<ThroughputModel>,
+    ) -> Self {
+        Risc0Groth16Backend {
+            stark_compression_tools_path,
+            loaded_images,
+            cycle_estimator,
+            throughput,
+        }
+    }
+}
+
+#[async_trait]
+impl ProverBackend for Risc0Groth16Backend {
+    fn proof_system(&self) -> ProofSystem {
+        ProofSystem::Risc0Groth16
+    }
+
+    fn can_prove(&self, exec: &ExecutionRequestV1) -> bool {
+        can_execute(*exec)
+    }
+
+    fn has_image(&self, image_id: &str) -> bool {
+        self.loaded_images.contains(image_id)
+    }
+
+    fn store_image(&self, image_id: &str, size: u64, bytes: Bytes) -> Result<()> {
+        let img = self.loaded_images.store().commit(image_id, size, bytes)?;
+        self.loaded_images.insert(img)?;
+        Ok(())
+    }
+
+    async fn estimate(&self, image_id: &str, inputs: &[ProgramInput]) -> Result<DryRun> {
+        let image = self
+            .loaded_images
+            .get(image_id)
+            .await?
+            .ok_or(Risc0RunnerError::ImgLoadError)?;
+        self.cycle_estimator.estimate(&image, inputs).await
+    }
+
+    async fn prove(
+        &self,
+        image_id: &str,
+        inputs: Vec<ProgramInput>,
+        stdout: LogShipper,
+        stderr: LogShipper,
+    ) -> Result<ProvenExecution> {
+        let image = self
+            .loaded_images
+            .get(image_id)
+            .await?
+            .ok_or(Risc0RunnerError::ImgLoadError)?;
+        if image.bytes().is_none() {
+            return Err(Risc0RunnerError::ImageDataUnavailable.into());
+        }
+        let mem_image = image.get_memory_image()?;
+        let proving_started = Instant::now();
+        let (journal, assumptions_digest, receipt, cycles) =
+            tokio::task::spawn_blocking(move || {
+                risc0_prove(mem_image, inputs, stdout, stderr).map_err(|e| {
+                    info!("Error generating proof: {:?}", e);
+                    Risc0RunnerError::ProofGenerationError
+                })
+            })
+            .await??;
+        self.throughput
+            .record_proving(cycles, proving_started.elapsed());
+        let compression_started = Instant::now();
+        let compressed_receipt = risc0_compress_proof(&self.stark_compression_tools_path, receipt)
+            .await
+            .map_err(|e| {
+                info!("Error compressing proof: {:?}", e);
+                Risc0RunnerError::ProofCompressionError
+            })?;
+        self.throughput
+            .record_compression(compression_started.elapsed());
+        Ok(ProvenExecution {
+            proof: compressed_receipt.proof,
+            execution_digest: compressed_receipt.execution_digest,
+            assumption_digest: assumptions_digest.as_bytes().to_vec(),
+            journal: journal.bytes,
+            exit_code_system: compressed_receipt.exit_code_system,
+            exit_code_user: compressed_receipt.exit_code_user,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopBackend(ProofSystem);
+
+    #[async_trait]
+    impl ProverBackend for NoopBackend {
+        fn proof_system(&self) -> ProofSystem {
+            self.0
+        }
+
+        fn can_prove(&self, _exec: &ExecutionRequestV1) -> bool {
+            true
+        }
+
+        fn has_image(&self, _image_id: &str) -> bool {
+            false
+        }
+
+        fn store_image(&self, _image_id: &str, _size: u64, _bytes: Bytes) -> Result<()> {
+            Ok(())
+        }
+
+        async fn estimate(&self, _image_id: &str, _inputs: &[ProgramInput]) -> Result<DryRun> {
+            Ok(DryRun::Unavailable)
+        }
+
+        async fn prove(
+            &self,
+            _image_id: &str,
+            _inputs: Vec<ProgramInput>,
+            _stdout: LogShipper,
+            _stderr: LogShipper,
+        ) -> Result<ProvenExecution> {
+            Err(Risc0RunnerError::ProofGenerationError.into())
+        }
+    }
+
+    #[test]
+    fn test_backends_route_by_proof_system() {
+        let backends = ProverBackends::new([
+            Arc::new(NoopBackend(ProofSystem::Risc0Groth16)) as Arc<dyn ProverBackend>,
+            Arc::new(NoopBackend(ProofSystem::Sp1Groth16)),
+        ]);
+        for proof_system in [ProofSystem::Risc0Groth16, ProofSystem::Sp1Groth16] {
+            assert_eq!(
+                backends.get(proof_system).unwrap().proof_system(),
+                proof_system
+            );
+        }
+        assert!(backends.get(ProofSystem(7)).is_none());
+        assert!(ProverBackends::default()
+            .get(ProofSystem::Risc0Groth16)
+            .is_none());
+    }
+}
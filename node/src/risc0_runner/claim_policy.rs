@@ -0,0 +1,459 @@
+//! Decides which execution requests the node claims. Policies that need no execution data run
+//! before the image is loaded and the inputs resolved, those that need the cycle estimate run
+//! after the dry run. In both passes the first policy to skip wins, the built in policies are
+//! configured from `ClaimPolicyConfig`.
+
+use {
+    crate::config::ClaimPolicyConfig,
+    anyhow::{anyhow, Result},
+    bonsol_interface::bonsol_schema::ExecutionRequestV1,
+    solana_sdk::pubkey::Pubkey,
+    std::{collections::HashSet, str::FromStr},
+};
+
+/// How busy the node is when a request arrives.
+#[derive(Clone, Copy, Debug)]
+pub struct NodeLoad {
+    pub inflight_proofs: usize,
+    pub maximum_concurrent_proofs: usize,
+}
+
+/// Everything known about a request before claiming it.
+pub struct ClaimRequest<'a> {
+    pub request: ExecutionRequestV1<'a>,
+    pub requester: Pubkey,
+    pub image_id: &'a str,
+    /// Cycles the guest is expected to run for, when the node estimated them. Always `None`
+    /// before the dry run.
+    pub estimated_cycles: Option<u64>,
+    /// The block the claim would commit to proving by, `None` before the dry run.
+    pub block_commitment: Option<u64>,
+    pub current_block: u64,
+    pub load: NodeLoad,
+    /// Lamports held by the node's signer.
+    pub balance: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimDecision {
+    Claim,
+    Skip(String),
+}
+
+pub trait ClaimPolicy: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision;
+
+    /// Whether the policy reads `estimated_cycles` or `block_commitment`, such policies only run
+    /// after the dry run.
+    fn needs_estimate(&self) -> bool {
+        false
+    }
+}
+
+/// The policies a node runs, in order.
+pub struct ClaimPolicies {
+    policies: Vec<Box<dyn ClaimPolicy>>,
+}
+
+impl ClaimPolicies {
+    pub fn new(policies: Vec<Box<dyn ClaimPolicy>>) -> Self {
+        Self { policies }
+    }
+
+    /// The built in policies, those left at their defaults are not installed. Capacity and
+    /// deadline checks always run.
+    pub fn from_config(config: &ClaimPolicyConfig) -> Result<Self> {
+        let mut policies: Vec<Box<dyn ClaimPolicy>> = vec![
+            Box::new(Capacity),
+            Box::new(DeadlineFeasibility {
+                min_blocks_to_expiry: config.min_blocks_to_expiry,
+            }),
+        ];
+        if config.min_tip > 0 {
+            policies.push(Box::new(MinimumTip {
+                min_tip: config.min_tip,
+            }));
+        }
+        if config.min_tip_per_million_cycles > 0 {
+            policies.push(Box::new(MinimumCycleTip {
+                min_tip_per_million_cycles: config.min_tip_per_million_cycles,
+            }));
+        }
+        if !config.allowed_images.is_empty() || !config.denied_images.is_empty() {
+            policies.push(Box::new(ImageFilter {
+                allowed: config.allowed_images.iter().cloned().collect(),
+                denied: config.denied_images.iter().cloned().collect(),
+            }));
+        }
+        if !config.allowed_requesters.is_empty() || !config.denied_requesters.is_empty() {
+            policies.push(Box::new(RequesterFilter {
+                allowed: parse_pubkeys(&config.allowed_requesters)?,
+                denied: parse_pubkeys(&config.denied_requesters)?,
+            }));
+        }
+        if config.min_balance_lamports > 0 {
+            policies.push(Box::new(MinimumBalance {
+                min_balance_lamports: config.min_balance_lamports,
+            }));
+        }
+        Ok(Self::new(policies))
+    }
+
+    /// Runs the policies that need no execution data, before the image is loaded.
+    pub fn decide_before_execution(&self, request: &ClaimRequest) -> ClaimDecision {
+        self.decide(request, false)
+    }
+
+    /// Runs the policies that need the cycle estimate and block commitment from the dry run.
+    pub fn decide_after_execution(&self, request: &ClaimRequest) -> ClaimDecision {
+        self.decide(request, true)
+    }
+
+    fn decide(&self, request: &ClaimRequest, after_execution: bool) -> ClaimDecision {
+        let policies = self
+            .policies
+            .iter()
+            .filter(|p| p.needs_estimate() == after_execution);
+        for policy in policies {
+            if let ClaimDecision::Skip(reason) = policy.decide(request) {
+                return ClaimDecision::Skip(format!("{}: {}", policy.name(), reason));
+            }
+        }
+        ClaimDecision::Claim
+    }
+}
+
+fn parse_pubkeys(keys: &[String]) -> Result<HashSet<Pubkey>> {
+    keys.iter()
+        .map(|k| Pubkey::from_str(k).map_err(|e| anyhow!("Invalid requester {}: {}", k, e)))
+        .collect()
+}
+
+/// Skips requests while `maximum_concurrent_proofs` are in flight.
+pub struct Capacity;
+
+impl ClaimPolicy for Capacity {
+    fn name(&self) -> &'static str {
+        "capacity"
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        if request.load.inflight_proofs >= request.load.maximum_concurrent_proofs {
+            return ClaimDecision::Skip(format!(
+                "{} proofs in flight",
+                request.load.inflight_proofs
+            ));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Skips requests whose tip is below `min_tip`.
+pub struct MinimumTip {
+    pub min_tip: u64,
+}
+
+impl ClaimPolicy for MinimumTip {
+    fn name(&self) -> &'static str {
+        "minimum_tip"
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        let tip = request.request.tip();
+        if tip < self.min_tip {
+            return ClaimDecision::Skip(format!("tip {} below {}", tip, self.min_tip));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Skips requests whose tip is below `min_tip_per_million_cycles` for the estimated cycles, when
+/// there is an estimate.
+pub struct MinimumCycleTip {
+    pub min_tip_per_million_cycles: u64,
+}
+
+impl ClaimPolicy for MinimumCycleTip {
+    fn name(&self) -> &'static str {
+        "minimum_cycle_tip"
+    }
+
+    fn needs_estimate(&self) -> bool {
+        true
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        let tip = request.request.tip();
+        if let Some(cycles) = request.estimated_cycles {
+            let required = self.min_tip_per_million_cycles as u128 * cycles as u128 / 1_000_000;
+            if (tip as u128) < required {
+                return ClaimDecision::Skip(format!(
+                    "tip {} below {} for {} cycles",
+                    tip, required, cycles
+                ));
+            }
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Claims only images in `allowed` when it is not empty, and never images in `denied`.
+pub struct ImageFilter {
+    pub allowed: HashSet<String>,
+    pub denied: HashSet<String>,
+}
+
+impl ClaimPolicy for ImageFilter {
+    fn name(&self) -> &'static str {
+        "image_filter"
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        if self.denied.contains(request.image_id) {
+            return ClaimDecision::Skip(format!("image {} is denied", request.image_id));
+        }
+        if !self.allowed.is_empty() && !self.allowed.contains(request.image_id) {
+            return ClaimDecision::Skip(format!("image {} is not allowed", request.image_id));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Claims only for requesters in `allowed` when it is not empty, and never for those in `denied`.
+pub struct RequesterFilter {
+    pub allowed: HashSet<Pubkey>,
+    pub denied: HashSet<Pubkey>,
+}
+
+impl ClaimPolicy for RequesterFilter {
+    fn name(&self) -> &'static str {
+        "requester_filter"
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        if self.denied.contains(&request.requester) {
+            return ClaimDecision::Skip(format!("requester {} is denied", request.requester));
+        }
+        if !self.allowed.is_empty() && !self.allowed.contains(&request.requester) {
+            return ClaimDecision::Skip(format!("requester {} is not allowed", request.requester));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Skips requests that expire within `min_blocks_to_expiry` blocks or before the block the claim
+/// would commit to.
+pub struct DeadlineFeasibility {
+    pub min_blocks_to_expiry: u64,
+}
+
+impl ClaimPolicy for DeadlineFeasibility {
+    fn name(&self) -> &'static str {
+        "deadline"
+    }
+
+    fn needs_estimate(&self) -> bool {
+        true
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        let expiry = request.request.max_block_height();
+        let remaining = expiry.saturating_sub(request.current_block);
+        if remaining <= self.min_blocks_to_expiry {
+            return ClaimDecision::Skip(format!(
+                "expires in {} blocks, at least {} are needed",
+                remaining,
+                self.min_blocks_to_expiry + 1
+            ));
+        }
+        if let Some(commitment) = request.block_commitment.filter(|c| *c > expiry) {
+            return ClaimDecision::Skip(format!(
+                "commitment {} is past expiry {}",
+                commitment, expiry
+            ));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+/// Skips every request while the signer holds less than `min_balance_lamports`.
+pub struct MinimumBalance {
+    pub min_balance_lamports: u64,
+}
+
+impl ClaimPolicy for MinimumBalance {
+    fn name(&self) -> &'static str {
+        "minimum_balance"
+    }
+
+    fn decide(&self, request: &ClaimRequest) -> ClaimDecision {
+        if request.balance < self.min_balance_lamports {
+            return ClaimDecision::Skip(format!(
+                "balance {} below {}",
+                request.balance, self.min_balance_lamports
+            ));
+        }
+        ClaimDecision::Claim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bonsol_interface::bonsol_schema::{root_as_execution_request_v1, ExecutionRequestV1T},
+        flatbuffers::FlatBufferBuilder,
+    };
+
+    fn request_bytes(tip: u64, max_block_height: u64) -> Vec<u8> {
+        let mut er = ExecutionRequestV1T::default();
+        er.tip = tip;
+        er.max_block_height = max_block_height;
+        er.execution_id = Some("policy".to_string());
+        er.image_id = Some("image".to_string());
+        let mut fbb = FlatBufferBuilder::new();
+        let root = er.pack(&mut fbb);
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
+    }
+
+    fn claim_request<'a>(bytes: &'a [u8], requester: Pubkey) -> ClaimRequest<'a> {
+        ClaimRequest {
+            request: root_as_execution_request_v1(bytes).unwrap(),
+            requester,
+            image_id: "image",
+            estimated_cycles: Some(4_000_000),
+            block_commitment: Some(150),
+            current_block: 100,
+            load: NodeLoad {
+                inflight_proofs: 0,
+                maximum_concurrent_proofs: 1,
+            },
+            balance: 1_000_000,
+        }
+    }
+
+    fn config() -> ClaimPolicyConfig {
+        ClaimPolicyConfig {
+            min_tip: 1000,
+            min_tip_per_million_cycles: 500,
+            denied_images: vec!["image".to_string()],
+            min_blocks_to_expiry: 10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_config_claims() {
+        let policies = ClaimPolicies::from_config(&ClaimPolicyConfig::default()).unwrap();
+        let bytes = request_bytes(0, 200);
+        let mut request = claim_request(&bytes, Pubkey::new_unique());
+        assert_eq!(
+            policies.decide_before_execution(&request),
+            ClaimDecision::Claim
+        );
+        assert_eq!(
+            policies.decide_after_execution(&request),
+            ClaimDecision::Claim
+        );
+        request.load.inflight_proofs = 1;
+        assert_eq!(
+            policies.decide_before_execution(&request),
+            ClaimDecision::Skip("capacity: 1 proofs in flight".to_string())
+        );
+    }
+
+    #[test]
+    fn test_first_skip_wins() {
+        let policies = ClaimPolicies::from_config(&config()).unwrap();
+        let bytes = request_bytes(500, 200);
+        let request = claim_request(&bytes, Pubkey::new_unique());
+        assert_eq!(
+            policies.decide_before_execution(&request),
+            ClaimDecision::Skip("minimum_tip: tip 500 below 1000".to_string())
+        );
+        let bytes = request_bytes(1500, 200);
+        let request = claim_request(&bytes, Pubkey::new_unique());
+        assert_eq!(
+            policies.decide_before_execution(&request),
+            ClaimDecision::Skip("image_filter: image image is denied".to_string())
+        );
+    }
+
+    #[test]
+    fn test_estimate_policies_run_after_execution() {
+        let policies = ClaimPolicies::from_config(&ClaimPolicyConfig {
+            denied_images: vec![],
+            ..config()
+        })
+        .unwrap();
+        let bytes = request_bytes(1500, 105);
+        let mut request = claim_request(&bytes, Pubkey::new_unique());
+        request.estimated_cycles = None;
+        request.block_commitment = None;
+        assert_eq!(
+            policies.decide_before_execution(&request),
+            ClaimDecision::Claim
+        );
+        assert_eq!(
+            policies.decide_after_execution(&request),
+            ClaimDecision::Skip(
+                "deadline: expires in 5 blocks, at least 11 are needed".to_string()
+            )
+        );
+        let bytes = request_bytes(1500, 200);
+        let mut request = claim_request(&bytes, Pubkey::new_unique());
+        assert_eq!(
+            policies.decide_after_execution(&request),
+            ClaimDecision::Skip(
+                "minimum_cycle_tip: tip 1500 below 2000 for 4000000 cycles".to_string()
+            )
+        );
+        request.estimated_cycles = None;
+        assert_eq!(
+            policies.decide_after_execution(&request),
+            ClaimDecision::Claim
+        );
+    }
+
+    #[test]
+    fn test_deadline() {
+        let policy = DeadlineFeasibility {
+            min_blocks_to_expiry: 10,
+        };
+        let bytes = request_bytes(0, 110);
+        let request = claim_request(&bytes, Pubkey::new_unique());
+        assert!(matches!(policy.decide(&request), ClaimDecision::Skip(_)));
+        let bytes = request_bytes(0, 140);
+        let request = claim_request(&bytes, Pubkey::new_unique());
+        assert!(matches!(policy.decide(&request), ClaimDecision::Skip(_)));
+        let bytes = request_bytes(0, 150);
+        let request = claim_request(&bytes, Pubkey::new_unique());
+        assert_eq!(policy.decide(&request), ClaimDecision::Claim);
+    }
+
+    #[test]
+    fn test_requester_lists() {
+        let allowed = Pubkey::new_unique();
+        let policies = ClaimPolicies::from_config(&ClaimPolicyConfig {
+            allowed_requesters: vec![allowed.to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+        let bytes = request_bytes(0, 200);
+        assert_eq!(
+            policies.decide_before_execution(&claim_request(&bytes, allowed)),
+            ClaimDecision::Claim
+        );
+        assert!(matches!(
+            policies.decide_before_execution(&claim_request(&bytes, Pubkey::new_unique())),
+            ClaimDecision::Skip(_)
+        ));
+        assert!(ClaimPolicies::from_config(&ClaimPolicyConfig {
+            denied_requesters: vec!["not a key".to_string()],
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
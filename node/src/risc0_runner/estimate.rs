@@ -0,0 +1,255 @@
+//! Executes a request's guest with its public inputs before claiming it, without proving. The
+//! real segment and cycle counts feed the claim decision, and guests that fail or run past the
+//! cycle limit are never claimed. Results are cached per image and input digest.
+
+use {
+    anyhow::Result,
+    bonsol_prover::{
+        image::Image,
+        input_resolver::{resolved_input_digest, ProgramInput},
+        prover::new_risc0_exec_env_with_limit,
+    },
+    risc0_binfmt::MemoryImage,
+    risc0_zkvm::ExitCode,
+    std::{
+        collections::{BTreeMap, HashMap},
+        io,
+        sync::Mutex,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CycleEstimate {
+    pub segments: u64,
+    pub total_cycles: u64,
+    pub user_cycles: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DryRun {
+    Completed(CycleEstimate),
+    /// The guest failed or ran past the cycle limit, no proof of it is worth claiming.
+    Failed(String),
+    /// Some inputs are only served after claiming, or dry runs are disabled.
+    Unavailable,
+}
+
+type CacheKey = (String, [u8; 32]);
+
+/// Dry run results by image id and input digest, the least recently used goes first when full.
+struct DryRunCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<CacheKey, (u64, DryRun)>,
+    /// Entries by the tick of their last use.
+    recency: BTreeMap<u64, CacheKey>,
+}
+
+impl DryRunCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn touch(&mut self, key: &CacheKey) -> Option<&mut (u64, DryRun)> {
+        let entry = self.entries.get_mut(key)?;
+        self.recency.remove(&entry.0);
+        self.tick += 1;
+        entry.0 = self.tick;
+        self.recency.insert(self.tick, key.clone());
+        Some(entry)
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<DryRun> {
+        self.touch(key).map(|(_, result)| result.clone())
+    }
+
+    fn insert(&mut self, key: CacheKey, result: DryRun) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some(entry) = self.touch(&key) {
+            entry.1 = result;
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            let Some((_, lru)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&lru);
+        }
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        self.entries.insert(key, (self.tick, result));
+    }
+}
+
+pub struct CycleEstimator {
+    /// Cycles a dry run may take, 0 disables dry runs.
+    cycle_limit: u64,
+    cache: Mutex<DryRunCache>,
+}
+
+impl CycleEstimator {
+    pub fn new(cycle_limit: u64, cache_size: usize) -> Self {
+        Self {
+            cycle_limit,
+            cache: Mutex::new(DryRunCache::new(cache_size)),
+        }
+    }
+
+    pub async fn estimate(&self, image: &Image, inputs: &[ProgramInput]) -> Result<DryRun> {
+        if self.cycle_limit == 0
+            || inputs
+                .iter()
+                .any(|i| matches!(i, ProgramInput::Unresolved(_)))
+        {
+            return Ok(DryRun::Unavailable);
+        }
+        let key = (image.id.clone(), resolved_input_digest(inputs)?);
+        if let Some(cached) = self.cache.lock().unwrap().get(&key) {
+            return Ok(cached);
+        }
+        let memory_image = image.get_memory_image()?;
+        let inputs = inputs.to_vec();
+        let cycle_limit = self.cycle_limit;
+        let result =
+            tokio::task::spawn_blocking(move || dry_run(memory_image, inputs, cycle_limit)).await?;
+        self.cache.lock().unwrap().insert(key, result.clone());
+        Ok(result)
+    }
+}
+
+fn dry_run(memory_image: MemoryImage, inputs: Vec<ProgramInput>, cycle_limit: u64) -> DryRun {
+    let session = new_risc0_exec_env_with_limit(
+        memory_image,
+        inputs,
+        io::sink(),
+        io::sink(),
+        Some(cycle_limit),
+    )
+    .and_then(|mut exec| exec.run());
+    match session {
+        Ok(session) => match session.exit_code {
+            ExitCode::Halted(_) | ExitCode::Paused(_) => {
+                let stats = session.stats();
+                DryRun::Completed(CycleEstimate {
+                    segments: stats.segments as u64,
+                    total_cycles: stats.total_cycles,
+                    user_cycles: stats.user_cycles,
+                })
+            }
+            exit_code => DryRun::Failed(format!("exited with {:?}", exit_code)),
+        },
+        Err(e) => DryRun::Failed(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, bonsol_interface::bonsol_schema::ProgramInputType,
+        bonsol_prover::input_resolver::ResolvedInput,
+    };
+
+    fn input(data: &[u8]) -> Vec<ProgramInput> {
+        vec![ProgramInput::Resolved(ResolvedInput {
+            index: 0,
+            data: data.to_vec(),
+            input_type: ProgramInputType::Public,
+        })]
+    }
+
+    fn completed(segments: u64) -> DryRun {
+        DryRun::Completed(CycleEstimate {
+            segments,
+            total_cycles: segments << 20,
+            user_cycles: segments << 19,
+        })
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let key = |image: &str, data: &[u8]| {
+            (
+                image.to_string(),
+                resolved_input_digest(&input(data)).unwrap(),
+            )
+        };
+        let mut cache = DryRunCache::new(2);
+        cache.insert(key("a", b"1"), completed(1));
+        cache.insert(key("a", b"2"), completed(2));
+        // keyed by image and input digest
+        assert_eq!(cache.get(&key("a", b"1")), Some(completed(1)));
+        assert_eq!(cache.get(&key("b", b"1")), None);
+        // "a" "1" was used last, so "a" "2" goes
+        cache.insert(key("b", b"1"), DryRun::Failed("exited".to_string()));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key("a", b"2")), None);
+        assert_eq!(cache.get(&key("a", b"1")), Some(completed(1)));
+        assert_eq!(
+            cache.get(&key("b", b"1")),
+            Some(DryRun::Failed("exited".to_string()))
+        );
+        // replacing an entry does not evict another
+        cache.insert(key("a", b"1"), completed(3));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key("a", b"1")), Some(completed(3)));
+        assert_eq!(cache.recency.len(), 2);
+
+        let mut disabled = DryRunCache::new(0);
+        disabled.insert(key("a", b"1"), completed(1));
+        assert_eq!(disabled.len(), 0);
+    }
+
+    /// Set `BONSOL_TEST_IMAGE` to the built `images/simple` binary.
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "requires BONSOL_TEST_IMAGE"]
+    async fn test_dry_run_simple_image() {
+        let image_path = std::env::var("BONSOL_TEST_IMAGE").unwrap();
+        let image = Image::new(image_path.into()).await.unwrap();
+        let inputs = vec![
+            ProgramInput::Resolved(ResolvedInput {
+                index: 0,
+                data: br#"{"attestation":"test"}"#.to_vec(),
+                input_type: ProgramInputType::Public,
+            }),
+            ProgramInput::Resolved(ResolvedInput {
+                index: 1,
+                data: b"test".to_vec(),
+                input_type: ProgramInputType::Private,
+            }),
+        ];
+        let estimator = CycleEstimator::new(1 << 30, 1);
+        let DryRun::Completed(estimate) = estimator.estimate(&image, &inputs).await.unwrap() else {
+            panic!("dry run did not complete");
+        };
+        assert!(estimate.segments > 0);
+        assert!(estimate.total_cycles >= estimate.user_cycles);
+        assert_eq!(estimator.cache.lock().unwrap().len(), 1);
+        assert_eq!(
+            estimator.estimate(&image, &inputs).await.unwrap(),
+            DryRun::Completed(estimate)
+        );
+
+        let capped = CycleEstimator::new(estimate.total_cycles / 2, 1);
+        assert!(matches!(
+            capped.estimate(&image, &inputs).await.unwrap(),
+            DryRun::Failed(_)
+        ));
+        let disabled = CycleEstimator::new(0, 1);
+        assert_eq!(
+            disabled.estimate(&image, &inputs).await.unwrap(),
+            DryRun::Unavailable
+        );
+    }
+}
@@ -0,0 +1,488 @@
+//! The images a node can prove. Images on disk are tracked in an index so startup does not decode
+//! every ELF, they are only loaded into memory when a request needs them. Loaded images are
+//! evicted least recently used first under a memory budget, those idle past the compression ttl
+//! drop their ELF bytes, and the least recently used files are deleted past the disk quota unless a
+//! job still needs them. Files are verified through the [`ImageStore`] when first indexed and every
+//! time they are loaded.
+
+use {
+    super::image_store::ImageStore,
+    anyhow::Result,
+    bonsol_prover::image::Image,
+    dashmap::DashMap,
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{BTreeMap, HashSet},
+        fs,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
+    tracing::{info, warn},
+};
+
+/// Kept in the image folder, files starting with a dot are never read as images.
+const INDEX_FILE: &str = ".index.json";
+
+/// The images unfinished jobs still need, their files are kept over the disk quota.
+pub type ImagesInUse = Box<dyn Fn() -> Result<HashSet<String>> + Send + Sync>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// File name in the image folder.
+    pub file: String,
+    pub file_size: u64,
+    /// Size of the loaded memory image.
+    pub memory_size: u64,
+    /// Unix seconds.
+    pub last_used: u64,
+}
+
+struct LoadedImage {
+    image: Arc<Image>,
+    last_used: Instant,
+}
+
+impl LoadedImage {
+    fn memory_size(&self) -> u64 {
+        self.image.size + self.image.bytes().map_or(0, |b| b.len() as u64)
+    }
+}
+
+pub struct ImageCache {
+    store: ImageStore,
+    memory_budget: u64,
+    /// Bytes of image files kept on disk, 0 for no limit.
+    disk_quota: u64,
+    compression_ttl: Duration,
+    in_use: ImagesInUse,
+    index: Mutex<BTreeMap<String, IndexEntry>>,
+    loaded: DashMap<String, LoadedImage>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+impl ImageCache {
+    /// Reads the index in the store's folder and verifies only the files it does not list, or
+    /// whose size changed since they were indexed.
+    pub async fn open(
+        store: ImageStore,
+        memory_budget: u64,
+        disk_quota: u64,
+        compression_ttl: Duration,
+        in_use: ImagesInUse,
+    ) -> Result<Self> {
+        let index_path = store.folder().join(INDEX_FILE);
+        let mut index: BTreeMap<String, IndexEntry> = match fs::read(&index_path) {
+            Ok(data) => serde_json::from_slice(&data).unwrap_or_else(|e| {
+                warn!("Ignoring unreadable image index: {:?}", e);
+                BTreeMap::new()
+            }),
+            Err(_) => BTreeMap::new(),
+        };
+        let mut files = vec![];
+        for entry in fs::read_dir(store.folder())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type()?.is_file() && !name.starts_with('.') {
+                files.push((name, entry.metadata()?.len()));
+            }
+        }
+        index.retain(|id, e| {
+            *id == e.file
+                && files
+                    .iter()
+                    .any(|(name, size)| *name == e.file && *size == e.file_size)
+        });
+        for (name, file_size) in files {
+            if index.contains_key(&name) {
+                continue;
+            }
+            if let Some(img) = store.verify(&name).await? {
+                info!("Indexed image: {}", &img.id);
+                index.insert(
+                    img.id.clone(),
+                    IndexEntry {
+                        file: img.id.clone(),
+                        file_size,
+                        memory_size: img.size,
+                        last_used: now(),
+                    },
+                );
+            }
+        }
+        info!("{} images available", index.len());
+        let cache = Self {
+            store,
+            memory_budget,
+            disk_quota,
+            compression_ttl,
+            in_use,
+            index: Mutex::new(index),
+            loaded: DashMap::new(),
+        };
+        cache.save_index()?;
+        Ok(cache)
+    }
+
+    fn save_index(&self) -> Result<()> {
+        let json = serde_json::to_vec_pretty(&*self.index.lock().unwrap())?;
+        fs::write(self.store.folder().join(INDEX_FILE), json)?;
+        Ok(())
+    }
+
+    /// Whether the image is on disk, loaded or not.
+    pub fn contains(&self, image_id: &str) -> bool {
+        self.index.lock().unwrap().contains_key(image_id)
+    }
+
+    pub fn store(&self) -> &ImageStore {
+        &self.store
+    }
+
+    /// The image with its ELF bytes, loaded from disk if it is not in memory. A file that no
+    /// longer decodes to `image_id` is quarantined and the image is gone.
+    pub async fn get(&self, image_id: &str) -> Result<Option<Arc<Image>>> {
+        let file = {
+            let mut index = self.index.lock().unwrap();
+            let Some(entry) = index.get_mut(image_id) else {
+                return Ok(None);
+            };
+            entry.last_used = now();
+            entry.file.clone()
+        };
+        if let Some(mut loaded) = self.loaded.get_mut(image_id) {
+            if loaded.image.bytes().is_some() {
+                loaded.last_used = Instant::now();
+                return Ok(Some(loaded.image.clone()));
+            }
+        }
+        let image = match Image::new(self.store.folder().join(&file)).await {
+            Ok(image) if image.id == image_id => image,
+            Ok(image) => {
+                self.reject(image_id, &file, &format!("decodes to image {}", image.id))?;
+                return Ok(None);
+            }
+            Err(e) => {
+                self.reject(image_id, &file, &format!("not a risc0 image: {}", e))?;
+                return Ok(None);
+            }
+        };
+        Ok(Some(self.admit(image)))
+    }
+
+    fn reject(&self, image_id: &str, file: &str, reason: &str) -> Result<()> {
+        self.loaded.remove(image_id);
+        self.index.lock().unwrap().remove(image_id);
+        self.save_index()?;
+        self.store.quarantine(file, reason)
+    }
+
+    /// Adds an image the store has committed.
+    pub fn insert(&self, mut image: Image) -> Result<Arc<Image>> {
+        let path = self.store.path(&image.id);
+        let file_size = fs::metadata(&path)?.len();
+        image.path = path;
+        self.index.lock().unwrap().insert(
+            image.id.clone(),
+            IndexEntry {
+                file: image.id.clone(),
+                file_size,
+                memory_size: image.size,
+                last_used: now(),
+            },
+        );
+        self.save_index()?;
+        let image = self.admit(image);
+        self.enforce_disk_quota()?;
+        Ok(image)
+    }
+
+    fn admit(&self, image: Image) -> Arc<Image> {
+        let id = image.id.clone();
+        let image = Arc::new(image);
+        self.loaded.insert(
+            id.clone(),
+            LoadedImage {
+                image: image.clone(),
+                last_used: Instant::now(),
+            },
+        );
+        self.evict(&id);
+        image
+    }
+
+    /// Drops least recently used images from memory until the rest fit the budget, `keep` stays.
+    fn evict(&self, keep: &str) {
+        loop {
+            let used: u64 = self.loaded.iter().map(|l| l.memory_size()).sum();
+            if used <= self.memory_budget {
+                return;
+            }
+            let lru = self
+                .loaded
+                .iter()
+                .filter(|l| l.key() != keep)
+                .min_by_key(|l| l.last_used)
+                .map(|l| l.key().clone());
+            let Some(lru) = lru else {
+                return;
+            };
+            info!("Evicting image {} from memory", lru);
+            self.loaded.remove(&lru);
+        }
+    }
+
+    /// Deletes the least recently used image files no job needs until the rest fit the disk quota.
+    fn enforce_disk_quota(&self) -> Result<()> {
+        if self.disk_quota == 0 {
+            return Ok(());
+        }
+        let in_use = (self.in_use)()?;
+        let mut removed = vec![];
+        {
+            let mut index = self.index.lock().unwrap();
+            while index.values().map(|e| e.file_size).sum::<u64>() > self.disk_quota
+                && index.len() > 1
+            {
+                let lru = index
+                    .iter()
+                    .filter(|(id, _)| !in_use.contains(*id))
+                    .min_by_key(|(_, e)| e.last_used)
+                    .map(|(id, _)| id.clone());
+                let Some(lru) = lru else {
+                    break;
+                };
+                if let Some(entry) = index.remove(&lru) {
+                    removed.push((lru, entry.file));
+                }
+            }
+        }
+        for (id, file) in &removed {
+            info!("Deleting image {} over the disk quota", id);
+            self.loaded.remove(id);
+            fs::remove_file(self.store.folder().join(file))?;
+        }
+        if !removed.is_empty() {
+            self.save_index()?;
+        }
+        Ok(())
+    }
+
+    /// Drops the ELF bytes of images idle past the compression ttl, enforces the disk quota and
+    /// saves the last use times. Run periodically.
+    pub fn maintain(&self) -> Result<()> {
+        for mut loaded in self.loaded.iter_mut() {
+            if loaded.last_used.elapsed() < self.compression_ttl {
+                continue;
+            }
+            if let Some(image) = Arc::get_mut(&mut loaded.image) {
+                if image.bytes().is_some() {
+                    info!("Compressing idle image {}", image.id);
+                    image.compress();
+                }
+            }
+        }
+        self.enforce_disk_quota()?;
+        self.save_index()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bytes::Bytes, risc0_binfmt::ProgramBinary};
+
+    /// A risc0 image of a single word, distinct words give distinct image ids.
+    fn tiny_image(word: u32) -> Vec<u8> {
+        const VADDR: u32 = 0x1000;
+        // elf32 header, one PT_LOAD program header, the word
+        let mut elf = vec![0x7f, b'E', b'L', b'F', 1, 1, 1];
+        elf.resize(16, 0);
+        elf.extend_from_slice(&2u16.to_le_bytes()); // ET_EXEC
+        elf.extend_from_slice(&243u16.to_le_bytes()); // EM_RISCV
+        elf.extend_from_slice(&1u32.to_le_bytes());
+        elf.extend_from_slice(&VADDR.to_le_bytes()); // entry
+        elf.extend_from_slice(&52u32.to_le_bytes()); // program headers
+        elf.extend_from_slice(&0u32.to_le_bytes()); // no section headers
+        elf.extend_from_slice(&0u32.to_le_bytes());
+        for half in [52u16, 32, 1, 40, 0, 0] {
+            elf.extend_from_slice(&half.to_le_bytes());
+        }
+        for field in [1u32, 84, VADDR, VADDR, 4, 4, 5, 4] {
+            elf.extend_from_slice(&field.to_le_bytes());
+        }
+        elf.extend_from_slice(&word.to_le_bytes());
+        ProgramBinary::new(&elf, &elf).encode()
+    }
+
+    /// Writes images under names other than their ids, as an operator copying files would.
+    fn write_images(dir: &std::path::Path, words: &[u32]) -> Vec<(String, u64)> {
+        words
+            .iter()
+            .map(|&word| {
+                let bytes = tiny_image(word);
+                fs::write(dir.join(format!("image-{}", word)), &bytes).unwrap();
+                let image = Image::from_bytes(Bytes::from(bytes.clone())).unwrap();
+                (image.id, image.size + bytes.len() as u64)
+            })
+            .collect()
+    }
+
+    fn no_jobs() -> ImagesInUse {
+        Box::new(|| Ok(HashSet::new()))
+    }
+
+    #[tokio::test]
+    async fn test_memory_lru_eviction_and_compression() {
+        let dir = tempfile::tempdir().unwrap();
+        let images = write_images(dir.path(), &[1, 2, 3]);
+        let (a, b, c) = (&images[0].0, &images[1].0, &images[2].0);
+        // room for two loaded images
+        let budget = images[0].1 + images[1].1;
+        let store = ImageStore::open(dir.path()).unwrap();
+        let cache = ImageCache::open(store, budget, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        assert!(images.iter().all(|(id, _)| cache.contains(id)));
+        assert!(images.iter().all(|(id, _)| dir.path().join(id).exists()));
+        assert!(cache.loaded.is_empty());
+
+        cache.get(a).await.unwrap().unwrap();
+        cache.get(b).await.unwrap().unwrap();
+        cache.get(a).await.unwrap().unwrap();
+        // b was used least recently
+        cache.get(c).await.unwrap().unwrap();
+        assert_eq!(cache.loaded.len(), 2);
+        assert!(cache.loaded.contains_key(a));
+        assert!(!cache.loaded.contains_key(b));
+
+        // idle images lose their bytes, unless a job holds them
+        let held = cache.get(c).await.unwrap().unwrap();
+        cache.maintain().unwrap();
+        assert!(cache.loaded.get(a).unwrap().image.bytes().is_none());
+        assert!(cache.loaded.get(c).unwrap().image.bytes().is_some());
+        drop(held);
+        // the next use loads them again
+        assert!(cache.get(a).await.unwrap().unwrap().bytes().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_index_is_reused() {
+        let dir = tempfile::tempdir().unwrap();
+        let images = write_images(dir.path(), &[1]);
+        fs::write(dir.path().join("garbage"), b"not an elf").unwrap();
+        let store = ImageStore::open(dir.path()).unwrap();
+        let cache = ImageCache::open(store, u64::MAX, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        let id = &images[0].0;
+        assert_eq!(cache.index.lock().unwrap().len(), 1);
+        assert!(!dir.path().join("garbage").exists());
+        assert!(cache.get("missing").await.unwrap().is_none());
+
+        // an indexed file is not decoded on open, a changed one is verified again
+        let listed = fs::read(dir.path().join(id)).unwrap();
+        fs::write(dir.path().join(id), vec![0; listed.len()]).unwrap();
+        let store = ImageStore::open(dir.path()).unwrap();
+        let reopened = ImageCache::open(store, u64::MAX, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        assert!(reopened.contains(id));
+        // loading catches the corrupt file
+        assert!(reopened.get(id).await.unwrap().is_none());
+        assert!(!reopened.contains(id));
+
+        fs::write(dir.path().join("late"), tiny_image(2)).unwrap();
+        let store = ImageStore::open(dir.path()).unwrap();
+        let reopened = ImageCache::open(store, u64::MAX, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        assert_eq!(reopened.index.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_disk_quota_spares_images_in_use() {
+        let dir = tempfile::tempdir().unwrap();
+        let images = write_images(dir.path(), &[1, 2, 3, 4]);
+        let ids: Vec<_> = images.iter().map(|(id, _)| id.clone()).collect();
+        let file_size = tiny_image(1).len() as u64;
+        let in_use = HashSet::from([ids[0].clone()]);
+        let store = ImageStore::open(dir.path()).unwrap();
+        let cache = ImageCache::open(
+            store,
+            u64::MAX,
+            2 * file_size,
+            Duration::from_secs(3600),
+            Box::new(move || Ok(in_use.clone())),
+        )
+        .await
+        .unwrap();
+        cache.get(&ids[1]).await.unwrap().unwrap();
+        for (last_used, id) in ids.iter().enumerate() {
+            cache.index.lock().unwrap().get_mut(id).unwrap().last_used = last_used as u64;
+        }
+
+        // the oldest image has a job, the next two oldest go
+        cache.maintain().unwrap();
+        let kept: Vec<_> = ids.iter().filter(|id| cache.contains(id)).collect();
+        assert_eq!(kept, vec![&ids[0], &ids[3]]);
+        assert!(dir.path().join(&ids[0]).exists());
+        assert!(!dir.path().join(&ids[1]).exists());
+        assert!(!dir.path().join(&ids[2]).exists());
+        assert!(cache.loaded.is_empty());
+
+        // the index on disk matches
+        let store = ImageStore::open(dir.path()).unwrap();
+        let reopened = ImageCache::open(store, u64::MAX, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        assert_eq!(reopened.index.lock().unwrap().len(), 2);
+    }
+
+    /// Set `BONSOL_TEST_IMAGE` to the built `images/simple` binary.
+    #[tokio::test]
+    #[ignore = "requires BONSOL_TEST_IMAGE"]
+    async fn test_index_lazy_load_and_eviction() {
+        let image_path = std::env::var("BONSOL_TEST_IMAGE").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        fs::copy(&image_path, dir.path().join("simple")).unwrap();
+        fs::write(dir.path().join("garbage"), b"not an elf").unwrap();
+
+        let store = ImageStore::open(dir.path()).unwrap();
+        let cache = ImageCache::open(store, u64::MAX, 0, Duration::ZERO, no_jobs())
+            .await
+            .unwrap();
+        // the image is renamed to its id and the garbage quarantined
+        let id = cache.index.lock().unwrap().keys().next().unwrap().clone();
+        assert_eq!(cache.index.lock().unwrap().len(), 1);
+        assert!(dir.path().join(&id).exists());
+        assert!(!dir.path().join("garbage").exists());
+        assert!(cache.contains(&id));
+        assert!(cache.loaded.is_empty());
+        assert!(cache.get("missing").await.unwrap().is_none());
+
+        // loading compresses idle images and reloads their bytes on the next use
+        let image = cache.get(&id).await.unwrap().unwrap();
+        assert!(image.bytes().is_some());
+        drop(image);
+        cache.maintain().unwrap();
+        assert!(cache.loaded.get(&id).unwrap().image.bytes().is_none());
+        assert!(cache.get(&id).await.unwrap().unwrap().bytes().is_some());
+
+        // the index spares decoding on the next start, a zero budget keeps only the latest image
+        let store = ImageStore::open(dir.path()).unwrap();
+        let reopened = ImageCache::open(store, 0, 0, Duration::from_secs(3600), no_jobs())
+            .await
+            .unwrap();
+        assert_eq!(
+            reopened.index.lock().unwrap()[&id].file,
+            cache.index.lock().unwrap()[&id].file
+        );
+        reopened.get(&id).await.unwrap().unwrap();
+        assert_eq!(reopened.loaded.len(), 1);
+    }
+}
@@ -0,0 +1,204 @@
+//! Image files on disk, each named by its image id. Downloads are verified against their
+//! deployment and written to a temp file that is renamed into place, so the folder never holds a
+//! partial or mismatched ELF under an image id. Files that fail verification are moved to a
+//! quarantine directory for inspection instead of being loaded or deleted.
+
+use {
+    super::Risc0RunnerError,
+    anyhow::Result,
+    bonsol_prover::image::Image,
+    bytes::Bytes,
+    std::{
+        fs,
+        io::Write,
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicU64, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    tracing::{info, warn},
+};
+
+const QUARANTINE_DIR: &str = ".quarantine";
+/// Writes in progress, left behind only when the node stopped mid write.
+const TEMP_PREFIX: &str = ".tmp-";
+
+/// Tells apart concurrent writes of the same image.
+static WRITE_SEQ: AtomicU64 = AtomicU64::new(0);
+
+pub struct ImageStore {
+    folder: PathBuf,
+    quarantine: PathBuf,
+}
+
+impl ImageStore {
+    /// Creates the folder and its quarantine directory and removes temp files an interrupted
+    /// write left behind.
+    pub fn open(folder: impl AsRef<Path>) -> Result<Self> {
+        let folder = folder.as_ref().to_path_buf();
+        let quarantine = folder.join(QUARANTINE_DIR);
+        fs::create_dir_all(&quarantine)?;
+        for entry in fs::read_dir(&folder)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with(TEMP_PREFIX) {
+                info!("Removing partial image write {:?}", entry.path());
+                fs::remove_file(entry.path())?;
+            }
+        }
+        Ok(Self { folder, quarantine })
+    }
+
+    pub fn folder(&self) -> &Path {
+        &self.folder
+    }
+
+    pub fn path(&self, image_id: &str) -> PathBuf {
+        self.folder.join(image_id)
+    }
+
+    /// Verifies downloaded bytes against the deployment's image id and size and moves them into
+    /// place. Bytes that do not match are quarantined and the deployment fails.
+    pub fn commit(&self, expected_id: &str, expected_size: u64, bytes: Bytes) -> Result<Image> {
+        if bytes.len() as u64 != expected_size {
+            let reason = format!("size {} expected {}", bytes.len(), expected_size);
+            self.quarantine_bytes(expected_id, &bytes, &reason)?;
+            return Err(Risc0RunnerError::ImageVerificationFailed(reason).into());
+        }
+        let mut image = match Image::from_bytes(bytes.clone()) {
+            Ok(image) => image,
+            Err(e) => {
+                let reason = format!("not a risc0 image: {}", e);
+                self.quarantine_bytes(expected_id, &bytes, &reason)?;
+                return Err(Risc0RunnerError::ImageVerificationFailed(reason).into());
+            }
+        };
+        if image.id != expected_id {
+            let reason = format!("image id {} expected {}", image.id, expected_id);
+            self.quarantine_bytes(expected_id, &bytes, &reason)?;
+            return Err(Risc0RunnerError::ImageVerificationFailed(reason).into());
+        }
+        let path = self.path(&image.id);
+        self.write_atomic(&path, &bytes)?;
+        image.path = path;
+        Ok(image)
+    }
+
+    /// Checks that `file` in the folder is an image named by its id. An image under another name
+    /// is renamed to its id, or quarantined when that id is already taken. Anything that does not
+    /// decode is quarantined.
+    pub async fn verify(&self, file: &str) -> Result<Option<Image>> {
+        let mut image = match Image::new(self.folder.join(file)).await {
+            Ok(image) => image,
+            Err(e) => {
+                self.quarantine(file, &format!("not a risc0 image: {}", e))?;
+                return Ok(None);
+            }
+        };
+        if image.id != file {
+            let path = self.path(&image.id);
+            if path.exists() {
+                self.quarantine(file, &format!("duplicate of image {}", image.id))?;
+                return Ok(None);
+            }
+            info!("Renaming image file {} to its id {}", file, image.id);
+            fs::rename(self.folder.join(file), &path)?;
+            image.path = path;
+        }
+        Ok(Some(image))
+    }
+
+    /// Moves `file` out of the folder into the quarantine directory.
+    pub fn quarantine(&self, file: &str, reason: &str) -> Result<()> {
+        let target = self.quarantine_path(file);
+        warn!(
+            "Quarantining image file {} to {:?}: {}",
+            file, target, reason
+        );
+        fs::rename(self.folder.join(file), target)?;
+        Ok(())
+    }
+
+    fn quarantine_bytes(&self, name: &str, bytes: &[u8], reason: &str) -> Result<()> {
+        let target = self.quarantine_path(name);
+        warn!(
+            "Quarantining download of {} to {:?}: {}",
+            name, target, reason
+        );
+        self.write_atomic(&target, bytes)
+    }
+
+    fn quarantine_path(&self, name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        // image ids come from deployments, keep only their file name part
+        let name = Path::new(name)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.quarantine.join(format!("{}.{}", name, stamp))
+    }
+
+    /// Writes and syncs a temp file in the folder, then renames it over `path`.
+    fn write_atomic(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let temp = self.folder.join(format!(
+            "{}{}.{}",
+            TEMP_PREFIX,
+            name,
+            WRITE_SEQ.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = fs::File::create(&temp).and_then(|mut f| {
+            f.write_all(bytes)?;
+            f.sync_all()
+        });
+        if let Err(e) = written.and_then(|_| fs::rename(&temp, path)) {
+            let _ = fs::remove_file(&temp);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quarantined(store: &ImageStore) -> Vec<String> {
+        fs::read_dir(&store.quarantine)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_mismatched_downloads_are_quarantined() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".tmp-abc.1"), b"partial").unwrap();
+        let store = ImageStore::open(dir.path()).unwrap();
+        assert!(!dir.path().join(".tmp-abc.1").exists());
+
+        let bytes = Bytes::from_static(b"not an elf");
+        assert!(store.commit("abc", 3, bytes.clone()).is_err());
+        assert!(store.commit("abc", bytes.len() as u64, bytes).is_err());
+        assert!(!store.path("abc").exists());
+        let quarantined = quarantined(&store);
+        assert_eq!(quarantined.len(), 2);
+        assert!(quarantined.iter().all(|f| f.starts_with("abc.")));
+        // nothing but the quarantine directory is left in the image folder
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_files_are_quarantined() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("garbage"), b"not an elf").unwrap();
+        let store = ImageStore::open(dir.path()).unwrap();
+        assert!(store.verify("garbage").await.unwrap().is_none());
+        assert!(!dir.path().join("garbage").exists());
+        assert_eq!(quarantined(&store).len(), 1);
+    }
+}
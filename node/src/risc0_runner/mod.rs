@@ -1,61 +1,79 @@
-mod utils;
+pub mod backend;
+pub mod claim_policy;
+pub mod estimate;
+pub mod image_cache;
+pub mod image_store;
+pub mod sp1;
+pub mod throughput;
 pub mod verify_prover_version;
 
-use anyhow::anyhow;
 use bonsol_interface::prover_version::VERSION_V3_0_3;
 use bonsol_prover::util::{EventChannelTx, LogShipper};
 
 use {
+    bonsol_compression::{
+        self as compression, check_stark_compression_tools_path, check_x86_64arch,
+        CompressedReceipt,
+    },
     solana_sdk::instruction::AccountMeta,
-    utils::{check_stark_compression_tools_path, check_x86_64arch},
 };
 
-use crate::MissingImageStrategy;
+use crate::{
+    job_store::{Job, JobStage, JobStore, ProofRecord},
+    MissingImageStrategy,
+};
+
+use {
+    backend::{ProverBackend, ProverBackends, Risc0Groth16Backend},
+    claim_policy::{ClaimDecision, ClaimPolicies, ClaimRequest, NodeLoad},
+    estimate::{CycleEstimator, DryRun},
+    image_cache::ImageCache,
+    image_store::ImageStore,
+    sp1::Sp1Groth16Backend,
+    throughput::ThroughputModel,
+};
 
 use {
     crate::{
         config::ProverNodeConfig,
         observe::*,
-        risc0_runner::utils::async_to_json,
         transaction_sender::{
             rpc::RpcTransactionSender, status::TransactionStatus,
             transaction_sender::TransactionSender,
         },
     },
     bonsol_interface::{
-        bonsol_schema::{ClaimV1, DeployV1, ExecutionRequestV1},
+        bonsol_schema::{ClaimV1, DeployV1, ExecutionRequestV1, ProofSystem},
+        prover_set::ProverSetV1,
         prover_version::ProverVersion,
+        util::execution_request_digest,
     },
     dashmap::DashMap,
     risc0_binfmt::MemoryImage,
-    risc0_zkvm::{ExitCode, Journal, SuccinctReceipt},
+    risc0_zkvm::{Journal, SuccinctReceipt},
     solana_sdk::{pubkey::Pubkey, signature::Signature},
-    std::{
-        convert::TryInto, env::consts::ARCH, fs, io::Cursor, path::Path, sync::Arc, time::Duration,
-    },
+    std::{collections::HashSet, convert::TryInto, sync::Arc, time::Duration},
 };
 
 use {
     crate::types::{BonsolInstruction, ProgramExec},
     anyhow::Result,
-    bonsol_interface::bonsol_schema::{parse_ix_data, root_as_deploy_v1, ChannelInstructionIxType},
+    bonsol_interface::bonsol_schema::{
+        parse_ix_data, root_as_deploy_v1, root_as_execution_request_v1, ChannelInstructionIxType,
+    },
     bonsol_prover::{
         image::Image,
-        input_resolver::{InputResolver, ProgramInput},
+        input_resolver::{resolved_input_digest, InputResolver, ProgramInput},
         prover::{get_risc0_prover, new_risc0_exec_env},
         util::get_body_max_size,
     },
-    risc0_groth16::{ProofJson, Seal},
     risc0_zkvm::{
         recursion::identity_p254,
         sha::{Digest, Digestible},
         InnerReceipt, MaybePruned, ReceiptClaim, VerifierContext,
     },
-    tempfile::tempdir,
     thiserror::Error,
-    tokio::{
-        fs::File, io::AsyncReadExt, process::Command, sync::mpsc::UnboundedSender, task::JoinHandle,
-    },
+    tokio::{sync::mpsc::UnboundedSender, task::JoinHandle},
     tracing::{error, info, warn},
     verify_prover_version::verify_prover_version,
 };
@@ -74,6 +92,8 @@ pub enum Risc0RunnerError {
     ImgLoadError,
     #[error("Image Data Unavailable")]
     ImageDataUnavailable,
+    #[error("Image does not match its deployment: {0}")]
+    ImageVerificationFailed(String),
     #[error("Image download error")]
     ImageDownloadError(#[from] anyhow::Error),
     #[error("Transaction error")]
@@ -82,8 +102,12 @@ pub enum Risc0RunnerError {
     ProofCompressionError,
     #[error("Error with proof generation")]
     ProofGenerationError,
-    #[error("Invalid prover version {0}, expected {1}")]
-    InvalidProverVersion(ProverVersion, ProverVersion),
+    #[error("Input digest does not match the execution request")]
+    InputDigestMismatch,
+    #[error("Execute instruction does not match the request digest of its execution account")]
+    RequestDigestMismatch,
+    #[error("No prover backend for proof system {0:?}")]
+    UnsupportedProofSystem(ProofSystem),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -103,13 +127,14 @@ pub struct InflightProof {
     pub requester: Pubkey,
     pub program_callback: Option<ProgramExec>,
     pub additional_accounts: Vec<AccountMeta>,
+    pub input_digest: Option<Vec<u8>>,
+    pub proof_system: ProofSystem,
 }
 
 type InflightProofs = Arc<DashMap<String, InflightProof>>;
 type InflightProofRef<'a> = &'a DashMap<String, InflightProof>;
 
-type LoadedImageMap = Arc<DashMap<String, Image>>;
-type LoadedImageMapRef<'a> = &'a DashMap<String, Image>;
+type LoadedImageMap = Arc<ImageCache>;
 
 type InputStagingArea = Arc<DashMap<String, Vec<ProgramInput>>>;
 type InputStagingAreaRef<'a> = &'a DashMap<String, Vec<ProgramInput>>;
@@ -123,6 +148,10 @@ pub struct Risc0Runner {
     input_staging_area: InputStagingArea,
     self_identity: Arc<Pubkey>,
     inflight_proofs: InflightProofs,
+    job_store: Arc<JobStore>,
+    claim_policies: Arc<ClaimPolicies>,
+    backends: Arc<ProverBackends>,
+    throughput: Arc<ThroughputModel>,
     input_resolver: Arc<dyn InputResolver + 'static>,
     stdout: EventChannelTx,
     stderr: EventChannelTx,
@@ -143,30 +172,53 @@ impl Risc0Runner {
 
         check_stark_compression_tools_path(&config.stark_compression_tools_path)?;
 
-        if !std::path::Path::new(&config.risc0_image_folder).exists() {
-            fs::create_dir_all(&config.risc0_image_folder)?;
-        }
+        let job_store = Arc::new(JobStore::open(&config.job_store_path)?);
+        let claim_policies = ClaimPolicies::from_config(&config.claim_policy)?;
+        let cycle_estimator = CycleEstimator::new(
+            config.dry_run_cycle_limit,
+            config.dry_run_cache_size as usize,
+        );
+        let throughput = Arc::new(ThroughputModel::load(
+            &config.throughput_model_path,
+            config.commitment_safety_margin,
+        )?);
 
-        let dir = fs::read_dir(&config.risc0_image_folder)?;
-        let loaded_images = DashMap::new();
-        for entry in dir {
-            let entry = entry?;
-            if entry.file_type()?.is_file() {
-                let img = Image::new(entry.path()).await?;
-                info!("Loaded image: {}", &img.id);
-                loaded_images.insert(img.id.clone(), img);
-            }
+        let loaded_images = ImageCache::open(
+            ImageStore::open(&config.risc0_image_folder)?,
+            config.image_memory_budget_mb as u64 * 1024 * 1024,
+            config.image_disk_quota_mb as u64 * 1024 * 1024,
+            Duration::from_secs(config.image_compression_ttl_hours as u64 * 3600),
+            {
+                let job_store = job_store.clone();
+                Box::new(move || images_in_use(&job_store))
+            },
+        )
+        .await?;
+        let loaded_images = Arc::new(loaded_images);
+
+        let mut backends: Vec<Arc<dyn ProverBackend>> = vec![Arc::new(Risc0Groth16Backend::new(
+            config.stark_compression_tools_path.clone(),
+            loaded_images.clone(),
+            Arc::new(cycle_estimator),
+            throughput.clone(),
+        ))];
+        if let Some(sp1) = &config.sp1 {
+            backends.push(Arc::new(Sp1Groth16Backend::new(sp1)?));
         }
 
         Ok(Risc0Runner {
             config: Arc::new(config),
-            loaded_images: Arc::new(loaded_images),
+            loaded_images,
             worker_handle: None,
             inflight_proof_worker_handle: None,
             txn_sender,
             input_staging_area: Arc::new(DashMap::new()),
             self_identity: Arc::new(self_identity),
             inflight_proofs: Arc::new(DashMap::new()),
+            job_store,
+            claim_policies: Arc::new(claim_policies),
+            backends: Arc::new(ProverBackends::new(backends)),
+            throughput,
             input_resolver,
             stdout,
             stderr,
@@ -180,7 +232,7 @@ impl Risc0Runner {
         verify_prover_version(REQUIRED_PROVER)
             .expect("Bonsol build conflict: prover version is not supported");
         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<BonsolInstruction>();
-        let loaded_images = self.loaded_images.clone();
+        let backends = self.backends.clone();
         // TODO: move image handling out of prover
         let img_client = Arc::new(
             reqwest::Client::builder()
@@ -194,14 +246,18 @@ impl Risc0Runner {
         let input_staging_area = self.input_staging_area.clone();
         let inflight_proofs = self.inflight_proofs.clone();
         let txn_sender = self.txn_sender.clone();
+        let job_store = self.job_store.clone();
         self.inflight_proof_worker_handle = Some(tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(1));
             loop {
                 interval.tick().await;
                 let current_block = txn_sender.get_current_block().await.unwrap_or(0);
+                // retain holds the shard locks, the jobs are forgotten once it returns
+                let mut forgotten = vec![];
                 inflight_proofs.retain(|_, v| {
                     if v.expiry < current_block {
                         emit_event!(MetricEvents::ProofExpired, execution_id => v.execution_id.clone());
+                        forgotten.push(v.execution_id.clone());
                         return false;
                     }
                     match &v.status {
@@ -209,14 +265,17 @@ impl Risc0Runner {
                             let sig = v.claim_signature;
                             let inner_status = txn_sender.get_signature_status(&sig);
                             return match inner_status {
-                                None => false,
+                                None => {
+                                    forgotten.push(v.execution_id.clone());
+                                    false
+                                }
                                 Some(status) => {
                                     match status {
                                         TransactionStatus::Confirmed(status) => {
                                             txn_sender.clear_signature_status(&sig);
                                             if status.err.is_some() {
                                                 info!("Claim Transaction Failed");
-
+                                                forgotten.push(v.execution_id.clone());
                                             }
                                             status.err.is_none()
                                         },
@@ -248,24 +307,44 @@ impl Risc0Runner {
                     };
                     true
                 });
+                if !forgotten.is_empty() {
+                    let job_store = job_store.clone();
+                    let forgetting = tokio::task::spawn_blocking(move || {
+                        for execution_id in forgotten {
+                            forget_job(&job_store, &execution_id);
+                        }
+                    });
+                    if let Err(e) = forgetting.await {
+                        error!("Error forgetting jobs: {:?}", e);
+                    }
+                }
             }
         }));
 
         let inflight_proofs = self.inflight_proofs.clone();
         let txn_sender = self.txn_sender.clone();
+        let job_store = self.job_store.clone();
+        let claim_policies = self.claim_policies.clone();
+        let throughput = self.throughput.clone();
         let input_resolver = self.input_resolver.clone();
         let stdout = self.stdout.clone();
         let stderr = self.stderr.clone();
+        self.benchmark();
+        self.maintain_images();
+        self.resume_jobs(&img_client);
         self.worker_handle = Some(tokio::spawn(async move {
             while let Some(bix) = rx.recv().await {
                 let txn_sender = txn_sender.clone();
-                let loaded_images = loaded_images.clone();
+                let backends = backends.clone();
                 let config = config.clone();
                 let img_client = img_client.clone();
                 let input_resolver = input_resolver.clone();
                 let self_id = self_id.clone();
                 let input_staging_area = input_staging_area.clone();
                 let inflight_proofs = inflight_proofs.clone();
+                let job_store = job_store.clone();
+                let claim_policies = claim_policies.clone();
+                let throughput = throughput.clone();
                 let stdout = stdout.clone();
                 let stderr = stderr.clone();
                 tokio::spawn(async move {
@@ -273,77 +352,76 @@ impl Risc0Runner {
                         parse_ix_data(&bix.data).map_err(|_| Risc0RunnerError::InvalidData)?;
                     let result = match bonsol_ix_type.ix_type() {
                         ChannelInstructionIxType::DeployV1 => {
-                            let payload = bonsol_ix_type
-                                .deploy_v1_nested_flatbuffer()
-                                .ok_or::<anyhow::Error>(
-                                Risc0RunnerError::EmptyInstruction.into(),
-                            )?;
+                            let payload =
+                                bonsol_ix_type.deploy_v1_verified().ok_or::<anyhow::Error>(
+                                    Risc0RunnerError::EmptyInstruction.into(),
+                                )?;
                             emit_counter!(MetricEvents::ImageDeployment, 1, "image_id" => payload.image_id().unwrap_or_default());
-                            handle_image_deployment(&config, &img_client, payload, &loaded_images)
-                                .await
+                            // deployments do not name a proof system, images of other proof
+                            // systems are downloaded with the first request for them
+                            let Some(backend) = backends.get(ProofSystem::Risc0Groth16) else {
+                                return Ok(());
+                            };
+                            handle_image_deployment(&config, &img_client, payload, &*backend).await
                         }
                         ChannelInstructionIxType::ExecuteV1 => {
                             info!("Received execution request");
                             // Evaluate the execution request and decide if it should be claimed
                             let payload = bonsol_ix_type
-                                .execute_v1_nested_flatbuffer()
+                                .execute_v1_verified()
                                 .ok_or::<anyhow::Error>(
-                                Risc0RunnerError::EmptyInstruction.into(),
-                            )?;
-                            let er_prover_version: ProverVersion = payload
-                                .prover_version()
-                                .try_into()
-                                .map_err::<anyhow::Error, _>(|_| {
-                                    Risc0RunnerError::InvalidProverVersion(
-                                        ProverVersion::UnsupportedVersion,
-                                        REQUIRED_PROVER,
-                                    )
-                                    .into()
-                                })?;
-                            if er_prover_version != REQUIRED_PROVER {
-                                return Err(Risc0RunnerError::InvalidProverVersion(
-                                    er_prover_version,
-                                    REQUIRED_PROVER,
-                                )
-                                .into());
-                            }
-                            if let Some(authorized_provers) = payload.authorized_provers() {
-                                if !authorized_provers.iter().any(|key| {
-                                    key.bytes().iter().collect::<Vec<_>>() == self_id.as_array()
-                                }) {
-                                    info!("Oops, not for us :(");
-                                    return Ok(());
-                                }
+                                    Risc0RunnerError::EmptyInstruction.into(),
+                                )?;
+                            // route the request to the prover backend for its proof system,
+                            // requests for a proof system this node does not prove are left
+                            let Some(backend) = backends.get(payload.proof_system()) else {
+                                warn!(
+                                    "No prover backend for proof system {:?}",
+                                    payload.proof_system()
+                                );
+                                emit_event!(MetricEvents::UnsupportedProofSystem, execution_id => payload.execution_id().unwrap_or_default());
+                                return Ok(());
+                            };
+                            if !is_authorized_prover(&txn_sender, payload, &self_id).await? {
+                                info!("Oops, not for us :(");
+                                return Ok(());
                             }
+                            let request = bonsol_ix_type
+                                .execute_v1()
+                                .map(|v| v.bytes())
+                                .unwrap_or_default();
                             handle_execution_request(
                                 &config,
                                 &inflight_proofs,
+                                &job_store,
+                                &claim_policies,
+                                &throughput,
                                 input_resolver.clone(),
                                 img_client.clone(),
                                 &txn_sender,
-                                &loaded_images,
+                                &*backend,
                                 &input_staging_area,
                                 bix.last_known_block,
                                 payload,
+                                request,
                                 &bix.accounts,
                             )
                             .await
                         }
                         ChannelInstructionIxType::ClaimV1 => {
                             info!("Claim Event");
-                            let payload = bonsol_ix_type
-                                .claim_v1_nested_flatbuffer()
-                                .ok_or::<anyhow::Error>(
-                                Risc0RunnerError::EmptyInstruction.into(),
-                            )?;
+                            let payload =
+                                bonsol_ix_type.claim_v1_verified().ok_or::<anyhow::Error>(
+                                    Risc0RunnerError::EmptyInstruction.into(),
+                                )?;
 
                             handle_claim(
-                                &config,
                                 &self_id,
                                 &inflight_proofs,
+                                &job_store,
                                 input_resolver.clone(),
                                 &txn_sender,
-                                &loaded_images,
+                                &backends,
                                 &input_staging_area,
                                 payload,
                                 &bix.accounts,
@@ -369,6 +447,107 @@ impl Risc0Runner {
         Ok(tx)
     }
 
+    /// Calibrates the throughput model with `benchmark_image` in the background, unless it is
+    /// already calibrated.
+    fn benchmark(&self) {
+        let Some(path) = self.config.benchmark_image.clone() else {
+            return;
+        };
+        let config = self.config.clone();
+        let throughput = self.throughput.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let image = Image::new(path.into()).await?;
+                throughput
+                    .benchmark(&image, &config.stark_compression_tools_path)
+                    .await
+            }
+            .await;
+            if let Err(e) = result {
+                warn!("Error benchmarking proving throughput: {:?}", e);
+            }
+        });
+    }
+
+    /// Compresses idle images and trims the image folder to the disk quota once a minute.
+    fn maintain_images(&self) {
+        let loaded_images = self.loaded_images.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                if let Err(e) = loaded_images.maintain() {
+                    warn!("Error maintaining the image cache: {:?}", e);
+                }
+            }
+        });
+    }
+
+    /// Picks up the jobs a previous run left unfinished, each against the current on-chain state
+    /// of its execution.
+    fn resume_jobs(&self, img_client: &Arc<reqwest::Client>) {
+        let config = self.config.clone();
+        let self_id = self.self_identity.clone();
+        let txn_sender = self.txn_sender.clone();
+        let job_store = self.job_store.clone();
+        let claim_policies = self.claim_policies.clone();
+        let throughput = self.throughput.clone();
+        let backends = self.backends.clone();
+        let input_staging_area = self.input_staging_area.clone();
+        let inflight_proofs = self.inflight_proofs.clone();
+        let input_resolver = self.input_resolver.clone();
+        let img_client = img_client.clone();
+        let stdout = self.stdout.clone();
+        let stderr = self.stderr.clone();
+        tokio::spawn(async move {
+            let current_block = txn_sender.get_current_block().await?;
+            let pruned = job_store.prune(current_block)?;
+            let jobs = job_store.unfinished()?;
+            info!("Resuming {} jobs, {} expired", jobs.len(), pruned);
+            for job in jobs {
+                let config = config.clone();
+                let self_id = self_id.clone();
+                let txn_sender = txn_sender.clone();
+                let job_store = job_store.clone();
+                let claim_policies = claim_policies.clone();
+                let throughput = throughput.clone();
+                let backends = backends.clone();
+                let input_staging_area = input_staging_area.clone();
+                let inflight_proofs = inflight_proofs.clone();
+                let input_resolver = input_resolver.clone();
+                let img_client = img_client.clone();
+                let stdout = stdout.clone();
+                let stderr = stderr.clone();
+                tokio::spawn(async move {
+                    let execution_id = job.execution_id.clone();
+                    let result = resume_job(
+                        &config,
+                        &self_id,
+                        &inflight_proofs,
+                        &job_store,
+                        &claim_policies,
+                        &throughput,
+                        input_resolver,
+                        img_client,
+                        &txn_sender,
+                        &backends,
+                        &input_staging_area,
+                        job,
+                        current_block,
+                        stdout,
+                        stderr,
+                    )
+                    .await;
+                    if let Err(e) = &result {
+                        warn!("Error resuming {}: {:?}", execution_id, e);
+                    }
+                    result
+                });
+            }
+            Ok::<_, anyhow::Error>(())
+        });
+    }
+
     pub fn stop(&mut self) -> Result<()> {
         self.worker_handle.take().unwrap().abort();
         Ok(())
@@ -376,12 +555,12 @@ impl Risc0Runner {
 }
 
 pub async fn handle_claim<'a>(
-    config: &ProverNodeConfig,
     self_identity: &Pubkey,
     in_flight_proofs: InflightProofRef<'a>,
+    job_store: &JobStore,
     input_resolver: Arc<dyn InputResolver + 'static>,
     transaction_sender: &RpcTransactionSender,
-    loaded_images: LoadedImageMapRef<'a>,
+    backends: &ProverBackends,
     input_staging_area: InputStagingAreaRef<'a>,
     claim: ClaimV1<'a>,
     accounts: &[Pubkey], // need to create canonical parsing of accounts per instruction type for my flatbuffer model or use shank
@@ -398,6 +577,7 @@ pub async fn handle_claim<'a>(
                 transaction_sender.clear_signature_status(&claim.claim_signature);
                 emit_event!(MetricEvents::ClaimMissed, execution_id => ifp, signature => &claim.claim_signature.to_string());
             }
+            forget_job(job_store, execution_id);
         }
         return Ok(());
     }
@@ -405,128 +585,209 @@ pub async fn handle_claim<'a>(
     let claim_status = in_flight_proofs
         .get(execution_id)
         .map(|v| v.value().to_owned());
-    if let Some(mut claim) = claim_status {
+    if let Some(claim) = claim_status {
         emit_event!(MetricEvents::ClaimReceived, execution_id => execution_id);
         if let ClaimStatus::Claiming = claim.status {
-            if let Some(image) = loaded_images.get(&claim.image_id) {
-                if image.bytes().is_none() {
-                    return Err(Risc0RunnerError::ImageDataUnavailable.into());
-                }
-                //if image is not loaded at claim, fail
-                let mut inputs = input_staging_area
-                    .get(execution_id)
-                    .ok_or(Risc0RunnerError::InvalidData)?
-                    .value()
-                    .clone(); //clone soe we dont hold a reference over http requests
-                let unresolved_count = inputs
-                    .iter()
-                    .filter(|i| match i {
-                        ProgramInput::Unresolved(_) => true,
-                        _ => false,
-                    })
-                    .count();
-
-                if unresolved_count > 0 {
-                    info!("{} outstanding inputs", unresolved_count);
-
-                    emit_event_with_duration!(MetricEvents::InputDownload, {
-                        input_resolver.resolve_private_inputs(execution_id, &mut inputs, Arc::new(transaction_sender)).await?;
-                    }, execution_id => execution_id, stage => "private");
-                    input_staging_area.insert(execution_id.to_string(), inputs);
-                    // one of the huge problems with the claim system is that we are not guaranteed to have
-                    // the inputs we need at the time we claim and no way to
-                }
-                info!("{} inputs resolved", unresolved_count);
-
-                // drain the inputs and own them here, this is a bit of a hack but it works
-                let (eid, inputs) = input_staging_area
-                    .remove(execution_id)
-                    .ok_or(Risc0RunnerError::InvalidData)?;
-                let mem_image = image.get_memory_image()?;
-
-                let stdout = LogShipper::new(stdout, &claim.image_id, &claim.execution_id);
-                let stderr = LogShipper::new(stderr, &claim.image_id, &claim.execution_id);
-
-                let num_inputs = inputs.len();
-
-                let result: Result<
-                    (Journal, Digest, SuccinctReceipt<ReceiptClaim>),
-                    Risc0RunnerError,
-                > = tokio::task::spawn_blocking(move || {
-                    risc0_prove(mem_image, inputs, stdout, stderr).map_err(|e| {
-                        info!("Error generating proof: {:?}", e);
-                        Risc0RunnerError::ProofGenerationError
-                    })
-                })
-                .await?;
-                match result {
-                    Ok((journal, assumptions_digest, receipt)) => {
-                        let compressed_receipt = risc0_compress_proof(
-                            config.stark_compression_tools_path.as_str(),
-                            receipt,
-                        )
-                        .await
-                        .map_err(|e| {
-                            info!("Error compressing proof: {:?}", e);
-                            Risc0RunnerError::ProofCompressionError
-                        })?;
-
-                        let (input_digest, committed_outputs) = if num_inputs > 0 {
-                            journal.bytes.split_at(32)
-                        } else {
-                            (&[] as &[u8], journal.bytes.as_slice())
-                        };
-                        let sig = transaction_sender
-                            .submit_proof(
-                                &eid,
-                                claim.requester,
-                                claim.program_callback.clone(),
-                                &compressed_receipt.proof,
-                                &compressed_receipt.execution_digest,
-                                input_digest,
-                                assumptions_digest.as_bytes(),
-                                committed_outputs,
-                                claim.additional_accounts.clone(),
-                                compressed_receipt.exit_code_system,
-                                compressed_receipt.exit_code_user,
-                            )
-                            .await
-                            .map_err(|e| {
-                                error!("Error submitting proof: {:?}", e);
-                                Risc0RunnerError::TransactionError(e.to_string())
-                            })?;
-
-                        claim.status = ClaimStatus::Submitted;
-                        claim.submission_signature = Some(sig);
-                        in_flight_proofs.insert(eid.clone(), claim);
-                        info!("Proof submitted: {:?}", sig);
-                    }
-                    Err(e) => {
-                        info!("Error generating proof: {:?}", e);
+            prove_claim(
+                in_flight_proofs,
+                job_store,
+                input_resolver,
+                transaction_sender,
+                backends,
+                input_staging_area,
+                claim,
+                stdout,
+                stderr,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Proves a claim that landed and submits the proof, the inputs must be in the staging area.
+async fn prove_claim<'a>(
+    in_flight_proofs: InflightProofRef<'a>,
+    job_store: &JobStore,
+    input_resolver: Arc<dyn InputResolver + 'static>,
+    transaction_sender: &RpcTransactionSender,
+    backends: &ProverBackends,
+    input_staging_area: InputStagingAreaRef<'a>,
+    claim: InflightProof,
+    stdout: EventChannelTx,
+    stderr: EventChannelTx,
+) -> Result<()> {
+    let execution_id = claim.execution_id.as_str();
+    let Some(backend) = backends.get(claim.proof_system) else {
+        forget_job(job_store, execution_id);
+        return Err(Risc0RunnerError::UnsupportedProofSystem(claim.proof_system).into());
+    };
+    if backend.has_image(&claim.image_id) {
+        //if image is not loaded at claim, fail
+        let mut inputs = input_staging_area
+            .get(execution_id)
+            .ok_or(Risc0RunnerError::InvalidData)?
+            .value()
+            .clone(); //clone soe we dont hold a reference over http requests
+        let unresolved_count = inputs
+            .iter()
+            .filter(|i| match i {
+                ProgramInput::Unresolved(_) => true,
+                _ => false,
+            })
+            .count();
+
+        if unresolved_count > 0 {
+            info!("{} outstanding inputs", unresolved_count);
+
+            emit_event_with_duration!(MetricEvents::InputDownload, {
+                input_resolver.resolve_private_inputs(execution_id, &mut inputs, Arc::new(transaction_sender)).await?;
+            }, execution_id => execution_id, stage => "private");
+            input_staging_area.insert(execution_id.to_string(), inputs);
+            // one of the huge problems with the claim system is that we are not guaranteed to have
+            // the inputs we need at the time we claim and no way to
+        }
+        info!("{} inputs resolved", unresolved_count);
+
+        // drain the inputs and own them here, this is a bit of a hack but it works
+        let (eid, inputs) = input_staging_area
+            .remove(execution_id)
+            .ok_or(Risc0RunnerError::InvalidData)?;
+        job_store.advance(&eid, JobStage::InputsResolved)?;
+        if let Some(expected) = &claim.input_digest {
+            // the requester committed to other inputs than were served, no proof can settle
+            if resolved_input_digest(&inputs)?.as_slice() != expected.as_slice() {
+                forget_job(job_store, &eid);
+                return Err(Risc0RunnerError::InputDigestMismatch.into());
+            }
+        }
+
+        let stdout = LogShipper::new(stdout, &claim.image_id, &claim.execution_id);
+        let stderr = LogShipper::new(stderr, &claim.image_id, &claim.execution_id);
+
+        let num_inputs = inputs.len();
+
+        job_store.advance(&eid, JobStage::Proving)?;
+        match backend.prove(&claim.image_id, inputs, stdout, stderr).await {
+            Ok(proven) => {
+                let (input_digest, committed_outputs) = split_journal(&proven.journal, num_inputs)?;
+                if let Some(expected) = &claim.input_digest {
+                    if input_digest != expected.as_slice() {
+                        forget_job(job_store, &eid);
+                        return Err(Risc0RunnerError::InputDigestMismatch.into());
                     }
+                }
+                let proof = ProofRecord {
+                    input_digest: input_digest.to_vec(),
+                    committed_outputs: committed_outputs.to_vec(),
+                    proof: proven.proof,
+                    execution_digest: proven.execution_digest,
+                    assumption_digest: proven.assumption_digest,
+                    exit_code_system: proven.exit_code_system,
+                    exit_code_user: proven.exit_code_user,
                 };
-                in_flight_proofs.remove(&eid);
-            } else {
-                info!("Image not loaded, fatal error aborting execution");
+                job_store.update(&eid, |job| {
+                    job.stage = JobStage::Proved;
+                    job.proof = Some(proof.clone());
+                })?;
+                let sig = submit_job_proof(transaction_sender, job_store, &claim, &proof).await?;
+                in_flight_proofs.insert(
+                    eid.clone(),
+                    InflightProof {
+                        status: ClaimStatus::Submitted,
+                        submission_signature: Some(sig),
+                        ..claim
+                    },
+                );
+                info!("Proof submitted: {:?}", sig);
             }
-        }
+            Err(e) => {
+                info!("Error generating proof: {:?}", e);
+                forget_job(job_store, &eid);
+            }
+        };
+        in_flight_proofs.remove(&eid);
+    } else {
+        info!("Image not loaded, fatal error aborting execution");
     }
     Ok(())
 }
 
+/// Sends the status transaction for a proven job, recording it as submitted until it confirms
+/// so a restart in between sends it again.
+async fn submit_job_proof(
+    transaction_sender: &RpcTransactionSender,
+    job_store: &JobStore,
+    claim: &InflightProof,
+    proof: &ProofRecord,
+) -> Result<Signature> {
+    job_store.advance(&claim.execution_id, JobStage::Submitted)?;
+    let sig = transaction_sender
+        .submit_proof(
+            &claim.execution_id,
+            claim.requester,
+            claim.program_callback.clone(),
+            &proof.proof,
+            &proof.execution_digest,
+            &proof.input_digest,
+            &proof.assumption_digest,
+            &proof.committed_outputs,
+            claim.additional_accounts.clone(),
+            proof.exit_code_system,
+            proof.exit_code_user,
+            claim.proof_system,
+            claim.expiry,
+        )
+        .await
+        .map_err(|e| {
+            error!("Error submitting proof: {:?}", e);
+            Risc0RunnerError::TransactionError(e.to_string())
+        })?;
+    job_store.update(&claim.execution_id, |job| {
+        job.stage = JobStage::Confirmed;
+        job.submission_signature = Some(sig);
+    })?;
+    Ok(sig)
+}
+
+/// Images of the jobs that are not proven yet, proven jobs only need their stored proof.
+fn images_in_use(job_store: &JobStore) -> Result<HashSet<String>> {
+    Ok(job_store
+        .unfinished()?
+        .iter()
+        .filter(|job| job.stage < JobStage::Proved)
+        .filter_map(|job| {
+            let request = root_as_execution_request_v1(&job.request).ok()?;
+            request.image_id().map(str::to_string)
+        })
+        .collect())
+}
+
+/// Drops a job the node gave up on, a store error only costs the job being looked at again
+/// after a restart.
+fn forget_job(job_store: &JobStore, execution_id: &str) {
+    if let Err(e) = job_store.remove(execution_id) {
+        error!("Error removing job {}: {:?}", execution_id, e);
+    }
+}
+
 async fn handle_execution_request<'a>(
     config: &ProverNodeConfig,
     in_flight_proofs: InflightProofRef<'a>,
+    job_store: &JobStore,
+    claim_policies: &ClaimPolicies,
+    throughput: &ThroughputModel,
     input_resolver: Arc<dyn InputResolver + 'static>,
     img_client: Arc<reqwest::Client>,
     transaction_sender: &RpcTransactionSender,
-    loaded_images: LoadedImageMapRef<'a>,
+    backend: &dyn ProverBackend,
     input_staging_area: InputStagingAreaRef<'a>,
-    _execution_block: u64,
+    execution_block: u64,
     exec: ExecutionRequestV1<'a>,
+    request: &[u8],
     accounts: &[Pubkey],
 ) -> Result<()> {
-    if !can_execute(exec) {
+    if !backend.can_prove(&exec) {
         warn!(
             "Execution request for incompatible prover version: {:?}",
             exec.prover_version()
@@ -535,140 +796,298 @@ async fn handle_execution_request<'a>(
         return Ok(());
     }
 
-    // current naive implementation is to accept everything we have pending capacity for on this node, but this needs work
-    let inflight = in_flight_proofs.len();
-    emit_event!(MetricEvents::ExecutionRequest, execution_id => exec.execution_id().unwrap_or_default());
-    if inflight < config.maximum_concurrent_proofs as usize {
-        let eid = exec
-            .execution_id()
-            .map(|d| d.to_string())
-            .ok_or(Risc0RunnerError::InvalidData)?;
-        let image_id = exec
-            .image_id()
-            .map(|d| d.to_string())
+    // a compacted execution account only commits to the request by its digest, the inputs this
+    // node would prove are read from the execute instruction so they are checked against it
+    if exec.compact_storage() {
+        let execution_account = accounts.get(2).ok_or(Risc0RunnerError::InvalidData)?;
+        let account = transaction_sender
+            .get_execution_account(execution_account)
+            .await?
             .ok_or(Risc0RunnerError::InvalidData)?;
-        let expiry = exec.max_block_height();
-        let img = loaded_images.get(&image_id);
-        let img = if img.is_none() {
-            match config.missing_image_strategy {
-                MissingImageStrategy::DownloadAndClaim => {
-                    info!("Image not loaded, attempting to load and running claim");
-                    load_image(
-                        config,
-                        transaction_sender,
-                        &img_client,
-                        &image_id,
-                        loaded_images,
-                    )
-                    .await?;
-                    loaded_images.get(&image_id)
-                }
-                MissingImageStrategy::DownloadAndMiss => {
-                    info!("Image not loaded, loading and rejecting claim");
-                    load_image(
-                        config,
-                        transaction_sender,
-                        &img_client,
-                        &image_id,
-                        loaded_images,
-                    )
-                    .await?;
-                    None
-                }
-                MissingImageStrategy::Fail => {
-                    info!("Image not loaded, rejecting claim");
-                    None
-                }
+        check_request_digest(request, &account.data)?;
+    }
+
+    emit_event!(MetricEvents::ExecutionRequest, execution_id => exec.execution_id().unwrap_or_default());
+    let eid = exec
+        .execution_id()
+        .map(|d| d.to_string())
+        .ok_or(Risc0RunnerError::InvalidData)?;
+    let image_id = exec
+        .image_id()
+        .map(|d| d.to_string())
+        .ok_or(Risc0RunnerError::InvalidData)?;
+    let expiry = exec.max_block_height();
+    let mut claim_request = ClaimRequest {
+        request: exec,
+        requester: accounts[0],
+        image_id: &image_id,
+        estimated_cycles: None,
+        block_commitment: None,
+        current_block: execution_block,
+        load: NodeLoad {
+            inflight_proofs: in_flight_proofs.len(),
+            maximum_concurrent_proofs: config.maximum_concurrent_proofs as usize,
+        },
+        balance: transaction_sender.get_balance().await?,
+    };
+    if let ClaimDecision::Skip(reason) = claim_policies.decide_before_execution(&claim_request) {
+        emit_event!(MetricEvents::ClaimSkipped, execution_id => eid.as_str(), reason => reason.as_str());
+        return Ok(());
+    }
+
+    if !backend.has_image(&image_id) {
+        match config.missing_image_strategy {
+            MissingImageStrategy::DownloadAndClaim => {
+                info!("Image not loaded, attempting to load and running claim");
+                load_image(config, transaction_sender, &img_client, &image_id, backend).await?;
+            }
+            MissingImageStrategy::DownloadAndMiss => {
+                info!("Image not loaded, loading and rejecting claim");
+                load_image(config, transaction_sender, &img_client, &image_id, backend).await?;
+                return Err(Risc0RunnerError::ImgLoadError.into());
+            }
+            MissingImageStrategy::Fail => {
+                info!("Image not loaded, rejecting claim");
+                return Err(Risc0RunnerError::ImgLoadError.into());
             }
-        } else {
-            img
         }
-        .ok_or(Risc0RunnerError::ImgLoadError)?;
+    }
 
-        // naive compute cost estimate which is YES WE CAN DO THIS in the default amount of time
-        emit_histogram!(MetricEvents::ImageComputeEstimate, img.size  as f64, image_id => image_id.clone());
-        //ensure compute can happen before expiry
-        //execution_block + (image_compute_estimate % config.max_compute_per_block) + 1 some bogus calc
-        let computable_by = expiry / 2;
+    let inputs = exec.input().ok_or(Risc0RunnerError::InvalidData)?;
+    let program_inputs = emit_event_with_duration!(MetricEvents::InputDownload, {
+        input_resolver.resolve_public_inputs(
+            inputs.iter().map(|i| i.unpack()).collect()
+        ).await?
+    }, execution_id => eid, stage => "public");
+    let dry_run = emit_event_with_duration!(MetricEvents::DryRun, {
+        backend.estimate(&image_id, &program_inputs).await?
+    }, execution_id => eid, image_id => image_id);
+    let estimated_cycles = match dry_run {
+        DryRun::Completed(estimate) => {
+            emit_histogram!(MetricEvents::ImageComputeEstimate, estimate.total_cycles as f64, image_id => &image_id, cycle_type => "total");
+            emit_histogram!(MetricEvents::ImageComputeEstimate, estimate.user_cycles as f64, image_id => &image_id, cycle_type => "user");
+            emit_histogram!(MetricEvents::ImageComputeEstimate, estimate.segments as f64, image_id => &image_id, cycle_type => "segments");
+            Some(estimate.total_cycles)
+        }
+        DryRun::Failed(reason) => {
+            emit_event!(MetricEvents::ClaimSkipped, execution_id => eid.as_str(), reason => format!("dry run: {}", reason).as_str());
+            return Ok(());
+        }
+        DryRun::Unavailable => None,
+    };
+    let computable_by = throughput.block_commitment(execution_block, expiry, estimated_cycles);
 
-        if computable_by < expiry {
-            //the way this is done can cause race conditions where so many request come in a short time that we accept
-            // them before we change the value of g so we optimistically change to inflight and we will decrement if we dont win the claim
-            let inputs = exec.input().ok_or(Risc0RunnerError::InvalidData)?;
-            let program_inputs = emit_event_with_duration!(MetricEvents::InputDownload, {
-                input_resolver.resolve_public_inputs(
-                    inputs.iter().map(|i| i.unpack()).collect()
-                ).await?
-            }, execution_id => eid, stage => "public");
-            input_staging_area.insert(eid.clone(), program_inputs);
-            let sig = transaction_sender
-                .claim(&eid, accounts[0], accounts[2], computable_by)
-                .await
-                .map_err(|e| Risc0RunnerError::TransactionError(e.to_string()));
-            match sig {
-                Ok(sig) => {
-                    let callback_program = exec
-                        .callback_program_id()
-                        .and_then::<[u8; 32], _>(|v| v.bytes().try_into().ok())
-                        .map(Pubkey::from);
-                    let callback = if callback_program.is_some() {
-                        Some(ProgramExec {
-                            program_id: callback_program.unwrap(),
-                            instruction_prefix: exec
-                                .callback_instruction_prefix()
-                                .map(|v| v.bytes().to_vec())
-                                .unwrap_or(vec![0x1]),
-                        })
-                    } else {
-                        None
-                    };
+    claim_request.estimated_cycles = estimated_cycles;
+    claim_request.block_commitment = Some(computable_by);
+    if let ClaimDecision::Skip(reason) = claim_policies.decide_after_execution(&claim_request) {
+        emit_event!(MetricEvents::ClaimSkipped, execution_id => eid.as_str(), reason => reason.as_str());
+        return Ok(());
+    }
 
-                    in_flight_proofs.insert(
-                        eid.clone(),
-                        InflightProof {
-                            execution_id: eid.clone(),
-                            image_id: image_id.clone(),
-                            status: ClaimStatus::Claiming,
-                            expiry,
-                            claim_signature: sig,
-                            submission_signature: None,
-                            requester: accounts[0],
-                            program_callback: callback,
-                            additional_accounts: exec
-                                .callback_extra_accounts()
-                                .unwrap_or_default()
-                                .into_iter()
-                                .map(|a| {
-                                    let pkbytes: [u8; 32] = a.pubkey().into();
-                                    let pubkey = Pubkey::try_from(pkbytes).unwrap_or_default();
-                                    let writable = a.writable();
-                                    AccountMeta {
-                                        pubkey,
-                                        is_writable: writable == 1,
-                                        is_signer: false,
-                                    }
-                                })
-                                .collect(),
-                        },
-                    );
-                    emit_event!(MetricEvents::ClaimAttempt, execution_id => eid);
-                }
-                Err(e) => {
-                    info!("Error claiming: {:?}", e);
-                    in_flight_proofs.remove(&eid);
-                }
-            }
+    //the way this is done can cause race conditions where so many request come in a short time that we accept
+    // them before we change the value of g so we optimistically change to inflight and we will decrement if we dont win the claim
+    job_store.insert(&Job::new(
+        eid.clone(),
+        request.to_vec(),
+        accounts.to_vec(),
+        expiry,
+    ))?;
+    input_staging_area.insert(eid.clone(), program_inputs);
+    let sig = transaction_sender
+        .claim(
+            &eid,
+            accounts[0],
+            accounts[2],
+            computable_by,
+            prover_set_address(&exec),
+            expiry,
+        )
+        .await
+        .map_err(|e| Risc0RunnerError::TransactionError(e.to_string()));
+    match sig {
+        Ok(sig) => {
+            job_store.update(&eid, |job| {
+                job.stage = JobStage::Claimed;
+                job.claim_signature = Some(sig);
+            })?;
+            in_flight_proofs.insert(eid.clone(), inflight_proof(&exec, accounts, sig)?);
+            emit_event!(MetricEvents::ClaimAttempt, execution_id => eid);
+        }
+        Err(e) => {
+            info!("Error claiming: {:?}", e);
+            in_flight_proofs.remove(&eid);
+            forget_job(job_store, &eid);
         }
     }
     Ok(())
 }
 
-async fn load_image<'a>(
+/// Continues a job from the stage it was stored at. A stored proof is submitted again, a claim
+/// the node holds is proven again from its inputs and a claim that never landed is sent again.
+/// Jobs whose execution settled or that another prover holds an active claim on are dropped.
+async fn resume_job<'a>(
+    config: &ProverNodeConfig,
+    self_identity: &Pubkey,
+    in_flight_proofs: InflightProofRef<'a>,
+    job_store: &JobStore,
+    claim_policies: &ClaimPolicies,
+    throughput: &ThroughputModel,
+    input_resolver: Arc<dyn InputResolver + 'static>,
+    img_client: Arc<reqwest::Client>,
+    transaction_sender: &RpcTransactionSender,
+    backends: &ProverBackends,
+    input_staging_area: InputStagingAreaRef<'a>,
+    job: Job,
+    current_block: u64,
+    stdout: EventChannelTx,
+    stderr: EventChannelTx,
+) -> Result<()> {
+    let exec =
+        root_as_execution_request_v1(&job.request).map_err(|_| Risc0RunnerError::InvalidData)?;
+    let execution_account = *job.accounts.get(2).ok_or(Risc0RunnerError::InvalidData)?;
+    if !transaction_sender
+        .is_execution_pending(&execution_account)
+        .await?
+    {
+        info!("Execution {} settled while stopped", job.execution_id);
+        return job_store.remove(&job.execution_id);
+    }
+    let claim = inflight_proof(
+        &exec,
+        &job.accounts,
+        job.claim_signature.unwrap_or_default(),
+    )?;
+    if let (true, Some(proof)) = (job.stage >= JobStage::Proved, &job.proof) {
+        info!("Submitting stored proof for {}", job.execution_id);
+        submit_job_proof(transaction_sender, job_store, &claim, proof).await?;
+        return Ok(());
+    }
+    let Some(backend) = backends.get(claim.proof_system) else {
+        warn!("No prover backend for {}, dropping it", job.execution_id);
+        return job_store.remove(&job.execution_id);
+    };
+    let claim_state = transaction_sender
+        .get_claim_state(&execution_account)
+        .await?;
+    match claim_state {
+        Some(state) if state.claimer == self_identity.to_bytes() => {
+            info!("Proving {} again", job.execution_id);
+            if !backend.has_image(&claim.image_id) {
+                load_image(
+                    config,
+                    transaction_sender,
+                    &img_client,
+                    &claim.image_id,
+                    &*backend,
+                )
+                .await?;
+            }
+            let inputs = exec.input().ok_or(Risc0RunnerError::InvalidData)?;
+            let program_inputs = input_resolver
+                .resolve_public_inputs(inputs.iter().map(|i| i.unpack()).collect())
+                .await?;
+            input_staging_area.insert(job.execution_id.clone(), program_inputs);
+            prove_claim(
+                in_flight_proofs,
+                job_store,
+                input_resolver,
+                transaction_sender,
+                backends,
+                input_staging_area,
+                claim,
+                stdout,
+                stderr,
+            )
+            .await
+        }
+        Some(state) if state.block_commitment >= current_block => {
+            emit_event!(MetricEvents::ClaimMissed, execution_id => &job.execution_id, signature => &claim.claim_signature.to_string());
+            job_store.remove(&job.execution_id)
+        }
+        _ => {
+            info!("Claiming {} again", job.execution_id);
+            handle_execution_request(
+                config,
+                in_flight_proofs,
+                job_store,
+                claim_policies,
+                throughput,
+                input_resolver,
+                img_client,
+                transaction_sender,
+                &*backend,
+                input_staging_area,
+                current_block,
+                exec,
+                &job.request,
+                &job.accounts,
+            )
+            .await
+        }
+    }
+}
+
+/// What the runner tracks for a request it sent a claim for.
+fn inflight_proof(
+    exec: &ExecutionRequestV1,
+    accounts: &[Pubkey],
+    claim_signature: Signature,
+) -> Result<InflightProof> {
+    let callback_program = exec
+        .callback_program_id()
+        .and_then::<[u8; 32], _>(|v| v.bytes().try_into().ok())
+        .map(Pubkey::from);
+    let callback = callback_program.map(|program_id| ProgramExec {
+        program_id,
+        instruction_prefix: exec
+            .callback_instruction_prefix()
+            .map(|v| v.bytes().to_vec())
+            .unwrap_or(vec![0x1]),
+    });
+    Ok(InflightProof {
+        execution_id: exec
+            .execution_id()
+            .map(|d| d.to_string())
+            .ok_or(Risc0RunnerError::InvalidData)?,
+        image_id: exec
+            .image_id()
+            .map(|d| d.to_string())
+            .ok_or(Risc0RunnerError::InvalidData)?,
+        status: ClaimStatus::Claiming,
+        expiry: exec.max_block_height(),
+        claim_signature,
+        submission_signature: None,
+        requester: *accounts.first().ok_or(Risc0RunnerError::InvalidData)?,
+        program_callback: callback,
+        additional_accounts: exec
+            .callback_extra_accounts()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| {
+                let pkbytes: [u8; 32] = a.pubkey().into();
+                let pubkey = Pubkey::try_from(pkbytes).unwrap_or_default();
+                let writable = a.writable();
+                AccountMeta {
+                    pubkey,
+                    is_writable: writable == 1,
+                    is_signer: false,
+                }
+            })
+            .collect(),
+        input_digest: exec
+            .input_digest()
+            .filter(|_| exec.verify_input_hash())
+            .map(|d| d.bytes().to_vec()),
+        proof_system: exec.proof_system(),
+    })
+}
+
+async fn load_image(
     config: &ProverNodeConfig,
     transaction_sender: &RpcTransactionSender,
     http_client: &reqwest::Client,
     image_id: &str,
-    loaded_images: LoadedImageMapRef<'a>,
+    backend: &dyn ProverBackend,
 ) -> Result<()> {
     let account = transaction_sender
         .get_deployment_account(image_id)
@@ -676,15 +1095,15 @@ async fn load_image<'a>(
         .map_err(Risc0RunnerError::ImageDownloadError)?;
     let deploy_data = root_as_deploy_v1(&account.data)
         .map_err(|_| anyhow::anyhow!("Failed to parse account data"))?;
-    handle_image_deployment(config, http_client, deploy_data, loaded_images).await?;
+    handle_image_deployment(config, http_client, deploy_data, backend).await?;
     Ok(())
 }
 
-async fn handle_image_deployment<'a>(
+async fn handle_image_deployment(
     config: &ProverNodeConfig,
     http_client: &reqwest::Client,
-    deploy: DeployV1<'a>,
-    loaded_images: LoadedImageMapRef<'a>,
+    deploy: DeployV1<'_>,
+    backend: &dyn ProverBackend,
 ) -> Result<()> {
     let url = deploy.url().ok_or(Risc0RunnerError::InvalidData)?;
     let size = deploy.size_();
@@ -708,15 +1127,7 @@ async fn handle_image_deployment<'a>(
                 .await
                 .map_err(|_|Risc0RunnerError::ImgTooLarge)?;
 
-            let img = Image::from_bytes(resp_data)?;
-            if let Some(bytes) = img.bytes() {
-                tokio::fs::write(Path::new(&config.risc0_image_folder).join(img.id.clone()), bytes).await?;
-            }
-            if img.id != image_id {
-                info!("Image ID mismatch. Expected: {}, Got: {}", image_id, img.id);
-                return Err(Risc0RunnerError::InvalidData.into());
-            }
-            loaded_images.insert(img.id.clone(), img);
+            backend.store_image(image_id, size, resp_data)?;
             info!("Successfully downloaded and stored image: {}", image_id);
         } else {
             info!("Download failed with status: {}", resp.status());
@@ -726,13 +1137,26 @@ async fn handle_image_deployment<'a>(
     }, url => url.to_string())
 }
 
+/// Guests that read inputs commit the canonical input digest as the first 32 bytes of their
+/// journal, the rest is the committed output forwarded to callbacks.
+fn split_journal(journal: &[u8], num_inputs: usize) -> Result<(&[u8], &[u8]), Risc0RunnerError> {
+    if num_inputs == 0 {
+        return Ok((&[], journal));
+    }
+    if journal.len() < 32 {
+        return Err(Risc0RunnerError::InvalidData);
+    }
+    Ok(journal.split_at(32))
+}
+
 // proving function, no async this is cpu/gpu intesive
+/// Proves the guest and returns the journal, assumptions digest, receipt and total cycles.
 fn risc0_prove(
     mut memory_image: MemoryImage,
     sorted_inputs: Vec<ProgramInput>,
     stdout: LogShipper,
     stderr: LogShipper,
-) -> Result<(Journal, Digest, SuccinctReceipt<ReceiptClaim>)> {
+) -> Result<(Journal, Digest, SuccinctReceipt<ReceiptClaim>, u64)> {
     let image_id = memory_image.image_id().to_string();
     let mut exec = new_risc0_exec_env(memory_image, sorted_inputs, stdout, stderr)?;
     let session = exec.run()?;
@@ -751,7 +1175,12 @@ fn risc0_prove(
         if let MaybePruned::Value(rc) = sr.claim {
             if let MaybePruned::Value(Some(op)) = rc.output {
                 if let MaybePruned::Value(ass) = op.assumptions {
-                    return Ok((info.receipt.journal, ass.digest(), ident_receipt));
+                    return Ok((
+                        info.receipt.journal,
+                        ass.digest(),
+                        ident_receipt,
+                        info.stats.total_cycles,
+                    ));
                 }
             }
         }
@@ -759,91 +1188,64 @@ fn risc0_prove(
     Err(Risc0RunnerError::ProofGenerationError.into())
 }
 
-pub struct CompressedReceipt {
-    pub execution_digest: Vec<u8>,
-    pub exit_code_system: u32,
-    pub exit_code_user: u32,
-    pub proof: Vec<u8>,
-}
 /// Compresses the proof to be sent to the blockchain
-/// This is a temporary solution until the wasm groth16 prover or a rust impl is working
 async fn risc0_compress_proof(
     tools_path: &str,
     succinct_receipt: SuccinctReceipt<ReceiptClaim>,
 ) -> Result<CompressedReceipt> {
-    let sealbytes = succinct_receipt.get_seal_bytes();
-    if !(ARCH == "x86_64" || ARCH == "x86") {
-        panic!("X86 only");
-    }
-    let tmp = tempdir()?;
-    let prove_dir = tmp.path();
-    let root_path = Path::new(tools_path);
-    let mut cursor = Cursor::new(&sealbytes);
-    let inputs = prove_dir.join("input.json");
-    let witness = prove_dir.join("out.wtns");
-    let input_file = File::create(&inputs).await?;
-    emit_event_with_duration!(MetricEvents::ProofConversion,{
-        async_to_json(&mut cursor, input_file).await
-    }, system => "groth16json")?;
-    let zkey = root_path.join("stark_verify_final.zkey");
-    let proof_out = prove_dir.join("proof.json");
-    let public = prove_dir.join("public.json");
-    emit_event_with_duration!(MetricEvents::ProofCompression,{
-    let status = Command::new(root_path.join("stark_verify"))
-        .arg(inputs.clone())
-        .arg(witness.clone())
-        .output()
-        .await?;
-    if !status.status.success() {
-        info!("witness {:?}", status);
-        return Err(Risc0RunnerError::ProofCompressionError.into());
+    emit_event_with_duration!(MetricEvents::ProofCompression, {
+        compression::risc0_compress_proof(tools_path, succinct_receipt).await
+    }, system => "risc0")
+}
+
+/// Checks the nested request bytes of an execute instruction against the `request_digest` the
+/// program stored when it compacted the execution account.
+fn check_request_digest(request: &[u8], execution_account: &[u8]) -> Result<(), Risc0RunnerError> {
+    let stored = root_as_execution_request_v1(execution_account)
+        .map_err(|_| Risc0RunnerError::InvalidData)?;
+    match stored.request_digest() {
+        Some(digest) if digest.bytes() == execution_request_digest(request) => Ok(()),
+        _ => Err(Risc0RunnerError::RequestDigestMismatch),
     }
-    let snark_status = Command::new(root_path.join("rapidsnark"))
-        .arg(zkey)
-        .arg(witness)
-        .arg(proof_out.clone())
-        .arg(public)
-        .output()
-        .await?;
-    if !snark_status.status.success() {
-        info!("snark {:?}", snark_status);
-        return Err(Risc0RunnerError::ProofCompressionError.into());
+}
+
+fn prover_set_address(exec: &ExecutionRequestV1) -> Option<Pubkey> {
+    exec.prover_set()
+        .and_then::<[u8; 32], _>(|v| v.bytes().try_into().ok())
+        .map(Pubkey::from)
+}
+
+/// Mirrors the claim check in the program, a prover may claim if it is listed inline or in the
+/// referenced prover set. The set is read at request time so rotations apply to pending requests.
+async fn is_authorized_prover(
+    transaction_sender: &RpcTransactionSender,
+    exec: ExecutionRequestV1<'_>,
+    self_identity: &Pubkey,
+) -> Result<bool> {
+    let inline_provers = exec.authorized_provers();
+    let prover_set = prover_set_address(&exec);
+    if inline_provers.is_none() && prover_set.is_none() {
+        return Ok(true);
     }
-    }, system => "risc0");
-
-    let mut proof_fd = File::open(proof_out).await?;
-    let mt = proof_fd.metadata().await?;
-    let mut bytes = Vec::with_capacity(mt.len() as usize);
-    proof_fd.read_to_end(&mut bytes).await?;
-    let proof: ProofJson = serde_json::from_slice(&bytes)?;
-    let seal: Seal = proof.try_into()?;
-    let claim = succinct_receipt.claim;
-    if let MaybePruned::Value(rc) = claim {
-        let (system, user) = match rc.exit_code {
-            ExitCode::Halted(user_exit) => (0, user_exit),
-            ExitCode::Paused(user_exit) => (1, user_exit),
-            ExitCode::SystemSplit => (2, 0),
-            ExitCode::SessionLimit => (2, 2),
-            _ => {
-                // Log a warning or error for debugging
-                error!(
-                    "Encountered unexpected ExitCode variant: {:?}",
-                    rc.exit_code
-                );
-                // Return an error indicating that this specific variant is not supported by this function
-                return Err(anyhow!(
-                    "Unsupported ExitCode variant encountered during compression"
-                ));
-            }
-        };
-        Ok(CompressedReceipt {
-            execution_digest: rc.post.digest().as_bytes().to_vec(),
-            exit_code_system: system,
-            exit_code_user: user,
-            proof: seal.to_vec(),
+    if inline_provers.is_some_and(|provers| {
+        provers.iter().any(|key| {
+            key.bytes()
+                .iter()
+                .eq(self_identity.as_ref().iter().copied())
         })
-    } else {
-        Err(Risc0RunnerError::ProofCompressionError.into())
+    }) {
+        return Ok(true);
+    }
+    match prover_set {
+        Some(prover_set) => {
+            let account = transaction_sender
+                .get_prover_set_account(&prover_set)
+                .await?;
+            let set =
+                ProverSetV1::load(&account.data).map_err(|_| Risc0RunnerError::InvalidData)?;
+            Ok(set.contains(self_identity.as_ref()))
+        }
+        None => Ok(false),
     }
 }
 
@@ -858,3 +1260,55 @@ fn can_execute(exec: ExecutionRequestV1) -> bool {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_journal() {
+        let journal = [[1u8; 32].as_slice(), &[2u8]].concat();
+        assert_eq!(
+            split_journal(&journal, 2).unwrap(),
+            ([1u8; 32].as_slice(), [2u8].as_slice())
+        );
+        assert_eq!(split_journal(&journal, 0).unwrap(), (&[][..], &journal[..]));
+        assert!(split_journal(&[1u8; 31], 1).is_err());
+    }
+
+    #[test]
+    fn test_check_request_digest() {
+        use bonsol_interface::bonsol_schema::{ExecutionRequestV1Args, ExitCode};
+        use flatbuffers::FlatBufferBuilder;
+
+        let request = b"execute request".as_slice();
+        let compacted = |request_digest: Option<&[u8]>| {
+            let mut fbb = FlatBufferBuilder::new();
+            let request_digest = request_digest.map(|d| fbb.create_vector(d));
+            let er = ExecutionRequestV1::create(
+                &mut fbb,
+                &ExecutionRequestV1Args {
+                    compact_storage: true,
+                    request_digest,
+                    ..Default::default()
+                },
+            );
+            fbb.finish(er, None);
+            fbb.finished_data().to_vec()
+        };
+        let digest = execution_request_digest(request);
+        assert!(check_request_digest(request, &compacted(Some(&digest))).is_ok());
+        assert!(matches!(
+            check_request_digest(b"other request", &compacted(Some(&digest))),
+            Err(Risc0RunnerError::RequestDigestMismatch)
+        ));
+        assert!(matches!(
+            check_request_digest(request, &compacted(None)),
+            Err(Risc0RunnerError::RequestDigestMismatch)
+        ));
+        assert!(matches!(
+            check_request_digest(request, &[ExitCode::Success as u8]),
+            Err(Risc0RunnerError::InvalidData)
+        ));
+    }
+}
@@ -0,0 +1,263 @@
+//! Proves SP1 programs with an external prover command, the node does not link the SP1 toolchain.
+//!
+//! The command is run as `<prover_command> <elf> <inputs.json> <proof.json>`. `inputs.json` is a
+//! json array of the hex encoded inputs in the order the guest reads them, and the command writes
+//! `proof.json` as `{"vkey_hash": "0x..", "proof": "<hex>", "public_values": "<hex>"}` with the
+//! Groth16 proof bytes SP1 produces, verifying key selector first.
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use bonsol_interface::bonsol_schema::{ExecutionRequestV1, ProgramInputType, ProofSystem};
+use bonsol_prover::{input_resolver::ProgramInput, util::LogShipper};
+use bytes::Bytes;
+use serde::Deserialize;
+use tempfile::tempdir;
+use tokio::process::Command;
+use tracing::{info, warn};
+
+use super::{
+    backend::{ProvenExecution, ProverBackend},
+    estimate::DryRun,
+    Risc0RunnerError,
+};
+use crate::config::Sp1Config;
+
+/// The 4 byte verifying key selector and the uncompressed Groth16 proof points.
+const SP1_SEAL_LEN: usize = 260;
+
+#[derive(Debug, Deserialize)]
+struct Sp1ProofOutput {
+    vkey_hash: String,
+    proof: String,
+    public_values: String,
+}
+
+pub struct Sp1Groth16Backend {
+    prover_command: PathBuf,
+    image_folder: PathBuf,
+}
+
+impl Sp1Groth16Backend {
+    pub fn new(config: &Sp1Config) -> Result<Self> {
+        std::fs::create_dir_all(&config.image_folder)?;
+        Ok(Sp1Groth16Backend {
+            prover_command: config.prover_command.clone().into(),
+            image_folder: config.image_folder.clone().into(),
+        })
+    }
+
+    fn image_path(&self, image_id: &str) -> PathBuf {
+        self.image_folder.join(image_id)
+    }
+}
+
+#[async_trait]
+impl ProverBackend for Sp1Groth16Backend {
+    fn proof_system(&self) -> ProofSystem {
+        ProofSystem::Sp1Groth16
+    }
+
+    // the prover version names a risc0 release, sp1 programs are wrapped by one pinned circuit
+    fn can_prove(&self, _exec: &ExecutionRequestV1) -> bool {
+        true
+    }
+
+    fn has_image(&self, image_id: &str) -> bool {
+        self.image_path(image_id).is_file()
+    }
+
+    // computing the verifying key hash of an elf takes the sp1 toolchain, so the image id is
+    // checked against the hash the prover command reports instead
+    fn store_image(&self, image_id: &str, size: u64, bytes: Bytes) -> Result<()> {
+        if bytes.len() as u64 != size {
+            return Err(Risc0RunnerError::ImageVerificationFailed(format!(
+                "expected {} bytes, got {}",
+                size,
+                bytes.len()
+            ))
+            .into());
+        }
+        let path = self.image_path(image_id);
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, &bytes)?;
+        std::fs::rename(partial, path)?;
+        Ok(())
+    }
+
+    async fn estimate(&self, _image_id: &str, _inputs: &[ProgramInput]) -> Result<DryRun> {
+        Ok(DryRun::Unavailable)
+    }
+
+    async fn prove(
+        &self,
+        image_id: &str,
+        inputs: Vec<ProgramInput>,
+        mut stdout: LogShipper,
+        mut stderr: LogShipper,
+    ) -> Result<ProvenExecution> {
+        let elf = self.image_path(image_id);
+        if !elf.is_file() {
+            return Err(Risc0RunnerError::ImgLoadError.into());
+        }
+        let tmp = tempdir()?;
+        let inputs_path = tmp.path().join("inputs.json");
+        let proof_path = tmp.path().join("proof.json");
+        tokio::fs::write(&inputs_path, serde_json::to_vec(&hex_inputs(inputs)?)?).await?;
+        let output = Command::new(&self.prover_command)
+            .arg(&elf)
+            .arg(&inputs_path)
+            .arg(&proof_path)
+            .output()
+            .await?;
+        for (shipper, log) in [(&mut stdout, &output.stdout), (&mut stderr, &output.stderr)] {
+            if let Err(e) = shipper.write_all(log) {
+                warn!("Error shipping sp1 prover logs: {:?}", e);
+            }
+        }
+        if !output.status.success() {
+            info!("sp1 prover {:?}", output.status);
+            return Err(Risc0RunnerError::ProofGenerationError.into());
+        }
+        read_proof(&proof_path, image_id).await
+    }
+}
+
+fn hex_inputs(inputs: Vec<ProgramInput>) -> Result<Vec<String>> {
+    inputs
+        .into_iter()
+        .map(|input| match input {
+            // proof inputs are risc0 receipts the guest is composed with
+            ProgramInput::Resolved(ri) if ri.input_type != ProgramInputType::PublicProof => {
+                Ok(hex::encode(ri.data))
+            }
+            _ => Err(Risc0RunnerError::InvalidData.into()),
+        })
+        .collect()
+}
+
+async fn read_proof(path: &Path, image_id: &str) -> Result<ProvenExecution> {
+    let output: Sp1ProofOutput = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+    let vkey_hash = output.vkey_hash.trim_start_matches("0x");
+    if !vkey_hash.eq_ignore_ascii_case(image_id.trim_start_matches("0x")) {
+        return Err(Risc0RunnerError::ImageVerificationFailed(format!(
+            "proven program has verifying key hash {}",
+            output.vkey_hash
+        ))
+        .into());
+    }
+    let proof = hex::decode(output.proof.trim_start_matches("0x"))?;
+    if proof.len() != SP1_SEAL_LEN {
+        return Err(Risc0RunnerError::ProofCompressionError.into());
+    }
+    // the sp1 circuit only exposes the program and its public values, the program ignores the
+    // execution and assumption digests and exit codes of an sp1 submission
+    Ok(ProvenExecution {
+        proof,
+        execution_digest: vec![0; 32],
+        assumption_digest: vec![0; 32],
+        journal: hex::decode(output.public_values.trim_start_matches("0x"))?,
+        exit_code_system: 0,
+        exit_code_user: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use bonsol_prover::input_resolver::ResolvedInput;
+
+    use super::*;
+
+    const VKEY_HASH: &str = "0x0054c0e58911dd8b993c6d8f249aa50a2e523114ec4b7ef9dd355c5f6bfbf3ce";
+
+    // stands in for the sp1 prover, echoing the inputs file as the public values
+    fn fake_prover(dir: &Path, vkey_hash: &str) -> Sp1Config {
+        let script = dir.join("prove.sh");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\ntest -f \"$1\" || exit 1\nvalues=$(od -An -tx1 \"$2\" | tr -d ' \\n')\nprintf '{{\"vkey_hash\":\"{}\",\"proof\":\"%s\",\"public_values\":\"%s\"}}' $(printf 'ab%.0s' $(seq 260)) \"$values\" > \"$3\"\n",
+                vkey_hash
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        Sp1Config {
+            prover_command: script.to_string_lossy().to_string(),
+            image_folder: dir.join("elf").to_string_lossy().to_string(),
+        }
+    }
+
+    fn log_shipper() -> LogShipper {
+        let (tx, _rx) = std::sync::mpsc::channel();
+        LogShipper::new(tx, "image", "job")
+    }
+
+    fn inputs() -> Vec<ProgramInput> {
+        vec![ProgramInput::Resolved(ResolvedInput {
+            index: 0,
+            data: vec![1, 2],
+            input_type: ProgramInputType::Public,
+        })]
+    }
+
+    #[tokio::test]
+    async fn test_sp1_prove() {
+        let dir = tempdir().unwrap();
+        let backend = Sp1Groth16Backend::new(&fake_prover(dir.path(), VKEY_HASH)).unwrap();
+        assert!(backend
+            .prove(VKEY_HASH, inputs(), log_shipper(), log_shipper())
+            .await
+            .is_err());
+        assert!(backend
+            .store_image(VKEY_HASH, 4, Bytes::from_static(b"elf"))
+            .is_err());
+        backend
+            .store_image(VKEY_HASH, 3, Bytes::from_static(b"elf"))
+            .unwrap();
+        assert!(backend.has_image(VKEY_HASH));
+
+        let proven = backend
+            .prove(VKEY_HASH, inputs(), log_shipper(), log_shipper())
+            .await
+            .unwrap();
+        assert_eq!(proven.proof, vec![0xab; SP1_SEAL_LEN]);
+        assert_eq!(proven.journal, br#"["0102"]"#);
+        assert_eq!(proven.execution_digest, vec![0; 32]);
+    }
+
+    #[tokio::test]
+    async fn test_sp1_prove_rejects_other_program() {
+        let dir = tempdir().unwrap();
+        let other = "0x0011111111111111111111111111111111111111111111111111111111111111";
+        let backend = Sp1Groth16Backend::new(&fake_prover(dir.path(), other)).unwrap();
+        backend
+            .store_image(VKEY_HASH, 3, Bytes::from_static(b"elf"))
+            .unwrap();
+        let err = backend
+            .prove(VKEY_HASH, inputs(), log_shipper(), log_shipper())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(Risc0RunnerError::ImageVerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_sp1_rejects_proof_inputs() {
+        let proof = ProgramInput::Resolved(ResolvedInput {
+            index: 0,
+            data: vec![],
+            input_type: ProgramInputType::PublicProof,
+        });
+        assert!(hex_inputs(vec![proof]).is_err());
+        assert_eq!(hex_inputs(inputs()).unwrap(), vec!["0102".to_string()]);
+    }
+}
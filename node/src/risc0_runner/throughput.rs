@@ -0,0 +1,219 @@
+//! How long this machine takes to prove, used to pick the block a claim commits to. The model
+//! starts from a benchmark proof, or conservative defaults, and follows every proof the node
+//! generates with a moving average. It is saved after each update so a restart keeps it.
+
+use {
+    super::{risc0_compress_proof, risc0_prove},
+    anyhow::Result,
+    bonsol_prover::{image::Image, util::LogShipper},
+    serde::{Deserialize, Serialize},
+    std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::{mpsc::channel, Mutex},
+        time::{Duration, Instant},
+    },
+    tracing::{error, info},
+};
+
+/// Roughly how often the block height advances.
+const BLOCK_TIME_MS: f64 = 400.0;
+/// Blocks allowed for the status transaction to land once the proof is ready.
+const SUBMISSION_BLOCKS: u64 = 10;
+/// Weight of a new observation in the moving averages.
+const SMOOTHING: f64 = 0.2;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    /// Time to execute and prove a million cycles, up to the succinct receipt.
+    pub prove_ms_per_million_cycles: f64,
+    /// Time to compress a succinct receipt to groth16, which does not depend on the cycles.
+    pub compression_ms: f64,
+    pub proving_samples: u64,
+    pub compression_samples: u64,
+}
+
+impl Default for Calibration {
+    /// Deliberately slow, cpu proving speeds until the first sample.
+    fn default() -> Self {
+        Self {
+            prove_ms_per_million_cycles: 10_000.0,
+            compression_ms: 60_000.0,
+            proving_samples: 0,
+            compression_samples: 0,
+        }
+    }
+}
+
+impl Calibration {
+    fn observe(average: f64, samples: u64, value: f64) -> f64 {
+        if samples == 0 {
+            value
+        } else {
+            average + SMOOTHING * (value - average)
+        }
+    }
+
+    pub fn record_proving(&mut self, cycles: u64, duration: Duration) {
+        if cycles == 0 {
+            return;
+        }
+        let per_million = duration.as_secs_f64() * 1000.0 * 1_000_000.0 / cycles as f64;
+        self.prove_ms_per_million_cycles = Self::observe(
+            self.prove_ms_per_million_cycles,
+            self.proving_samples,
+            per_million,
+        );
+        self.proving_samples += 1;
+    }
+
+    pub fn record_compression(&mut self, duration: Duration) {
+        self.compression_ms = Self::observe(
+            self.compression_ms,
+            self.compression_samples,
+            duration.as_secs_f64() * 1000.0,
+        );
+        self.compression_samples += 1;
+    }
+
+    /// Blocks from starting to prove `cycles` until the proof lands, times `safety_margin`.
+    pub fn blocks_for(&self, cycles: u64, safety_margin: f64) -> u64 {
+        let ms =
+            self.prove_ms_per_million_cycles * cycles as f64 / 1_000_000.0 + self.compression_ms;
+        (ms * safety_margin / BLOCK_TIME_MS).ceil() as u64 + SUBMISSION_BLOCKS
+    }
+}
+
+pub struct ThroughputModel {
+    path: PathBuf,
+    safety_margin: f64,
+    calibration: Mutex<Calibration>,
+}
+
+impl ThroughputModel {
+    /// Loads the calibration saved at `path`, or starts from the defaults when there is none.
+    pub fn load(path: impl AsRef<Path>, safety_margin: f64) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let calibration = if path.exists() {
+            serde_json::from_slice(&fs::read(&path)?)?
+        } else {
+            Calibration::default()
+        };
+        Ok(Self {
+            path,
+            safety_margin,
+            calibration: Mutex::new(calibration),
+        })
+    }
+
+    pub fn calibration(&self) -> Calibration {
+        self.calibration.lock().unwrap().clone()
+    }
+
+    /// The block a claim made at `current_block` should commit to. Without a cycle estimate the
+    /// commitment is halfway to `expiry`. A commitment past `expiry` means the proof would not
+    /// make it in time.
+    pub fn block_commitment(
+        &self,
+        current_block: u64,
+        expiry: u64,
+        estimated_cycles: Option<u64>,
+    ) -> u64 {
+        match estimated_cycles {
+            Some(cycles) => {
+                current_block
+                    + self
+                        .calibration
+                        .lock()
+                        .unwrap()
+                        .blocks_for(cycles, self.safety_margin)
+            }
+            None => current_block + expiry.saturating_sub(current_block) / 2,
+        }
+    }
+
+    pub fn record_proving(&self, cycles: u64, duration: Duration) {
+        self.update(|c| c.record_proving(cycles, duration));
+    }
+
+    pub fn record_compression(&self, duration: Duration) {
+        self.update(|c| c.record_compression(duration));
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Calibration)) {
+        let calibration = {
+            let mut calibration = self.calibration.lock().unwrap();
+            f(&mut calibration);
+            calibration.clone()
+        };
+        let saved = serde_json::to_vec_pretty(&calibration)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| Ok(fs::write(&self.path, json)?));
+        if let Err(e) = saved {
+            error!("Error saving throughput calibration: {:?}", e);
+        }
+    }
+
+    /// Proves and compresses `image`, which must run without inputs, to calibrate a machine that
+    /// has no proving samples yet.
+    pub async fn benchmark(&self, image: &Image, stark_compression_tools_path: &str) -> Result<()> {
+        if self.calibration().proving_samples > 0 {
+            return Ok(());
+        }
+        info!("Benchmarking proving throughput with {}", image.id);
+        let (tx, _rx) = channel();
+        let stdout = LogShipper::new(tx.clone(), &image.id, "benchmark");
+        let stderr = LogShipper::new(tx, &image.id, "benchmark");
+        let memory_image = image.get_memory_image()?;
+        let start = Instant::now();
+        let (_, _, receipt, cycles) =
+            tokio::task::spawn_blocking(move || risc0_prove(memory_image, vec![], stdout, stderr))
+                .await??;
+        self.record_proving(cycles, start.elapsed());
+        let start = Instant::now();
+        risc0_compress_proof(stark_compression_tools_path, receipt).await?;
+        self.record_compression(start.elapsed());
+        let calibration = self.calibration();
+        info!(
+            "Proving takes {:.0} ms per million cycles, compression {:.0} ms",
+            calibration.prove_ms_per_million_cycles, calibration.compression_ms
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration_follows_samples() {
+        let mut calibration = Calibration::default();
+        calibration.record_proving(2_000_000, Duration::from_secs(4));
+        assert_eq!(calibration.prove_ms_per_million_cycles, 2000.0);
+        calibration.record_proving(1_000_000, Duration::from_secs(3));
+        assert_eq!(calibration.prove_ms_per_million_cycles, 2200.0);
+        calibration.record_proving(0, Duration::from_secs(3));
+        assert_eq!(calibration.proving_samples, 2);
+        calibration.record_compression(Duration::from_secs(20));
+        // 10 Mcycles at 2.2 s each plus 20 s compression is 42 s, 105 blocks, 158 with a 1.5
+        // margin and 10 more to submit
+        assert_eq!(calibration.blocks_for(10_000_000, 1.5), 168);
+    }
+
+    #[test]
+    fn test_calibration_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("throughput.json");
+        let model = ThroughputModel::load(&path, 1.0).unwrap();
+        assert_eq!(model.block_commitment(100, 300, None), 200);
+        model.record_proving(1_000_000, Duration::from_millis(400));
+        model.record_compression(Duration::from_millis(4000));
+        let model = ThroughputModel::load(&path, 1.0).unwrap();
+        assert_eq!(model.calibration().proving_samples, 1);
+        assert_eq!(
+            model.block_commitment(100, 300, Some(10_000_000)),
+            100 + 20 + 10
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! A keypair encrypted at rest. The secret key is sealed with AES-256-GCM-SIV under a key derived
+//! from a passphrase with PBKDF2-HMAC-SHA256, and the public key is bound as associated data so
+//! a keystore cannot be edited to claim another key. Create one with
+//! `bonsol-node encrypt-keypair <keypair file> <keystore file>`.
+
+use {
+    aes_gcm_siv::{
+        aead::{rand_core::RngCore, Aead, KeyInit, OsRng, Payload},
+        Aes256GcmSiv, Nonce,
+    },
+    anyhow::{anyhow, bail, Result},
+    hmac::Hmac,
+    serde::{Deserialize, Serialize},
+    sha2::Sha256,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+    std::{fs, path::Path, str::FromStr},
+    zeroize::Zeroizing,
+};
+
+/// Env var the passphrase is read from when the config names neither a file nor a variable.
+pub const DEFAULT_PASSPHRASE_ENV: &str = "BONSOL_KEYSTORE_PASSPHRASE";
+pub const DEFAULT_ITERATIONS: u32 = 600_000;
+const VERSION: u32 = 1;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    /// Base58 public key of the sealed keypair.
+    pub pubkey: String,
+    /// PBKDF2 rounds.
+    pub iterations: u32,
+    /// Hex encoded.
+    pub salt: String,
+    /// Hex encoded.
+    pub nonce: String,
+    /// Hex encoded sealed secret key.
+    pub ciphertext: String,
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8], iterations: u32) -> Zeroizing<[u8; 32]> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase, salt, iterations, key.as_mut());
+    key
+}
+
+impl Keystore {
+    pub fn encrypt(keypair: &Keypair, passphrase: &[u8], iterations: u32) -> Result<Self> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+        let key = derive_key(passphrase, &salt, iterations);
+        let pubkey = keypair.pubkey();
+        let ciphertext = Aes256GcmSiv::new(key.as_ref().into())
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: keypair.secret_bytes(),
+                    aad: pubkey.as_ref(),
+                },
+            )
+            .map_err(|_| anyhow!("Error sealing keypair"))?;
+        Ok(Self {
+            version: VERSION,
+            pubkey: pubkey.to_string(),
+            iterations,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    pub fn decrypt(&self, passphrase: &[u8]) -> Result<Keypair> {
+        if self.version != VERSION {
+            bail!("Unsupported keystore version {}", self.version);
+        }
+        let pubkey = Pubkey::from_str(&self.pubkey)?;
+        let nonce = hex::decode(&self.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            bail!("Invalid keystore nonce");
+        }
+        let key = derive_key(passphrase, &hex::decode(&self.salt)?, self.iterations);
+        let secret = Aes256GcmSiv::new(key.as_ref().into())
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &hex::decode(&self.ciphertext)?,
+                    aad: pubkey.as_ref(),
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow!("Wrong passphrase or corrupted keystore"))?;
+        let secret: [u8; 32] = secret
+            .as_slice()
+            .try_into()
+            .map_err(|_| anyhow!("Invalid keystore secret key"))?;
+        let keypair = Keypair::new_from_array(secret);
+        if keypair.pubkey() != pubkey {
+            bail!("Keystore secret key does not match its public key");
+        }
+        Ok(keypair)
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Reads the passphrase from `passphrase_file` if set, otherwise from the `passphrase_env`
+/// variable or [`DEFAULT_PASSPHRASE_ENV`]. A trailing newline in the file is not part of it.
+pub fn read_passphrase(
+    passphrase_env: Option<&str>,
+    passphrase_file: Option<&str>,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let passphrase = match passphrase_file {
+        Some(path) => {
+            let mut data = Zeroizing::new(fs::read(path)?);
+            while data.last().is_some_and(|b| *b == b'\n' || *b == b'\r') {
+                data.pop();
+            }
+            data
+        }
+        None => {
+            let var = passphrase_env.unwrap_or(DEFAULT_PASSPHRASE_ENV);
+            let value = std::env::var(var)
+                .map_err(|_| anyhow!("Keystore passphrase variable {} is not set", var))?;
+            Zeroizing::new(value.into_bytes())
+        }
+    };
+    if passphrase.is_empty() {
+        bail!("Keystore passphrase is empty");
+    }
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let keypair = Keypair::new();
+        let keystore = Keystore::encrypt(&keypair, b"correct horse", 1000).unwrap();
+        assert_eq!(keystore.pubkey, keypair.pubkey().to_string());
+        assert!(!keystore
+            .ciphertext
+            .contains(&hex::encode(keypair.secret_bytes())));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        keystore.write(&path).unwrap();
+        let keystore = Keystore::read(&path).unwrap();
+        let decrypted = keystore.decrypt(b"correct horse").unwrap();
+        assert_eq!(decrypted.to_bytes(), keypair.to_bytes());
+        assert!(keystore.decrypt(b"wrong horse").is_err());
+
+        // the public key is authenticated, swapping it breaks decryption
+        let mut swapped = keystore.clone();
+        swapped.pubkey = Keypair::new().pubkey().to_string();
+        assert!(swapped.decrypt(b"correct horse").is_err());
+    }
+
+    #[test]
+    fn test_passphrase_file_trims_newline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("passphrase");
+        fs::write(&path, b"correct horse\n").unwrap();
+        let passphrase = read_passphrase(None, path.to_str()).unwrap();
+        assert_eq!(passphrase.as_slice(), b"correct horse");
+        fs::write(&path, b"\n").unwrap();
+        assert!(read_passphrase(None, path.to_str()).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+//! The key the node claims, submits proofs and logs in to bonfire with. Besides a plaintext
+//! keypair file it can be an encrypted keystore unlocked at startup, or a remote signer so the
+//! key never sits on the proving machine.
+
+pub mod keystore;
+pub mod remote;
+
+use {
+    crate::config::SignerConfig,
+    anyhow::Result,
+    keystore::{read_passphrase, Keystore},
+    remote::RemoteSigner,
+    solana_sdk::{signature::read_keypair_file, signer::Signer},
+    std::{sync::Arc, time::Duration},
+    tracing::info,
+};
+
+pub type NodeSigner = Arc<dyn Signer + Send + Sync>;
+
+pub async fn load_signer(config: &SignerConfig) -> Result<NodeSigner> {
+    let signer: NodeSigner = match config {
+        SignerConfig::KeypairFile { path } => {
+            info!("Using Keypair File");
+            Arc::new(read_keypair_file(path).map_err(|_| crate::CliError::InvalidSigner)?)
+        }
+        SignerConfig::EncryptedKeystore {
+            path,
+            passphrase_env,
+            passphrase_file,
+        } => {
+            info!("Using Encrypted Keystore");
+            let passphrase =
+                read_passphrase(passphrase_env.as_deref(), passphrase_file.as_deref())?;
+            Arc::new(Keystore::read(path)?.decrypt(&passphrase)?)
+        }
+        SignerConfig::Remote {
+            url,
+            auth_token_env,
+            timeout_secs,
+        } => {
+            info!("Using Remote Signer at {}", url);
+            let auth_token = auth_token_env
+                .as_deref()
+                .map(std::env::var)
+                .transpose()
+                .map_err(|_| crate::CliError::InvalidSigner)?;
+            Arc::new(
+                RemoteSigner::connect(
+                    url.clone(),
+                    auth_token,
+                    Duration::from_secs(*timeout_secs as u64),
+                )
+                .await?,
+            )
+        }
+    };
+    Ok(signer)
+}
@@ -0,0 +1,225 @@
+//! A signer that keeps the key on another machine and signs over HTTP.
+//!
+//! * `GET <url>/pubkey` answers `{"pubkey": "<base58>"}`
+//! * `POST <url>/sign` with `{"message": "<hex>"}` answers `{"signature": "<base58>"}`
+//!
+//! Requests carry `Authorization: Bearer <token>` when a token is configured. The public key is
+//! fetched once at startup and every signature is verified against it before it is used.
+
+use {
+    anyhow::{anyhow, Result},
+    serde::{Deserialize, Serialize},
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::Signature,
+        signer::{Signer, SignerError},
+    },
+    std::{future::Future, str::FromStr, time::Duration},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PubkeyResponse {
+    pub pubkey: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SignRequest {
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SignResponse {
+    pub signature: String,
+}
+
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    auth_token: Option<String>,
+    pubkey: Pubkey,
+}
+
+impl RemoteSigner {
+    /// Fetches the signer's public key, failing if the signer is unreachable.
+    pub async fn connect(
+        url: String,
+        auth_token: Option<String>,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        let url = url.trim_end_matches('/').to_string();
+        let mut request = client.get(format!("{}/pubkey", url));
+        if let Some(token) = &auth_token {
+            request = request.bearer_auth(token);
+        }
+        let body = request.send().await?.error_for_status()?.bytes().await?;
+        let response: PubkeyResponse = serde_json::from_slice(&body)?;
+        Ok(Self {
+            client,
+            url,
+            auth_token,
+            pubkey: Pubkey::from_str(&response.pubkey)?,
+        })
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature> {
+        let mut request = self
+            .client
+            .post(format!("{}/sign", self.url))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&SignRequest {
+                message: hex::encode(message),
+            })?);
+        if let Some(token) = &self.auth_token {
+            request = request.bearer_auth(token);
+        }
+        let body = request.send().await?.error_for_status()?.bytes().await?;
+        let response: SignResponse = serde_json::from_slice(&body)?;
+        let signature = Signature::from_str(&response.signature)?;
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(anyhow!(
+                "Remote signature does not verify against {}",
+                self.pubkey
+            ));
+        }
+        Ok(signature)
+    }
+}
+
+/// Runs a request to completion from the synchronous `Signer` interface. Inside the node's
+/// multi threaded runtime this parks the calling worker, anywhere else it uses a new runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => tokio::task::block_in_place(|| handle.block_on(future)),
+        Err(_) => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Error building a runtime for the remote signer")
+            .block_on(future),
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn try_pubkey(&self) -> std::result::Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> std::result::Result<Signature, SignerError> {
+        block_on(self.sign(message)).map_err(|e| SignerError::Connection(e.to_string()))
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::signature::Keypair,
+        std::sync::Arc,
+        tokio::{
+            io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+            net::{TcpListener, TcpStream},
+        },
+    };
+
+    /// Answers one request the way a remote signer holding `keypair` would.
+    async fn serve(stream: TcpStream, keypair: &Keypair, token: &str) -> Result<()> {
+        let mut stream = BufReader::new(stream);
+        let mut request_line = String::new();
+        stream.read_line(&mut request_line).await?;
+        let (mut content_length, mut authorized) = (0, false);
+        loop {
+            let mut header = String::new();
+            stream.read_line(&mut header).await?;
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header.split_once(':').unwrap_or_default();
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse()?,
+                "authorization" => authorized = value.trim() == format!("Bearer {}", token),
+                _ => {}
+            }
+        }
+        let mut body = vec![0; content_length];
+        stream.read_exact(&mut body).await?;
+        let (status, body) = if !authorized {
+            ("401 Unauthorized", vec![])
+        } else if request_line.starts_with("GET /pubkey ") {
+            let response = PubkeyResponse {
+                pubkey: keypair.pubkey().to_string(),
+            };
+            ("200 OK", serde_json::to_vec(&response)?)
+        } else if request_line.starts_with("POST /sign ") {
+            let request: SignRequest = serde_json::from_slice(&body)?;
+            let response = SignResponse {
+                signature: keypair
+                    .sign_message(&hex::decode(request.message)?)
+                    .to_string(),
+            };
+            ("200 OK", serde_json::to_vec(&response)?)
+        } else {
+            ("404 Not Found", vec![])
+        };
+        let head = format!(
+            "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+            status,
+            body.len()
+        );
+        let stream = stream.get_mut();
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(&body).await?;
+        Ok(())
+    }
+
+    /// A local stand-in for a remote signer, returns its url.
+    async fn stand_in(keypair: Keypair, token: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let keypair = Arc::new(keypair);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let keypair = keypair.clone();
+                tokio::spawn(async move { serve(stream, &keypair, token).await });
+            }
+        });
+        url
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_signer() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let url = stand_in(keypair, "secret").await;
+        let timeout = Duration::from_secs(5);
+
+        assert!(RemoteSigner::connect(url.clone(), None, timeout)
+            .await
+            .is_err());
+        let signer = RemoteSigner::connect(url, Some("secret".to_string()), timeout)
+            .await
+            .unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+        let signature = signer.try_sign_message(b"claim").unwrap();
+        assert!(signature.verify(pubkey.as_ref(), b"claim"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_signature_is_verified() {
+        let url = stand_in(Keypair::new(), "secret").await;
+        let mut signer =
+            RemoteSigner::connect(url, Some("secret".to_string()), Duration::from_secs(5))
+                .await
+                .unwrap();
+        // a signer answering with another key than it announced
+        signer.pubkey = Keypair::new().pubkey();
+        assert!(matches!(
+            signer.try_sign_message(b"claim"),
+            Err(SignerError::Connection(_))
+        ));
+    }
+}
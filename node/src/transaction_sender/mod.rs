@@ -1,3 +1,5 @@
 pub mod rpc;
 pub mod status;
+pub mod submit;
+pub mod tpu;
 pub mod transaction_sender;
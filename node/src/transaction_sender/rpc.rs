@@ -1,30 +1,33 @@
 use {
     async_trait::async_trait,
     bonsol_interface::{
+        accounts::ExecutionAccount,
         bonsol_schema::{
             ChannelInstruction, ChannelInstructionArgs, ChannelInstructionIxType, ClaimV1,
-            ClaimV1Args, StatusTypes, StatusV1, StatusV1Args,
+            ClaimV1Args, ProofSystem, StatusTypes, StatusV1, StatusV1Args,
         },
+        claim_state::ClaimStateV1,
         util::{deployment_address, execution_address, execution_claim_address},
     },
     dashmap::DashMap,
     flatbuffers::FlatBufferBuilder,
-    solana_rpc_client_api::config::RpcSendTransactionConfig,
     solana_sdk::{
-        account::Account,
-        commitment_config::CommitmentConfig,
-        message::{v0, VersionedMessage},
-        signature::Signature,
-        signer::SignerError,
-        system_program,
-        transaction::VersionedTransaction,
+        account::Account, commitment_config::CommitmentConfig, signature::Signature,
+        signer::SignerError, system_program,
     },
     tokio::task::JoinHandle,
 };
 
-use crate::transaction_sender::status::TransactionStatus;
+use crate::{
+    config::TransactionFeeConfig,
+    observe::*,
+    transaction_sender::{status::TransactionStatus, submit::Submitter, tpu::TpuBroadcaster},
+};
 
-use {std::sync::Arc, tracing::error};
+use {
+    std::{sync::Arc, time::Duration},
+    tracing::{error, warn},
+};
 
 use {
     crate::types::ProgramExec,
@@ -34,32 +37,96 @@ use {
     solana_sdk::{
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
-        signature::Keypair,
         signer::Signer,
     },
     tracing::info,
 };
 
-use crate::transaction_sender::transaction_sender::TransactionSender;
+use crate::{signer::NodeSigner, transaction_sender::transaction_sender::TransactionSender};
+
+/// A sent transaction the status worker re-signs with a fresh blockhash each time the last one
+/// expires unconfirmed, until the block height passes `max_block_height`.
+#[derive(Clone)]
+pub struct Resubmission {
+    pub instruction: Instruction,
+    /// Signature of the latest send, statuses stay keyed by the first one.
+    pub current: Signature,
+    pub max_block_height: u64,
+}
 
 pub struct RpcTransactionSender {
     pub rpc_client: Arc<RpcClient>,
     pub bonsol_program: Pubkey,
-    pub signer: Arc<Keypair>,
+    pub signer: NodeSigner,
     pub txn_status_handle: Option<JoinHandle<()>>,
     pub sigs: Arc<DashMap<Signature, TransactionStatus>>,
+    pub resubmissions: Arc<DashMap<Signature, Resubmission>>,
+    pub submitter: Submitter,
 }
 
 impl RpcTransactionSender {
-    pub fn new(rpc_url: String, bonsol_program: Pubkey, signer: Arc<Keypair>) -> Self {
+    pub fn new(
+        rpc_url: String,
+        bonsol_program: Pubkey,
+        signer: NodeSigner,
+        fees: TransactionFeeConfig,
+    ) -> Self {
+        let rpc_client = Arc::new(RpcClient::new(rpc_url));
         Self {
-            rpc_client: Arc::new(RpcClient::new(rpc_url)),
-            signer,
+            rpc_client: rpc_client.clone(),
+            signer: signer.clone(),
             bonsol_program,
             txn_status_handle: None,
             sigs: Arc::new(DashMap::new()),
+            resubmissions: Arc::new(DashMap::new()),
+            submitter: Submitter {
+                rpc_client,
+                signer,
+                fees,
+                tpu: None,
+            },
         }
     }
+
+    /// Sends claims and proofs through `tpu` first, RPC only when that fails.
+    pub fn with_tpu(mut self, tpu: TpuBroadcaster) -> Self {
+        self.submitter.tpu = Some(Arc::new(tpu));
+        self
+    }
+
+    /// Builds and sends `instruction`, resubmitting it until it lands or `max_block_height`
+    /// passes. The returned signature is the one its status is tracked under.
+    async fn send_instruction(
+        &self,
+        instruction: Instruction,
+        max_block_height: u64,
+    ) -> Result<Signature> {
+        let (tx, last_valid) = self.submitter.build(&instruction).await?;
+        let sig = self.submitter.send(&tx).await?;
+        self.sigs
+            .insert(sig, TransactionStatus::Pending { expiry: last_valid });
+        self.resubmissions.insert(
+            sig,
+            Resubmission {
+                instruction,
+                current: sig,
+                max_block_height,
+            },
+        );
+        Ok(sig)
+    }
+}
+
+/// Whether `account` still holds a pending request. A settled execution stays owned by the
+/// program, shrunk to the byte of its exit code.
+fn is_pending(bonsol_program: &Pubkey, account: Option<&Account>) -> bool {
+    account.is_some_and(|a| {
+        a.owner == *bonsol_program
+            && matches!(
+                ExecutionAccount::load(&a.data),
+                Ok(ExecutionAccount::Pending(_))
+            )
+    })
 }
 
 impl Signer for RpcTransactionSender {
@@ -68,7 +135,7 @@ impl Signer for RpcTransactionSender {
     }
 
     fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
-        Ok(self.signer.pubkey())
+        self.signer.try_pubkey()
     }
 
     fn sign_message(&self, message: &[u8]) -> Signature {
@@ -83,7 +150,7 @@ impl Signer for RpcTransactionSender {
     }
 
     fn is_interactive(&self) -> bool {
-        false
+        self.signer.is_interactive()
     }
 }
 
@@ -95,6 +162,7 @@ impl TransactionSender for RpcTransactionSender {
 
     fn clear_signature_status(&self, sig: &Signature) {
         self.sigs.remove(sig);
+        self.resubmissions.remove(sig);
     }
 
     async fn claim(
@@ -103,9 +171,11 @@ impl TransactionSender for RpcTransactionSender {
         requester: Pubkey,
         execution_account: Pubkey,
         block_commitment: u64,
+        prover_set: Option<Pubkey>,
+        max_block_height: u64,
     ) -> Result<Signature> {
         let (execution_claim_account, _) = execution_claim_address(execution_account.as_ref());
-        let accounts = vec![
+        let mut accounts = vec![
             AccountMeta::new(execution_account, false),
             AccountMeta::new_readonly(requester, false),
             AccountMeta::new(execution_claim_account, false),
@@ -113,6 +183,9 @@ impl TransactionSender for RpcTransactionSender {
             AccountMeta::new(self.signer.pubkey(), true),
             AccountMeta::new_readonly(system_program::id(), false),
         ];
+        if let Some(prover_set) = prover_set {
+            accounts.push(AccountMeta::new_readonly(prover_set, false));
+        }
         let mut fbb = FlatBufferBuilder::new();
         let eid = fbb.create_string(execution_id);
         let stat = ClaimV1::create(
@@ -137,29 +210,7 @@ impl TransactionSender for RpcTransactionSender {
         fbb2.finish(root, None);
         let ix_data = fbb2.finished_data();
         let instruction = Instruction::new_with_bytes(self.bonsol_program, ix_data, accounts);
-        let (blockhash_req, last_valid) = self
-            .rpc_client
-            .get_latest_blockhash_with_commitment(self.rpc_client.commitment())
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to get blockhash: {:?}", e))?;
-
-        let msg =
-            v0::Message::try_compile(&self.signer.pubkey(), &[instruction], &[], blockhash_req)?;
-        let tx = VersionedTransaction::try_new(VersionedMessage::V0(msg), &[&self.signer])?;
-        let sig = self
-            .rpc_client
-            .send_transaction_with_config(
-                &tx,
-                RpcSendTransactionConfig {
-                    skip_preflight: true,
-                    ..Default::default()
-                },
-            )
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to send transaction: {:?}", e))?;
-        self.sigs
-            .insert(sig, TransactionStatus::Pending { expiry: last_valid });
-        Ok(sig)
+        self.send_instruction(instruction, max_block_height).await
     }
 
     async fn submit_proof(
@@ -175,6 +226,8 @@ impl TransactionSender for RpcTransactionSender {
         additional_accounts: Vec<AccountMeta>,
         exit_code_system: u32,
         exit_code_user: u32,
+        proof_system: ProofSystem,
+        max_block_height: u64,
     ) -> Result<Signature> {
         let (execution_request_data_account, _) =
             execution_address(&requester_account, execution_id.as_bytes());
@@ -213,7 +266,8 @@ impl TransactionSender for RpcTransactionSender {
                 committed_outputs: Some(out),               //0-?? bytes lets say 32
                 exit_code_system,                           //4 byte
                 exit_code_user,                             //4 byte
-            }, //total ~408 bytes plenty of room for more stuff
+                proof_system,                               //1 byte
+            }, //total ~409 bytes plenty of room for more stuff
         );
         fbb.finish(stat, None);
         let statbytes = fbb.finished_data();
@@ -230,34 +284,36 @@ impl TransactionSender for RpcTransactionSender {
         fbb2.finish(root, None);
         let ix_data = fbb2.finished_data();
         let instruction = Instruction::new_with_bytes(self.bonsol_program, ix_data, accounts);
-        let (blockhash, last_valid) = self
-            .rpc_client
-            .get_latest_blockhash_with_commitment(self.rpc_client.commitment())
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to get blockhash: {:?}", e))?;
+        let sig = self.send_instruction(instruction, max_block_height).await?;
 
-        let msg = v0::Message::try_compile(&self.signer.pubkey(), &[instruction], &[], blockhash)?;
-        let tx = VersionedTransaction::try_new(VersionedMessage::V0(msg), &[&self.signer])?;
-
-        let sig = self
-            .rpc_client
-            .send_and_confirm_transaction_with_spinner_and_config(
-                &tx,
-                CommitmentConfig::confirmed(),
-                RpcSendTransactionConfig {
-                    skip_preflight: true,
-                    ..Default::default()
-                },
-            )
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to send transaction: {:?}", e))?;
-        self.sigs
-            .insert(sig, TransactionStatus::Pending { expiry: last_valid });
-        Ok(sig)
+        // wait until the submission, or one of its resubmissions, is confirmed
+        let mut interval = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            interval.tick().await;
+            match self.get_signature_status(&sig) {
+                Some(TransactionStatus::Confirmed(status)) => {
+                    if let Some(err) = status.err {
+                        return Err(anyhow::anyhow!("Proof submission failed: {:?}", err));
+                    }
+                    if status.satisfies_commitment(CommitmentConfig::confirmed()) {
+                        return Ok(sig);
+                    }
+                }
+                Some(TransactionStatus::Pending { .. }) => {}
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "Proof submission expired at block {}",
+                        max_block_height
+                    ))
+                }
+            }
+        }
     }
 
     fn start(&mut self) {
         let sigs_ref = self.sigs.clone();
+        let resubmissions = self.resubmissions.clone();
+        let submitter = self.submitter.clone();
         let rpc_client = self.rpc_client.clone();
         self.txn_status_handle = Some(tokio::spawn(async move {
             let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
@@ -268,22 +324,67 @@ impl TransactionSender for RpcTransactionSender {
                     .await;
 
                 if let Ok(current_block_height) = current_block_height {
-                    sigs_ref.retain(|k, v| {
-                        if let TransactionStatus::Pending { expiry } = v {
-                            if *expiry < current_block_height {
-                                info!("Transaction expired {}", k);
-                                return false;
+                    // statuses are looked up under each transaction's latest signature
+                    let tracked = sigs_ref
+                        .iter()
+                        .map(|x| {
+                            let sig = *x.key();
+                            (sig, resubmissions.get(&sig).map_or(sig, |r| r.current))
+                        })
+                        .collect_vec();
+                    let current_sigs = tracked.iter().map(|(_, current)| *current).collect_vec();
+                    let statuses = rpc_client.get_signature_statuses(&current_sigs).await;
+                    if let Ok(statuses) = statuses {
+                        for ((sig, _), status) in tracked.into_iter().zip(statuses.value) {
+                            if let Some(status) = status {
+                                sigs_ref.insert(sig, TransactionStatus::Confirmed(status));
                             }
                         }
-                        true
-                    });
-                    let all_sigs = sigs_ref.iter().map(|x| *x.key()).collect_vec();
-                    let statuses = rpc_client.get_signature_statuses(&all_sigs).await;
-                    if let Ok(statuses) = statuses {
-                        for sig in all_sigs.into_iter().zip(statuses.value.into_iter()) {
-                            if let Some(status) = sig.1 {
-                                sigs_ref.insert(sig.0, TransactionStatus::Confirmed(status));
+                    }
+                    // checked after the statuses so a transaction that landed in its last
+                    // valid block is not sent again
+                    let expired = sigs_ref
+                        .iter()
+                        .filter(|x| match x.value() {
+                            TransactionStatus::Pending { expiry } => *expiry < current_block_height,
+                            _ => false,
+                        })
+                        .map(|x| *x.key())
+                        .collect_vec();
+                    for sig in expired {
+                        let resubmission = resubmissions
+                            .get(&sig)
+                            .map(|r| r.value().clone())
+                            .filter(|r| r.max_block_height >= current_block_height);
+                        let Some(resubmission) = resubmission else {
+                            info!("Transaction expired {}", sig);
+                            emit_event!(MetricEvents::TransactionExpired, signature => sig.to_string());
+                            sigs_ref.remove(&sig);
+                            resubmissions.remove(&sig);
+                            continue;
+                        };
+                        // a failed attempt is retried on the next tick until the deadline
+                        let resent = async {
+                            let (tx, last_valid) =
+                                submitter.build(&resubmission.instruction).await?;
+                            Ok::<_, anyhow::Error>((submitter.send(&tx).await?, last_valid))
+                        }
+                        .await;
+                        match resent {
+                            Ok((current, last_valid)) => {
+                                info!("Resubmitted transaction {} as {}", sig, current);
+                                emit_event!(MetricEvents::TransactionResubmitted, signature => sig.to_string());
+                                resubmissions.insert(
+                                    sig,
+                                    Resubmission {
+                                        current,
+                                        ..resubmission
+                                    },
+                                );
+                                sigs_ref
+                                    .insert(sig, TransactionStatus::Pending { expiry: last_valid });
                             }
+                            Err(e) => warn!("Error resubmitting transaction {}: {:?}", sig, e),
                         }
                     }
                 } else {
@@ -307,4 +408,97 @@ impl TransactionSender for RpcTransactionSender {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))
     }
+
+    async fn get_prover_set_account(&self, prover_set: &Pubkey) -> Result<Account> {
+        self.rpc_client
+            .get_account(prover_set)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))
+    }
+
+    async fn get_claim_state(&self, execution_account: &Pubkey) -> Result<Option<ClaimStateV1>> {
+        let (execution_claim_account, _) = execution_claim_address(execution_account.as_ref());
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&execution_claim_account, self.rpc_client.commitment())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))?
+            .value;
+        account
+            .map(|a| ClaimStateV1::load_claim_owned(&a.data).map_err(Into::into))
+            .transpose()
+    }
+
+    async fn get_execution_account(&self, execution_account: &Pubkey) -> Result<Option<Account>> {
+        self.rpc_client
+            .get_account_with_commitment(execution_account, self.rpc_client.commitment())
+            .await
+            .map(|response| response.value)
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))
+    }
+
+    async fn is_execution_pending(&self, execution_account: &Pubkey) -> Result<bool> {
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(execution_account, self.rpc_client.commitment())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))?
+            .value;
+        Ok(is_pending(&self.bonsol_program, account.as_ref()))
+    }
+
+    async fn get_balance(&self) -> Result<u64> {
+        self.rpc_client
+            .get_balance(&self.signer.pubkey())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get balance: {:?}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bonsol_interface::bonsol_schema::{ExecutionRequestV1, ExecutionRequestV1Args, ExitCode},
+    };
+
+    fn account(owner: Pubkey, data: Vec<u8>) -> Account {
+        Account {
+            owner,
+            data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settled_execution_is_not_pending() {
+        let program = Pubkey::new_unique();
+        let mut fbb = FlatBufferBuilder::new();
+        let execution_id = fbb.create_string("exec");
+        let request = ExecutionRequestV1::create(
+            &mut fbb,
+            &ExecutionRequestV1Args {
+                execution_id: Some(execution_id),
+                max_block_height: 100,
+                ..Default::default()
+            },
+        );
+        fbb.finish(request, None);
+        let request = fbb.finished_data().to_vec();
+
+        assert!(is_pending(
+            &program,
+            Some(&account(program, request.clone()))
+        ));
+        // settling shrinks the account to its exit code without changing the owner
+        let settled = account(program, vec![ExitCode::Success as u8]);
+        assert!(!is_pending(&program, Some(&settled)));
+        let expired = account(program, vec![ExitCode::Expired as u8]);
+        assert!(!is_pending(&program, Some(&expired)));
+        assert!(!is_pending(
+            &program,
+            Some(&account(Pubkey::new_unique(), request))
+        ));
+        assert!(!is_pending(&program, None));
+    }
 }
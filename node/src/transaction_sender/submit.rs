@@ -0,0 +1,199 @@
+//! Builds, signs and sends the node's transactions. Each transaction is simulated to size its
+//! compute unit limit and pays a priority fee taken from the fees recently paid on the accounts
+//! it writes, within the configured caps. The blockhash, the recent fees and the simulation are
+//! fetched together so a claim pays a single round trip for them.
+
+use {
+    super::tpu::TpuBroadcaster,
+    crate::{config::TransactionFeeConfig, observe::*, signer::NodeSigner},
+    anyhow::{anyhow, bail, Result},
+    solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    solana_rpc_client_api::config::{RpcSendTransactionConfig, RpcSimulateTransactionConfig},
+    solana_sdk::{
+        compute_budget::ComputeBudgetInstruction,
+        hash::Hash,
+        instruction::Instruction,
+        message::{v0, VersionedMessage},
+        pubkey::Pubkey,
+        signature::Signature,
+        signer::Signer,
+        transaction::VersionedTransaction,
+    },
+    std::sync::Arc,
+    tracing::warn,
+};
+
+/// The most compute units a transaction may use.
+const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Micro lamports per compute unit at `percentile` of `recent_fees`, kept within the per unit
+/// bounds and lowered until `compute_units` of it stay under the per transaction cap.
+pub fn priority_fee(config: &TransactionFeeConfig, recent_fees: &[u64], compute_units: u32) -> u64 {
+    let mut fees = recent_fees.to_vec();
+    fees.sort_unstable();
+    let percentile = config.priority_fee_percentile.min(100) as usize;
+    let fee = fees
+        .get(fees.len().saturating_sub(1) * percentile / 100)
+        .copied()
+        .unwrap_or_default();
+    let fee = fee
+        .max(config.min_priority_fee_micro_lamports)
+        .min(config.max_priority_fee_micro_lamports);
+    let affordable = (config.max_priority_fee_lamports as u128 * 1_000_000
+        / compute_units.max(1) as u128)
+        .min(u64::MAX as u128) as u64;
+    fee.min(affordable)
+}
+
+/// The simulated compute units plus the configured margin.
+pub fn compute_unit_limit(config: &TransactionFeeConfig, units_consumed: u64) -> u32 {
+    let units = units_consumed * (100 + config.compute_unit_margin_percent as u64) / 100;
+    units.min(MAX_COMPUTE_UNITS as u64) as u32
+}
+
+fn with_compute_budget(instruction: &Instruction, limit: u32, price: u64) -> Vec<Instruction> {
+    vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(limit),
+        ComputeBudgetInstruction::set_compute_unit_price(price),
+        instruction.clone(),
+    ]
+}
+
+#[derive(Clone)]
+pub struct Submitter {
+    pub rpc_client: Arc<RpcClient>,
+    pub signer: NodeSigner,
+    pub fees: TransactionFeeConfig,
+    pub tpu: Option<Arc<TpuBroadcaster>>,
+}
+
+impl Submitter {
+    /// Signs `instruction` with a fresh blockhash, returning the transaction and the last block
+    /// height it can land at. Fails when the simulation does, the transaction would too.
+    pub async fn build(&self, instruction: &Instruction) -> Result<(VersionedTransaction, u64)> {
+        let payer = self.signer.pubkey();
+        let message = v0::Message::try_compile(
+            &payer,
+            &with_compute_budget(instruction, MAX_COMPUTE_UNITS, 0),
+            &[],
+            Hash::default(),
+        )?;
+        let simulation = VersionedTransaction {
+            signatures: vec![Signature::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::V0(message),
+        };
+        let writable: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .filter(|a| a.is_writable)
+            .map(|a| a.pubkey)
+            .collect();
+        let commitment = self.rpc_client.commitment();
+        let (blockhash, recent_fees, simulated) = tokio::try_join!(
+            async {
+                self.rpc_client
+                    .get_latest_blockhash_with_commitment(commitment)
+                    .await
+                    .map_err(|e| anyhow!("Failed to get blockhash: {:?}", e))
+            },
+            async {
+                self.rpc_client
+                    .get_recent_prioritization_fees(&writable)
+                    .await
+                    .map_err(|e| anyhow!("Failed to get prioritization fees: {:?}", e))
+            },
+            async {
+                self.rpc_client
+                    .simulate_transaction_with_config(
+                        &simulation,
+                        RpcSimulateTransactionConfig {
+                            sig_verify: false,
+                            replace_recent_blockhash: true,
+                            commitment: Some(commitment),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .map_err(|e| anyhow!("Failed to simulate transaction: {:?}", e))
+            },
+        )?;
+        let (blockhash, last_valid) = blockhash;
+        let simulated = simulated.value;
+        if let Some(err) = simulated.err {
+            bail!(
+                "Transaction simulation failed: {:?} {:?}",
+                err,
+                simulated.logs.unwrap_or_default()
+            );
+        }
+        let limit = simulated.units_consumed.map_or(MAX_COMPUTE_UNITS, |units| {
+            compute_unit_limit(&self.fees, units)
+        });
+        let recent_fees: Vec<u64> = recent_fees.iter().map(|f| f.prioritization_fee).collect();
+        let price = priority_fee(&self.fees, &recent_fees, limit);
+        emit_histogram!(MetricEvents::PriorityFee, price as f64, compute_units => limit);
+        let message = v0::Message::try_compile(
+            &payer,
+            &with_compute_budget(instruction, limit, price),
+            &[],
+            blockhash,
+        )?;
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&self.signer])?;
+        Ok((tx, last_valid))
+    }
+
+    /// Sends through the TPU when connected, through RPC when not or when that fails.
+    pub async fn send(&self, tx: &VersionedTransaction) -> Result<Signature> {
+        if let Some(tpu) = &self.tpu {
+            match tpu.send(tx).await {
+                Ok(()) => {
+                    return tx
+                        .signatures
+                        .first()
+                        .copied()
+                        .ok_or_else(|| anyhow!("Transaction is not signed"))
+                }
+                Err(e) => {
+                    warn!("TPU send failed, falling back to RPC: {:?}", e);
+                    emit_event!(MetricEvents::TpuSendFailed, error => e.to_string());
+                }
+            }
+        }
+        // the transaction was just simulated, preflight would only repeat that
+        self.rpc_client
+            .send_transaction_with_config(
+                tx,
+                RpcSendTransactionConfig {
+                    skip_preflight: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to send transaction: {:?}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_fee_caps() {
+        let config = TransactionFeeConfig {
+            priority_fee_percentile: 50,
+            min_priority_fee_micro_lamports: 10,
+            max_priority_fee_micro_lamports: 5_000,
+            max_priority_fee_lamports: 100,
+            compute_unit_margin_percent: 20,
+        };
+        // no recent fees pays the floor
+        assert_eq!(priority_fee(&config, &[], 10_000), 10);
+        assert_eq!(priority_fee(&config, &[0, 300, 100, 200, 400], 10_000), 200);
+        assert_eq!(priority_fee(&config, &[9_000, 10_000], 10_000), 5_000);
+        // 100 lamports over 50k units allows 2000 micro lamports per unit
+        assert_eq!(priority_fee(&config, &[9_000, 10_000], 50_000), 2_000);
+
+        assert_eq!(compute_unit_limit(&config, 10_000), 12_000);
+        assert_eq!(compute_unit_limit(&config, 1_300_000), MAX_COMPUTE_UNITS);
+    }
+}
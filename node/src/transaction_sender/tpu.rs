@@ -0,0 +1,43 @@
+//! Sends transactions straight to the TPU of the current and upcoming leaders over QUIC, the way
+//! validators forward them, instead of through an RPC node's `send_transaction`. Claims are races
+//! and the extra hop through a shared RPC is enough to lose them.
+
+use {
+    anyhow::Result,
+    solana_client::{nonblocking::tpu_client::TpuClient, tpu_client::TpuClientConfig},
+    solana_quic_client::{QuicConfig, QuicConnectionManager, QuicPool},
+    solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::transaction::VersionedTransaction,
+    std::sync::Arc,
+};
+
+pub struct TpuBroadcaster {
+    client: TpuClient<QuicPool, QuicConnectionManager, QuicConfig>,
+}
+
+impl TpuBroadcaster {
+    /// Follows the leader schedule through `rpc_client` and slot updates from `websocket_url`,
+    /// sending each transaction to the leaders of the next `fanout_slots` slots.
+    pub async fn connect(
+        rpc_client: Arc<RpcClient>,
+        websocket_url: &str,
+        fanout_slots: u64,
+    ) -> Result<Self> {
+        let client = TpuClient::new(
+            "bonsol-node",
+            rpc_client,
+            websocket_url,
+            TpuClientConfig { fanout_slots },
+        )
+        .await?;
+        Ok(Self { client })
+    }
+
+    /// Succeeds once a leader accepted the transaction, which does not mean it landed.
+    pub async fn send(&self, tx: &VersionedTransaction) -> Result<()> {
+        self.client
+            .try_send_wire_transaction(bincode::serialize(tx)?)
+            .await?;
+        Ok(())
+    }
+}
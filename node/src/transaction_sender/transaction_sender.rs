@@ -4,6 +4,7 @@ use crate::transaction_sender::status::TransactionStatus;
 
 use {
     async_trait::async_trait,
+    bonsol_interface::{bonsol_schema::ProofSystem, claim_state::ClaimStateV1},
     solana_sdk::{account::Account, signature::Signature},
 };
 
@@ -15,13 +16,18 @@ use {
 #[async_trait]
 pub trait TransactionSender {
     fn start(&mut self);
+    /// Claims the execution, resending the claim until it lands or `max_block_height` passes.
     async fn claim(
         &self,
         execution_id: &str,
         requester: Pubkey,
         execution_account: Pubkey,
         block_commitment: u64,
+        prover_set: Option<Pubkey>,
+        max_block_height: u64,
     ) -> Result<Signature>;
+    /// Submits the proof and waits for it to be confirmed, resending it until then or until
+    /// `max_block_height` passes.
     async fn submit_proof(
         &self,
         execution_id: &str,
@@ -35,9 +41,20 @@ pub trait TransactionSender {
         additional_accounts: Vec<AccountMeta>,
         exit_code_system: u32,
         exit_code_user: u32,
+        proof_system: ProofSystem,
+        max_block_height: u64,
     ) -> Result<Signature>;
     async fn get_current_block(&self) -> Result<u64>;
     fn get_signature_status(&self, sig: &Signature) -> Option<TransactionStatus>;
     fn clear_signature_status(&self, sig: &Signature);
     async fn get_deployment_account(&self, image_id: &str) -> Result<Account>;
+    async fn get_prover_set_account(&self, prover_set: &Pubkey) -> Result<Account>;
+    /// The claim currently held on an execution, if any.
+    async fn get_claim_state(&self, execution_account: &Pubkey) -> Result<Option<ClaimStateV1>>;
+    /// The data of an execution account, if it exists.
+    async fn get_execution_account(&self, execution_account: &Pubkey) -> Result<Option<Account>>;
+    /// Whether an execution account is still open, it is closed once a status settles it.
+    async fn is_execution_pending(&self, execution_account: &Pubkey) -> Result<bool>;
+    /// Lamports held by the signer.
+    async fn get_balance(&self) -> Result<u64>;
 }
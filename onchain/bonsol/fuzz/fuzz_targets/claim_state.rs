@@ -0,0 +1,14 @@
+#![no_main]
+
+use bonsol_interface::claim_state::ClaimStateV1;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut buf = data.to_vec();
+    if let Ok(claim) = ClaimStateV1::load_claim(&mut buf) {
+        let _ = (claim.claimer, claim.claimed_at, claim.block_commitment);
+    }
+    if let Ok(claim) = ClaimStateV1::load_claim_owned(data) {
+        let _ = (claim.claimer, claim.claimed_at, claim.block_commitment);
+    }
+});
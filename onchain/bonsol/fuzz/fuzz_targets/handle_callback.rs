@@ -0,0 +1,34 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bonsol_fuzz::{AccountStorage, ExecuteInput, FuzzAccount, REQUESTER};
+use bonsol_interface::{
+    bonsol_schema::parse_ix_data, callback::handle_callback, util::execution_address,
+};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    image_id: String,
+    execution_id: String,
+    accounts: Vec<FuzzAccount>,
+    /// When set the first account holds this request instead of arbitrary bytes
+    stored_request: Option<ExecuteInput>,
+    data: Vec<u8>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut storage = AccountStorage::new(&input.accounts, &input.execution_id, &input.image_id);
+    if let Some(request) = input.stored_request.as_ref().and_then(|r| r.ix_data()) {
+        let stored = parse_ix_data(&request)
+            .ok()
+            .and_then(|ix| ix.execute_v1())
+            .map(|er| er.bytes().to_vec());
+        if let Some(stored) = stored {
+            storage.set_first_data(stored);
+        }
+    }
+    let (execution_account, _) = execution_address(&REQUESTER, input.execution_id.as_bytes());
+    let infos = storage.infos();
+    let _ = handle_callback(&input.image_id, &execution_account, &infos, &input.data);
+});
@@ -0,0 +1,92 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bonsol::actions::fuzzing::{
+    claim_accounts, deploy_accounts, execute_accounts, status_accounts,
+};
+use bonsol_fuzz::{
+    claim_ix_data, AccountStorage, DeployInput, ExecuteInput, FuzzAccount, StatusInput,
+};
+use bonsol_interface::bonsol_schema::{parse_ix_data, ChannelInstructionIxType};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum FuzzInstruction {
+    Raw(Vec<u8>),
+    Execute(ExecuteInput),
+    Claim {
+        execution_id: String,
+        block_commitment: u64,
+    },
+    Status(StatusInput),
+    Deploy(DeployInput),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    ix: FuzzInstruction,
+    accounts: Vec<FuzzAccount>,
+    current_block: u64,
+}
+
+fuzz_target!(|input: Input| {
+    let data = match &input.ix {
+        FuzzInstruction::Raw(data) => Some(data.clone()),
+        FuzzInstruction::Execute(execute) => execute.ix_data(),
+        FuzzInstruction::Claim {
+            execution_id,
+            block_commitment,
+        } => Some(claim_ix_data(execution_id, *block_commitment)),
+        FuzzInstruction::Status(status) => Some(status.ix_data()),
+        FuzzInstruction::Deploy(deploy) => deploy.ix_data(),
+    };
+    let Some(data) = data else {
+        return;
+    };
+    let Ok(ix) = parse_ix_data(&data) else {
+        return;
+    };
+
+    match ix.ix_type() {
+        ChannelInstructionIxType::ExecuteV1 => {
+            let Some(er) = ix.execute_v1_verified() else {
+                return;
+            };
+            let mut storage = AccountStorage::new(
+                &input.accounts,
+                er.execution_id().unwrap_or_default(),
+                er.image_id().unwrap_or_default(),
+            );
+            let infos = storage.infos();
+            let _ = execute_accounts(&infos, &er);
+        }
+        ChannelInstructionIxType::ClaimV1 => {
+            let Some(cl) = ix.claim_v1_verified() else {
+                return;
+            };
+            let mut storage =
+                AccountStorage::new(&input.accounts, cl.execution_id().unwrap_or_default(), "");
+            let infos = storage.infos();
+            let _ = claim_accounts(&infos, &cl, input.current_block);
+        }
+        ChannelInstructionIxType::StatusV1 => {
+            let Some(st) = ix.status_v1_verified() else {
+                return;
+            };
+            let mut storage =
+                AccountStorage::new(&input.accounts, st.execution_id().unwrap_or_default(), "");
+            let infos = storage.infos();
+            let _ = status_accounts(&infos, &st);
+        }
+        ChannelInstructionIxType::DeployV1 => {
+            let Some(dp) = ix.deploy_v1_verified() else {
+                return;
+            };
+            let mut storage =
+                AccountStorage::new(&input.accounts, "", dp.image_id().unwrap_or_default());
+            let infos = storage.infos();
+            let _ = deploy_accounts(&infos, &dp);
+        }
+        _ => {}
+    }
+});
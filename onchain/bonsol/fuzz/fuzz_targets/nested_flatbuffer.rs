@@ -0,0 +1,35 @@
+#![no_main]
+
+//! The generated `*_nested_flatbuffer` accessors follow the nested payload without verifying it,
+//! the program reads payloads through the `*_verified` accessors instead. Following unverified
+//! bytes is what those accessors exist to avoid, so this target only calls the generated ones on
+//! payloads that verify and checks both read the same table.
+
+use bonsol_interface::bonsol_schema::parse_ix_data;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(ix) = parse_ix_data(data) else {
+        return;
+    };
+    if let Some(er) = ix.execute_v1_verified() {
+        let nested = ix.execute_v1_nested_flatbuffer().unwrap();
+        assert_eq!(format!("{er:?}"), format!("{nested:?}"));
+    }
+    if let Some(st) = ix.status_v1_verified() {
+        let nested = ix.status_v1_nested_flatbuffer().unwrap();
+        assert_eq!(format!("{st:?}"), format!("{nested:?}"));
+    }
+    if let Some(dp) = ix.deploy_v1_verified() {
+        let nested = ix.deploy_v1_nested_flatbuffer().unwrap();
+        assert_eq!(format!("{dp:?}"), format!("{nested:?}"));
+    }
+    if let Some(cl) = ix.claim_v1_verified() {
+        let nested = ix.claim_v1_nested_flatbuffer().unwrap();
+        assert_eq!(format!("{cl:?}"), format!("{nested:?}"));
+    }
+    if let Some(up) = ix.prover_set_update_v1_verified() {
+        let nested = ix.prover_set_update_v1_nested_flatbuffer().unwrap();
+        assert_eq!(format!("{up:?}"), format!("{nested:?}"));
+    }
+});
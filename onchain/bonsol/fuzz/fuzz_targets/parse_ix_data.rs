@@ -0,0 +1,45 @@
+#![no_main]
+
+use bonsol_fuzz::walk_execution_request;
+use bonsol_interface::bonsol_schema::parse_ix_data;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(ix) = parse_ix_data(data) else {
+        return;
+    };
+    let _ = ix.ix_type();
+    if let Some(er) = ix.execute_v1_verified() {
+        walk_execution_request(&er);
+    }
+    if let Some(st) = ix.status_v1_verified() {
+        let _ = (st.execution_id(), st.status(), st.proof_system());
+        let _ = (st.exit_code_system(), st.exit_code_user());
+        let _ = st.proof().map(|d| d.bytes().len());
+        let _ = st.execution_digest().map(|d| d.bytes().len());
+        let _ = st.input_digest().map(|d| d.bytes().len());
+        let _ = st.assumption_digest().map(|d| d.bytes().len());
+        let _ = st.committed_outputs().map(|d| d.bytes().len());
+    }
+    if let Some(dp) = ix.deploy_v1_verified() {
+        let _ = (dp.image_id(), dp.program_name(), dp.url(), dp.size_());
+        let _ = dp.owner().map(|d| d.bytes().len());
+        if let Some(inputs) = dp.inputs() {
+            for input in inputs.iter() {
+                let _ = input;
+            }
+        }
+    }
+    if let Some(cl) = ix.claim_v1_verified() {
+        let _ = (cl.execution_id(), cl.block_commitment());
+    }
+    if let Some(up) = ix.prover_set_update_v1_verified() {
+        let _ = (up.set_id(), up.op());
+        if let Some(provers) = up.provers() {
+            for prover in provers.iter() {
+                let key: [u8; 32] = prover.bytes().into();
+                let _ = key;
+            }
+        }
+    }
+});
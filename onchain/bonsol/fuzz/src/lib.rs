@@ -0,0 +1,323 @@
+//! Structured input for the bonsol fuzz targets.
+
+use arbitrary::Arbitrary;
+use bonsol_interface::{
+    bonsol_schema::{
+        ChannelInstruction, ChannelInstructionArgs, ChannelInstructionIxType, ClaimV1, ClaimV1Args,
+        ExecutionRequestV1, ProgramInputType, StatusTypes, StatusV1, StatusV1Args,
+    },
+    instructions::{deploy_v1, execute_v1, ExecutionConfig, InputRef},
+    util::{deployment_address, execution_address, execution_claim_address},
+};
+use flatbuffers::FlatBufferBuilder;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey, system_program};
+
+pub const REQUESTER: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+/// Keys are drawn from the addresses the program derives so fuzzing gets past the pda checks.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum KeyChoice {
+    Requester,
+    Execution,
+    ExecutionClaim,
+    Deployment,
+    Bonsol,
+    System,
+    Other(u8),
+}
+
+impl KeyChoice {
+    pub fn resolve(self, execution_id: &str, image_id: &str) -> Pubkey {
+        match self {
+            KeyChoice::Requester => REQUESTER,
+            KeyChoice::Execution => execution_address(&REQUESTER, execution_id.as_bytes()).0,
+            KeyChoice::ExecutionClaim => {
+                let exec = execution_address(&REQUESTER, execution_id.as_bytes()).0;
+                execution_claim_address(exec.as_ref()).0
+            }
+            KeyChoice::Deployment => deployment_address(image_id).0,
+            KeyChoice::Bonsol => bonsol::ID,
+            KeyChoice::System => system_program::ID,
+            KeyChoice::Other(n) => Pubkey::new_from_array([n; 32]),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzAccount {
+    pub key: KeyChoice,
+    pub owner: KeyChoice,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+/// Owns the account state `AccountInfo`s borrow from.
+pub struct AccountStorage {
+    keys: Vec<Pubkey>,
+    owners: Vec<Pubkey>,
+    lamports: Vec<u64>,
+    data: Vec<Vec<u8>>,
+    flags: Vec<(bool, bool, bool)>,
+}
+
+impl AccountStorage {
+    pub fn new(accounts: &[FuzzAccount], execution_id: &str, image_id: &str) -> Self {
+        AccountStorage {
+            keys: accounts
+                .iter()
+                .map(|a| a.key.resolve(execution_id, image_id))
+                .collect(),
+            owners: accounts
+                .iter()
+                .map(|a| a.owner.resolve(execution_id, image_id))
+                .collect(),
+            lamports: accounts.iter().map(|a| a.lamports).collect(),
+            data: accounts.iter().map(|a| a.data.clone()).collect(),
+            flags: accounts
+                .iter()
+                .map(|a| (a.is_signer, a.is_writable, a.executable))
+                .collect(),
+        }
+    }
+
+    /// Replaces the data of the first account, used to place a valid request where one is read.
+    pub fn set_first_data(&mut self, data: Vec<u8>) {
+        if let Some(first) = self.data.first_mut() {
+            *first = data;
+        }
+    }
+
+    pub fn infos(&mut self) -> Vec<AccountInfo<'_>> {
+        self.keys
+            .iter()
+            .zip(self.owners.iter())
+            .zip(self.lamports.iter_mut())
+            .zip(self.data.iter_mut())
+            .zip(self.flags.iter())
+            .map(
+                |((((key, owner), lamports), data), &(is_signer, is_writable, executable))| {
+                    AccountInfo::new(
+                        key,
+                        is_signer,
+                        is_writable,
+                        lamports,
+                        data,
+                        owner,
+                        executable,
+                        0,
+                    )
+                },
+            )
+            .collect()
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct ExecuteInput {
+    pub execution_id: String,
+    pub image_id: String,
+    pub inputs: Vec<(bool, Vec<u8>)>,
+    pub tip: u64,
+    pub expiry: u64,
+    pub verify_input_hash: bool,
+    pub input_hash: Option<[u8; 32]>,
+    pub forward_output: bool,
+    pub compact_storage: bool,
+    pub authorized_provers: Vec<u8>,
+}
+
+impl ExecuteInput {
+    pub fn ix_data(&self) -> Option<Vec<u8>> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|(public, data)| {
+                if *public {
+                    InputRef::public(data)
+                } else {
+                    InputRef::private(data)
+                }
+            })
+            .collect();
+        let ix = execute_v1(
+            &REQUESTER,
+            &REQUESTER,
+            &self.image_id,
+            &self.execution_id,
+            inputs,
+            self.tip,
+            self.expiry,
+            ExecutionConfig {
+                verify_input_hash: self.verify_input_hash,
+                input_hash: self.input_hash.as_ref().map(|h| h.as_slice()),
+                forward_output: self.forward_output,
+                compact_storage: self.compact_storage,
+                ..Default::default()
+            },
+            None,
+            None,
+            self.authorized_provers
+                .iter()
+                .map(|n| Pubkey::new_from_array([*n; 32]))
+                .collect(),
+        )
+        .ok()?;
+        Some(ix.data)
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct DeployInput {
+    pub image_id: String,
+    pub image_size: u64,
+    pub program_name: String,
+    pub url: String,
+    pub inputs: Vec<bool>,
+}
+
+impl DeployInput {
+    pub fn ix_data(&self) -> Option<Vec<u8>> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|public| {
+                if *public {
+                    ProgramInputType::Public
+                } else {
+                    ProgramInputType::Private
+                }
+            })
+            .collect();
+        let ix = deploy_v1(
+            &REQUESTER,
+            &self.image_id,
+            self.image_size,
+            &self.program_name,
+            &self.url,
+            inputs,
+        )
+        .ok()?;
+        Some(ix.data)
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct StatusInput {
+    pub execution_id: String,
+    pub proof: Option<Vec<u8>>,
+    pub execution_digest: Option<Vec<u8>>,
+    pub input_digest: Option<Vec<u8>>,
+    pub assumption_digest: Option<Vec<u8>>,
+    pub committed_outputs: Option<Vec<u8>>,
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
+}
+
+impl StatusInput {
+    pub fn ix_data(&self) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let execution_id = fbb.create_string(&self.execution_id);
+        let proof = self.proof.as_ref().map(|v| fbb.create_vector(v));
+        let execution_digest = self.execution_digest.as_ref().map(|v| fbb.create_vector(v));
+        let input_digest = self.input_digest.as_ref().map(|v| fbb.create_vector(v));
+        let assumption_digest = self
+            .assumption_digest
+            .as_ref()
+            .map(|v| fbb.create_vector(v));
+        let committed_outputs = self
+            .committed_outputs
+            .as_ref()
+            .map(|v| fbb.create_vector(v));
+        let status = StatusV1::create(
+            &mut fbb,
+            &StatusV1Args {
+                execution_id: Some(execution_id),
+                status: StatusTypes::Completed,
+                proof,
+                execution_digest,
+                input_digest,
+                assumption_digest,
+                committed_outputs,
+                exit_code_system: self.exit_code_system,
+                exit_code_user: self.exit_code_user,
+                ..Default::default()
+            },
+        );
+        fbb.finish(status, None);
+        channel_ix_data(ChannelInstructionIxType::StatusV1, fbb.finished_data())
+    }
+}
+
+pub fn claim_ix_data(execution_id: &str, block_commitment: u64) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let execution_id = fbb.create_string(execution_id);
+    let claim = ClaimV1::create(
+        &mut fbb,
+        &ClaimV1Args {
+            execution_id: Some(execution_id),
+            block_commitment,
+        },
+    );
+    fbb.finish(claim, None);
+    channel_ix_data(ChannelInstructionIxType::ClaimV1, fbb.finished_data())
+}
+
+fn channel_ix_data(ix_type: ChannelInstructionIxType, payload: &[u8]) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let payload = fbb.create_vector(payload);
+    let args = match ix_type {
+        ChannelInstructionIxType::ClaimV1 => ChannelInstructionArgs {
+            ix_type,
+            claim_v1: Some(payload),
+            ..Default::default()
+        },
+        _ => ChannelInstructionArgs {
+            ix_type,
+            status_v1: Some(payload),
+            ..Default::default()
+        },
+    };
+    let ix = ChannelInstruction::create(&mut fbb, &args);
+    fbb.finish(ix, None);
+    fbb.finished_data().to_vec()
+}
+
+/// Reads every field of a verified request, the accessors must not panic on any of them.
+pub fn walk_execution_request(er: &ExecutionRequestV1) {
+    let _ = (
+        er.execution_id(),
+        er.image_id(),
+        er.tip(),
+        er.max_block_height(),
+    );
+    let _ = (
+        er.verify_input_hash(),
+        er.forward_output(),
+        er.compact_storage(),
+    );
+    let _ = (er.prover_version(), er.proof_system(), er.request_digest());
+    let _ = er.input_digest().map(|d| d.bytes().len());
+    let _ = er.callback_program_id().map(|d| d.bytes().len());
+    let _ = er.callback_instruction_prefix().map(|d| d.bytes().len());
+    let _ = er.prover_set().map(|d| d.bytes().len());
+    if let Some(inputs) = er.input() {
+        for input in inputs.iter() {
+            let _ = (input.input_type(), input.data().map(|d| d.bytes().len()));
+        }
+    }
+    if let Some(accounts) = er.callback_extra_accounts() {
+        for account in accounts.iter() {
+            let key: [u8; 32] = account.pubkey().into();
+            let _ = (key, account.writable());
+        }
+    }
+    if let Some(provers) = er.authorized_provers() {
+        for prover in provers.iter() {
+            let key: [u8; 32] = prover.bytes().into();
+            let _ = key;
+        }
+    }
+}
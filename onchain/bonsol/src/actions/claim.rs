@@ -1,6 +1,7 @@
 use bonsol_interface::{
     bonsol_schema::{root_as_execution_request_v1, ChannelInstruction, ClaimV1},
     claim_state::ClaimStateV1,
+    prover_set::ProverSetV1,
     util::{execution_address_seeds, execution_claim_address_seeds},
 };
 
@@ -17,6 +18,7 @@ pub struct ClaimAccounts<'a, 'b> {
     pub claimer: &'a AccountInfo<'a>,
     pub payer: &'a AccountInfo<'a>,
     pub system_program: &'a AccountInfo<'a>,
+    pub prover_set: Option<&'a AccountInfo<'a>>,
     pub execution_id: &'b str,
     pub block_commitment: u64,
     pub existing_claim: bool,
@@ -25,11 +27,14 @@ pub struct ClaimAccounts<'a, 'b> {
 }
 
 impl<'a, 'b> ClaimAccounts<'a, 'b> {
-    fn from_instruction(
+    pub(crate) fn from_instruction(
         accounts: &'a [AccountInfo<'a>],
         data: &'b ClaimV1<'b>,
         current_block: u64,
     ) -> Result<Self, ChannelError> {
+        if accounts.len() < 6 {
+            return Err(ChannelError::InvalidInstruction);
+        }
         if let Some(executionid) = data.execution_id() {
             let mut ca = ClaimAccounts {
                 exec: &accounts[0],
@@ -38,6 +43,7 @@ impl<'a, 'b> ClaimAccounts<'a, 'b> {
                 claimer: &accounts[3],
                 payer: &accounts[4],
                 system_program: &accounts[5],
+                prover_set: accounts.get(6),
                 execution_id: executionid,
                 block_commitment: data.block_commitment(),
                 existing_claim: false,
@@ -72,11 +78,39 @@ impl<'a, 'b> ClaimAccounts<'a, 'b> {
                 return Err(ChannelError::InvalidExecutionId);
             }
 
-            if let Some(authorized_provers) = execution_request.authorized_provers() {
-                if !authorized_provers
-                    .iter()
-                    .any(|key| key.bytes().iter().collect::<Vec<_>>() == ca.claimer.key.as_array())
-                {
+            let inline_provers = execution_request.authorized_provers();
+            let prover_set = execution_request.prover_set();
+            if inline_provers.is_some() || prover_set.is_some() {
+                let claimer = ca.claimer.key.as_ref();
+                let inline_authorized = inline_provers.is_some_and(|provers| {
+                    provers
+                        .iter()
+                        .any(|key| key.bytes().iter().eq(claimer.iter().copied()))
+                });
+                let set_authorized = match prover_set {
+                    Some(set_address) if !inline_authorized => {
+                        let set_account =
+                            ca.prover_set.ok_or(ChannelError::InvalidProverSetAccount)?;
+                        check_bytes_match(
+                            set_account.key.as_ref(),
+                            set_address.bytes(),
+                            ChannelError::InvalidProverSetAccount,
+                        )?;
+                        check_owner(
+                            set_account,
+                            &crate::ID,
+                            ChannelError::InvalidProverSetAccount,
+                        )?;
+                        let set_data = set_account
+                            .try_borrow_data()
+                            .map_err(|_| ChannelError::CannotBorrowData)?;
+                        ProverSetV1::load(&set_data)
+                            .map_err(|_| ChannelError::InvalidProverSetAccount)?
+                            .contains(claimer)
+                    }
+                    _ => false,
+                };
+                if !inline_authorized && !set_authorized {
                     return Err(ChannelError::UnauthorizedProver);
                 }
             }
@@ -121,7 +155,7 @@ pub fn process_claim_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     ix: ChannelInstruction<'a>,
 ) -> Result<(), ProgramError> {
-    let cl = ix.claim_v1_nested_flatbuffer();
+    let cl = ix.claim_v1_verified();
     if cl.is_none() {
         return Err(ChannelError::InvalidInstruction.into());
     }
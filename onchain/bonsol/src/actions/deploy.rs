@@ -20,10 +20,13 @@ pub struct DeployAccounts<'a, 'b> {
 }
 
 impl<'a, 'b> DeployAccounts<'a, 'b> {
-    fn from_instruction(
+    pub(crate) fn from_instruction(
         accounts: &'a [AccountInfo<'a>],
         data: &'b DeployV1<'b>,
     ) -> Result<Self, ChannelError> {
+        if accounts.len() < 4 {
+            return Err(ChannelError::InvalidInstruction);
+        }
         if let Some(imageid) = data.image_id() {
             let mut da = DeployAccounts {
                 deployer: &accounts[0],
@@ -76,7 +79,7 @@ pub fn process_deploy_v1<'a>(
     ix: ChannelInstruction<'a>,
 ) -> Result<(), ChannelError> {
     msg!("deploy");
-    let dp = ix.deploy_v1_nested_flatbuffer();
+    let dp = ix.deploy_v1_verified();
     if dp.is_none() {
         return Err(ChannelError::InvalidInstruction);
     }
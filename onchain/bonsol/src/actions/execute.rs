@@ -1,10 +1,14 @@
-use crate::{assertions::*, error::ChannelError, utilities::*};
+use crate::{assertions::*, error::ChannelError, utilities::*, verifier::Verifier};
 
 use bonsol_interface::{
-    bonsol_schema::{root_as_deploy_v1, ChannelInstruction, ExecutionRequestV1, InputType},
-    util::execution_address_seeds,
+    bonsol_schema::{
+        root_as_deploy_v1, Account, ChannelInstruction, ExecutionRequestV1, ExecutionRequestV1Args,
+        Input, InputType,
+    },
+    util::{execution_address_seeds, execution_request_digest, input_digest},
 };
 
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
 use solana_program::{account_info::AccountInfo, bpf_loader_upgradeable, system_program};
 
 pub struct ExecuteAccounts<'a, 'b> {
@@ -20,10 +24,13 @@ pub struct ExecuteAccounts<'a, 'b> {
 }
 
 impl<'a, 'b> ExecuteAccounts<'a, 'b> {
-    fn from_instruction(
+    pub(crate) fn from_instruction(
         accounts: &'a [AccountInfo<'a>],
         data: &'b ExecutionRequestV1<'b>,
     ) -> Result<Self, ChannelError> {
+        if accounts.len() < 6 {
+            return Err(ChannelError::InvalidInstruction);
+        }
         if let Some(executionid) = data.execution_id() {
             let evec = executionid;
             let mut ea = ExecuteAccounts {
@@ -81,8 +88,20 @@ impl<'a, 'b> ExecuteAccounts<'a, 'b> {
                 return Err(ChannelError::MaxBlockHeightRequired);
             }
 
-            if data.verify_input_hash() && data.input_digest().is_none() {
-                return Err(ChannelError::InputDigestRequired);
+            if data.verify_input_hash() {
+                let input_digest = data
+                    .input_digest()
+                    .ok_or(ChannelError::InputDigestRequired)?;
+                check_inline_input_digest(&inputs, input_digest.bytes())?;
+            }
+
+            // only the program may commit to a request digest, when compacting
+            if data.request_digest().is_some() {
+                return Err(ChannelError::InvalidInstruction);
+            }
+
+            if !Verifier::is_supported(data.proof_system()) {
+                return Err(ChannelError::UnexpectedProofSystem);
             }
 
             or(
@@ -112,11 +131,34 @@ impl<'a, 'b> ExecuteAccounts<'a, 'b> {
     }
 }
 
+/// Requests made only of inline public data carry every byte the guest will read, so the
+/// committed input digest is checked up front instead of failing once a proof is submitted.
+fn check_inline_input_digest(
+    inputs: &Vector<ForwardsUOffset<Input>>,
+    expected: &[u8],
+) -> Result<(), ChannelError> {
+    if inputs
+        .iter()
+        .any(|i| i.input_type() != InputType::PublicData)
+    {
+        return Ok(());
+    }
+    let data: Vec<&[u8]> = inputs
+        .iter()
+        .map(|i| i.data().map(|d| d.bytes()).unwrap_or_default())
+        .collect();
+    check_bytes_match(
+        &input_digest(&data),
+        expected,
+        ChannelError::InputsDontMatch,
+    )
+}
+
 pub fn process_execute_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     ix: ChannelInstruction<'a>,
 ) -> Result<(), ChannelError> {
-    let er = ix.execute_v1_nested_flatbuffer();
+    let er = ix.execute_v1_verified();
     if er.is_none() {
         return Err(ChannelError::InvalidInstruction);
     }
@@ -126,5 +168,178 @@ pub fn process_execute_v1<'a>(
     let mut seeds = execution_address_seeds(ea.requester.key, ea.execution_id.as_bytes());
     seeds.push(&b);
     let bytes = ix.execute_v1().unwrap().bytes();
-    save_structure(ea.exec, &seeds, bytes, ea.payer, ea.system_program, None)
+    if er.compact_storage() {
+        let compact = compact_execution_request(&er, bytes);
+        save_structure(ea.exec, &seeds, &compact, ea.payer, ea.system_program, None)
+    } else {
+        save_structure(ea.exec, &seeds, bytes, ea.payer, ea.system_program, None)
+    }
+}
+
+/// Rebuilds the request without its inputs. Everything claim and status read is kept, the inputs
+/// are only needed by provers which read them from the execute transaction, and the node checks
+/// that transaction against `request_digest` before claiming.
+fn compact_execution_request(er: &ExecutionRequestV1, full_request: &[u8]) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let callback_extra_accounts = er.callback_extra_accounts().map(|accounts| {
+        fbb.start_vector::<WIPOffset<Account>>(accounts.len());
+        for account in accounts.iter().rev() {
+            fbb.push(*account);
+        }
+        fbb.end_vector(accounts.len())
+    });
+    let authorized_provers = er
+        .authorized_provers()
+        .map(|provers| fbb.create_vector_from_iter(provers.iter().copied()));
+    let execution_id = er.execution_id().map(|id| fbb.create_string(id));
+    let image_id = er.image_id().map(|id| fbb.create_string(id));
+    let callback_program_id = er
+        .callback_program_id()
+        .map(|id| fbb.create_vector(id.bytes()));
+    let callback_instruction_prefix = er
+        .callback_instruction_prefix()
+        .map(|prefix| fbb.create_vector(prefix.bytes()));
+    let input_digest = er.input_digest().map(|d| fbb.create_vector(d.bytes()));
+    let prover_set = er.prover_set().map(|ps| fbb.create_vector(ps.bytes()));
+    let request_digest = fbb.create_vector(&execution_request_digest(full_request));
+    let compact = ExecutionRequestV1::create(
+        &mut fbb,
+        &ExecutionRequestV1Args {
+            tip: er.tip(),
+            execution_id,
+            image_id,
+            callback_program_id,
+            callback_instruction_prefix,
+            forward_output: er.forward_output(),
+            verify_input_hash: er.verify_input_hash(),
+            input: None,
+            input_digest,
+            max_block_height: er.max_block_height(),
+            callback_extra_accounts,
+            prover_version: er.prover_version(),
+            authorized_provers,
+            proof_system: er.proof_system(),
+            prover_set,
+            compact_storage: true,
+            request_digest: Some(request_digest),
+        },
+    );
+    fbb.finish(compact, None);
+    fbb.finished_data().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bonsol_interface::{
+        bonsol_schema::{parse_ix_data, root_as_execution_request_v1},
+        instructions::{execute_v1, CallbackConfig, ExecutionConfig, InputRef},
+    };
+    use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+    #[test]
+    fn test_compact_execution_request() {
+        let requester = Pubkey::new_unique();
+        let extra = Pubkey::new_unique();
+        let prover = Pubkey::new_unique();
+        let input = [7u8; 512];
+        let ix = execute_v1(
+            &requester,
+            &requester,
+            "image",
+            "execution",
+            vec![InputRef::public(&input)],
+            100,
+            200,
+            ExecutionConfig {
+                input_hash: Some(&[1u8; 32]),
+                compact_storage: true,
+                ..Default::default()
+            },
+            Some(CallbackConfig {
+                program_id: Pubkey::new_unique(),
+                instruction_prefix: vec![1, 2],
+                extra_accounts: vec![AccountMeta::new(extra, false)],
+            }),
+            None,
+            vec![prover],
+        )
+        .unwrap();
+        let channel_ix = parse_ix_data(&ix.data).unwrap();
+        let full = channel_ix.execute_v1().unwrap().bytes();
+        let er = channel_ix.execute_v1_verified().unwrap();
+
+        let compact = compact_execution_request(&er, full);
+        assert!(compact.len() < full.len());
+        let stored = root_as_execution_request_v1(&compact).unwrap();
+        assert!(stored.input().is_none());
+        assert_eq!(
+            stored.request_digest().unwrap().bytes(),
+            execution_request_digest(full)
+        );
+        assert_eq!(stored.execution_id(), er.execution_id());
+        assert_eq!(stored.image_id(), er.image_id());
+        assert_eq!(stored.tip(), 100);
+        assert_eq!(stored.max_block_height(), 200);
+        assert_eq!(stored.input_digest().unwrap().bytes(), &[1u8; 32]);
+        assert_eq!(
+            stored.callback_instruction_prefix().unwrap().bytes(),
+            &[1, 2]
+        );
+        let extra_accounts = stored.callback_extra_accounts().unwrap();
+        assert_eq!(extra_accounts.len(), 1);
+        let stored_extra: [u8; 32] = extra_accounts.get(0).pubkey().into();
+        assert_eq!(stored_extra, extra.to_bytes());
+        let provers = stored.authorized_provers().unwrap();
+        let stored_prover: [u8; 32] = provers.get(0).bytes().into();
+        assert_eq!(stored_prover, prover.to_bytes());
+        assert_eq!(stored.prover_version(), er.prover_version());
+        assert_eq!(stored.proof_system(), er.proof_system());
+        assert!(stored.compact_storage());
+    }
+
+    fn request_inputs(inputs: Vec<InputRef>) -> Vec<u8> {
+        let requester = Pubkey::new_unique();
+        let ix = execute_v1(
+            &requester,
+            &requester,
+            "image",
+            "execution",
+            inputs,
+            100,
+            200,
+            ExecutionConfig {
+                input_hash: Some(&[1u8; 32]),
+                ..Default::default()
+            },
+            None,
+            None,
+            vec![],
+        )
+        .unwrap();
+        ix.data
+    }
+
+    #[test]
+    fn test_inline_input_digest() {
+        let data = request_inputs(vec![InputRef::public(b"a"), InputRef::public(b"bc")]);
+        let er = parse_ix_data(&data).unwrap().execute_v1_verified().unwrap();
+        let inputs = er.input().unwrap();
+        assert!(check_inline_input_digest(&inputs, &input_digest(&[b"abc".as_slice()])).is_ok());
+        assert_eq!(
+            check_inline_input_digest(&inputs, &[1u8; 32]),
+            Err(ChannelError::InputsDontMatch)
+        );
+    }
+
+    #[test]
+    fn test_input_digest_not_checked_with_resolved_inputs() {
+        let data = request_inputs(vec![
+            InputRef::public(b"a"),
+            InputRef::private(b"https://example.com/private"),
+        ]);
+        let er = parse_ix_data(&data).unwrap().execute_v1_verified().unwrap();
+        let inputs = er.input().unwrap();
+        assert!(check_inline_input_digest(&inputs, &[1u8; 32]).is_ok());
+    }
 }
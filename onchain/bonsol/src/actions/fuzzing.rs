@@ -0,0 +1,37 @@
+//! Entry points for the fuzz targets into the account parsing of the instructions, the accounts
+//! constructors are not part of the program api.
+
+use bonsol_interface::bonsol_schema::{ClaimV1, DeployV1, ExecutionRequestV1, StatusV1};
+use solana_program::account_info::AccountInfo;
+
+use super::{ClaimAccounts, DeployAccounts, ExecuteAccounts, StatusAccounts};
+use crate::error::ChannelError;
+
+pub fn execute_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'a>],
+    data: &'b ExecutionRequestV1<'b>,
+) -> Result<(), ChannelError> {
+    ExecuteAccounts::from_instruction(accounts, data).map(|_| ())
+}
+
+pub fn claim_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'a>],
+    data: &'b ClaimV1<'b>,
+    current_block: u64,
+) -> Result<(), ChannelError> {
+    ClaimAccounts::from_instruction(accounts, data, current_block).map(|_| ())
+}
+
+pub fn status_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'a>],
+    data: &'b StatusV1<'b>,
+) -> Result<(), ChannelError> {
+    StatusAccounts::from_instruction(accounts, data).map(|_| ())
+}
+
+pub fn deploy_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'a>],
+    data: &'b DeployV1<'b>,
+) -> Result<(), ChannelError> {
+    DeployAccounts::from_instruction(accounts, data).map(|_| ())
+}
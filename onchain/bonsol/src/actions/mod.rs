@@ -1,9 +1,13 @@
 mod claim;
 mod deploy;
 mod execute;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+mod prover_set;
 mod status;
 
 pub use claim::*;
 pub use deploy::*;
 pub use execute::*;
+pub use prover_set::*;
 pub use status::*;
@@ -0,0 +1,143 @@
+use crate::{assertions::*, error::ChannelError, utilities::*};
+
+use bonsol_interface::{
+    bonsol_schema::{ChannelInstruction, ProverSetOp, ProverSetUpdateV1},
+    prover_set::{ProverSetV1, MAX_PROVER_SET_ID_LEN, MAX_PROVER_SET_LEN},
+    util::prover_set_address_seeds,
+};
+
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, program_memory::sol_memcpy, rent::Rent,
+    system_program, sysvar::Sysvar,
+};
+
+pub struct ProverSetAccounts<'a, 'b> {
+    pub owner: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub prover_set: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+    pub set_id: &'b str,
+    pub prover_set_bump: Option<u8>,
+}
+
+impl<'a, 'b> ProverSetAccounts<'a, 'b> {
+    fn from_instruction(
+        accounts: &'a [AccountInfo<'a>],
+        data: &'b ProverSetUpdateV1<'b>,
+    ) -> Result<Self, ChannelError> {
+        if accounts.len() < 4 {
+            return Err(ChannelError::InvalidInstruction);
+        }
+        let set_id = data.set_id().ok_or(ChannelError::InvalidInstruction)?;
+        if set_id.is_empty() || set_id.len() > MAX_PROVER_SET_ID_LEN {
+            return Err(ChannelError::InvalidInstruction);
+        }
+        let mut pa = ProverSetAccounts {
+            owner: &accounts[0],
+            payer: &accounts[1],
+            prover_set: &accounts[2],
+            system_program: &accounts[3],
+            set_id,
+            prover_set_bump: None,
+        };
+        check_writable_signer(pa.owner, ChannelError::InvalidRequesterAccount)?;
+        check_writable_signer(pa.payer, ChannelError::InvalidPayerAccount)?;
+        check_writeable(pa.prover_set, ChannelError::InvalidProverSetAccount)?;
+        check_key_match(
+            pa.system_program,
+            &system_program::ID,
+            ChannelError::InvalidInstruction,
+        )?;
+        pa.prover_set_bump = Some(check_pda(
+            &prover_set_address_seeds(pa.owner.key, set_id.as_bytes()),
+            pa.prover_set.key,
+            ChannelError::InvalidProverSetAccount,
+        )?);
+        Ok(pa)
+    }
+}
+
+pub fn process_prover_set_update_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    ix: ChannelInstruction<'a>,
+) -> Result<(), ProgramError> {
+    let up = ix
+        .prover_set_update_v1_verified()
+        .ok_or(ChannelError::InvalidInstruction)?;
+    let pa = ProverSetAccounts::from_instruction(accounts, &up)?;
+    let changes = up.provers().ok_or(ChannelError::InvalidInstruction)?;
+
+    let exists = pa.prover_set.owner != &system_program::ID;
+    let mut provers: Vec<[u8; 32]> = if exists {
+        check_owner(
+            pa.prover_set,
+            &crate::ID,
+            ChannelError::InvalidProverSetAccount,
+        )?;
+        let data = pa
+            .prover_set
+            .try_borrow_data()
+            .map_err(|_| ChannelError::CannotBorrowData)?;
+        let set = ProverSetV1::load(&data).map_err(|_| ChannelError::InvalidProverSetAccount)?;
+        check_bytes_match(
+            set.owner,
+            pa.owner.key.as_ref(),
+            ChannelError::InvalidProverSetOwner,
+        )?;
+        set.provers.to_vec()
+    } else {
+        ensure_0(pa.prover_set, ChannelError::InvalidProverSetAccount)?;
+        Vec::new()
+    };
+
+    match up.op() {
+        ProverSetOp::Add => {
+            for key in changes.iter() {
+                let key: [u8; 32] = key.bytes().into();
+                if !provers.contains(&key) {
+                    provers.push(key);
+                }
+            }
+            if provers.len() > MAX_PROVER_SET_LEN {
+                return Err(ChannelError::ProverSetFull.into());
+            }
+        }
+        ProverSetOp::Remove => {
+            if !exists {
+                return Err(ChannelError::InvalidProverSetAccount.into());
+            }
+            let removed: Vec<[u8; 32]> = changes.iter().map(|key| key.bytes().into()).collect();
+            provers.retain(|p| !removed.contains(p));
+        }
+        _ => return Err(ChannelError::InvalidInstruction.into()),
+    }
+
+    let bytes = ProverSetV1::to_bytes(&pa.owner.key.to_bytes(), &provers);
+    if !exists {
+        let b = [pa.prover_set_bump.unwrap()];
+        let mut seeds = prover_set_address_seeds(pa.owner.key, pa.set_id.as_bytes());
+        seeds.push(&b);
+        save_structure(
+            pa.prover_set,
+            &seeds,
+            &bytes,
+            pa.payer,
+            pa.system_program,
+            None,
+        )?;
+        return Ok(());
+    }
+
+    // keep the set exactly rent exempt as it grows and shrinks, excess goes back to the owner
+    let required = Rent::get()?.minimum_balance(bytes.len());
+    let current = pa.prover_set.lamports();
+    if required > current {
+        transfer_unowned(pa.payer, pa.prover_set, required - current)?;
+    }
+    pa.prover_set.realloc(bytes.len(), false)?;
+    sol_memcpy(&mut pa.prover_set.data.borrow_mut(), &bytes, bytes.len());
+    if current > required {
+        transfer_owned(pa.prover_set, pa.owner, current - required)?;
+    }
+    Ok(())
+}
@@ -1,25 +1,15 @@
 use crate::{
     assertions::*,
     error::ChannelError,
-    proof_handling::{
-        output_digest_v1_0_1, output_digest_v1_2_1, output_digest_v3_0_3, prepare_inputs_v1_0_1,
-        prepare_inputs_v1_2_1, prepare_inputs_v3_0_3, verify_risc0_v1_0_1, verify_risc0_v1_2_1,
-        verify_risc0_v3_0_3,
-    },
+    settlement::{check_status, Verdict},
     utilities::*,
 };
 
 use bonsol_interface::{
-    bonsol_schema::{
-        root_as_execution_request_v1, ChannelInstruction, ExecutionRequestV1, ExitCode, StatusV1,
-    },
-    prover_version::{
-        ProverVersion, VERSION_V1_0_1, VERSION_V1_2_1, VERSION_V2_3_1, VERSION_V3_0_3,
-    },
+    bonsol_schema::{root_as_execution_request_v1, ChannelInstruction, ExitCode, StatusV1},
     util::execution_address_seeds,
 };
 
-use crate::proof_handling::{output_digest_v2_3_1, prepare_inputs_v2_3_1, verify_risc0_v2_3_1};
 use solana_program::{
     account_info::AccountInfo,
     clock::Clock,
@@ -31,7 +21,7 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
-struct StatusAccounts<'a, 'b> {
+pub(crate) struct StatusAccounts<'a, 'b> {
     pub requester: &'a AccountInfo<'a>,
     pub exec: &'a AccountInfo<'a>,
     pub prover: &'a AccountInfo<'a>,
@@ -42,10 +32,13 @@ struct StatusAccounts<'a, 'b> {
 }
 
 impl<'a, 'b> StatusAccounts<'a, 'b> {
-    fn from_instruction(
+    pub(crate) fn from_instruction(
         accounts: &'a [AccountInfo<'a>],
         data: &'b StatusV1<'b>,
     ) -> Result<Self, ChannelError> {
+        if accounts.len() < 4 {
+            return Err(ChannelError::InvalidInstruction);
+        }
         let ea = &accounts[1];
         let prover = &accounts[3];
         let callback_program = &accounts[2];
@@ -74,7 +67,7 @@ pub fn process_status_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     ix: ChannelInstruction,
 ) -> Result<(), ProgramError> {
-    let st = ix.status_v1_nested_flatbuffer();
+    let st = ix.status_v1_verified();
     if st.is_none() {
         return Err(ChannelError::InvalidInstruction.into());
     }
@@ -83,33 +76,18 @@ pub fn process_status_v1<'a>(
     let er_ref = sa.exec.try_borrow_data()?;
     let er =
         root_as_execution_request_v1(&er_ref).map_err(|_| ChannelError::InvalidExecutionAccount)?;
-    let pr_v = st.proof().filter(|x| x.len() == 256);
     if er.max_block_height() < Clock::get()?.slot {
         return Err(ChannelError::ExecutionExpired.into());
     }
-    let execution_digest_v = st.execution_digest().map(|x| x.bytes());
-    let input_digest_v = st.input_digest().map(|x| x.bytes());
-    let assumption_digest_v = st.assumption_digest().map(|x| x.bytes());
-    let committed_outputs_v = st.committed_outputs().map(|x| x.bytes());
-    if let (Some(proof), Some(exed), Some(asud), Some(input_digest), Some(co)) = (
-        pr_v,
-        execution_digest_v,
-        assumption_digest_v,
-        input_digest_v,
-        committed_outputs_v,
-    ) {
-        let proof: &[u8; 256] = proof
-            .bytes()
-            .try_into()
-            .map_err(|_| ChannelError::InvalidInstruction)?;
-        if er.verify_input_hash() {
-            er.input_digest()
-                .map(|x| check_bytes_match(x.bytes(), input_digest, ChannelError::InputsDontMatch));
-        }
-        let verified = verify_with_prover(input_digest, co, asud, er, exed, st, proof)?;
-        let tip = er.tip();
-
-        if verified {
+    let verdict = check_status(&er, &st)?;
+    if let Verdict::Verified { prover_version } | Verdict::VerifyFailed { prover_version } =
+        &verdict
+    {
+        msg!("Proof verified with {}", prover_version);
+    }
+    match verdict {
+        Verdict::Verified { .. } => {
+            let tip = er.tip();
             let callback_program_set =
                 sol_memcmp(sa.callback_program.key.as_ref(), crate::ID.as_ref(), 32) != 0;
             let ix_prefix_set = er.callback_instruction_prefix().is_some();
@@ -160,7 +138,7 @@ pub fn process_status_v1<'a>(
                 let payload = if er.forward_output() && st.committed_outputs().is_some() {
                     [
                         er.callback_instruction_prefix().unwrap().bytes(),
-                        input_digest,
+                        st.input_digest().unwrap().bytes(),
                         st.committed_outputs().unwrap().bytes(),
                     ]
                     .concat()
@@ -182,77 +160,17 @@ pub fn process_status_v1<'a>(
             }
             payout_tip(sa.exec, sa.prover, tip)?;
             cleanup_execution_account(sa.exec, sa.requester, ExitCode::Success as u8)?;
-        } else {
+        }
+        Verdict::VerifyFailed { .. } => {
             drop(er_ref);
             msg!("{} Verifying Failed Cleaning up", sa.eid);
             cleanup_execution_account(sa.exec, sa.requester, ExitCode::VerifyError as u8)?;
         }
-    } else {
-        drop(er_ref);
-        msg!("{} Proving Failed Cleaning up", sa.eid);
-        cleanup_execution_account(sa.exec, sa.requester, ExitCode::ProvingError as u8)?;
+        Verdict::ProvingFailed => {
+            drop(er_ref);
+            msg!("{} Proving Failed Cleaning up", sa.eid);
+            cleanup_execution_account(sa.exec, sa.requester, ExitCode::ProvingError as u8)?;
+        }
     }
     Ok(())
 }
-
-fn verify_with_prover(
-    input_digest: &[u8],
-    co: &[u8],
-    asud: &[u8],
-    er: ExecutionRequestV1,
-    exed: &[u8],
-    st: StatusV1,
-    proof: &[u8; 256],
-) -> Result<bool, ProgramError> {
-    let prover_version =
-        ProverVersion::try_from(er.prover_version()).unwrap_or(ProverVersion::default());
-    let verified = match prover_version {
-        VERSION_V1_0_1 => {
-            let output_digest = output_digest_v1_0_1(input_digest, co, asud);
-            let proof_inputs = prepare_inputs_v1_0_1(
-                er.image_id().unwrap(),
-                exed,
-                output_digest.as_ref(),
-                st.exit_code_system(),
-                st.exit_code_user(),
-            )?;
-            verify_risc0_v1_0_1(proof, &proof_inputs)?
-        }
-        VERSION_V1_2_1 => {
-            let output_digest = output_digest_v1_2_1(input_digest, co, asud);
-            let proof_inputs = prepare_inputs_v1_2_1(
-                er.image_id().unwrap(),
-                exed,
-                output_digest.as_ref(),
-                st.exit_code_system(),
-                st.exit_code_user(),
-            )?;
-            verify_risc0_v1_2_1(proof, &proof_inputs)?
-        }
-        VERSION_V2_3_1 => {
-            let output_digest = output_digest_v2_3_1(input_digest, co, asud);
-            let proof_inputs = prepare_inputs_v2_3_1(
-                er.image_id().unwrap(),
-                exed,
-                output_digest.as_ref(),
-                st.exit_code_system(),
-                st.exit_code_user(),
-            )?;
-            verify_risc0_v2_3_1(proof, &proof_inputs)?
-        }
-        VERSION_V3_0_3 => {
-            let output_digest = output_digest_v3_0_3(input_digest, co, asud);
-            let proof_inputs = prepare_inputs_v3_0_3(
-                er.image_id().unwrap(),
-                exed,
-                output_digest.as_ref(),
-                st.exit_code_system(),
-                st.exit_code_user(),
-            )?;
-            verify_risc0_v3_0_3(proof, &proof_inputs)?
-        }
-        _ => false,
-    };
-    msg!("Proof verified with {}", prover_version);
-    Ok(verified)
-}
@@ -81,6 +81,12 @@ pub enum ChannelError {
     UnexpectedProofSystem,
     #[error("Unauthorized prover")]
     UnauthorizedProver,
+    #[error("Invalid Prover Set Account")]
+    InvalidProverSetAccount,
+    #[error("Invalid Prover Set Owner")]
+    InvalidProverSetOwner,
+    #[error("Prover Set Full")]
+    ProverSetFull,
 }
 
 impl From<ChannelError> for ProgramError {
@@ -6,7 +6,9 @@ pub mod error;
 pub mod program;
 pub mod proof_handling;
 pub mod prover;
+pub mod settlement;
 pub mod utilities;
+pub mod verifier;
 
 mod assertions;
 mod verifying_key;
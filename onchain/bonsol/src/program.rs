@@ -22,6 +22,9 @@ pub fn program<'a>(
         ChannelInstructionIxType::StatusV1 => {
             process_status_v1(accounts, ix)?;
         }
+        ChannelInstructionIxType::ProverSetUpdateV1 => {
+            process_prover_set_update_v1(accounts, ix)?;
+        }
         _ => return Err(ChannelError::InvalidInstruction.into()),
     };
     Ok(())
@@ -4,40 +4,60 @@ use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate
 use groth16_solana::groth16::{Groth16Verifier, Groth16Verifyingkey};
 use solana_program::hash::hashv;
 
-use crate::{
-    error::ChannelError,
-    prover::{
-        PROVER_CONSTANTS_V1_0_1, PROVER_CONSTANTS_V1_2_1, PROVER_CONSTANTS_V2_3_1,
-        PROVER_CONSTANTS_V3_0_3,
-    },
-    verifying_key::VERIFYINGKEY,
-};
+use crate::{error::ChannelError, prover::ProverConstants, verifying_key::VERIFYINGKEY};
 
 type G1 = ark_bn254::g1::G1Affine;
 
-pub fn verify_risc0_v1_0_1(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelError> {
-    let ins: [[u8; 32]; 5] = [
-        sized_range::<32>(&inputs[0..32])?,
-        sized_range::<32>(&inputs[32..64])?,
-        sized_range::<32>(&inputs[64..96])?,
-        sized_range::<32>(&inputs[96..128])?,
-        sized_range::<32>(&inputs[128..160])?,
-    ];
-    verify_proof::<5>(proof, ins, &VERIFYINGKEY)
+// uncompressed a, b and c points of a bn254 Groth16 proof
+const GROTH16_PROOF_LEN: usize = 256;
+
+/// A risc0 Groth16 seal is the bare proof points.
+pub const RISC0_SEAL_LEN: usize = GROTH16_PROOF_LEN;
+
+/// An SP1 Groth16 seal is a 4 byte verifying key selector followed by the proof points.
+pub const SP1_SEAL_LEN: usize = 4 + GROTH16_PROOF_LEN;
+
+/// What a status submission claims about an execution, borrowed from the execution request and
+/// the `StatusV1` payload. Each proof system commits to the subset of it that its circuit exposes.
+pub struct ExecutionClaim<'a> {
+    pub image_id: &'a str,
+    pub execution_digest: &'a [u8],
+    pub input_digest: &'a [u8],
+    pub committed_outputs: &'a [u8],
+    pub assumption_digest: &'a [u8],
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
 }
 
-pub fn verify_risc0_v1_2_1(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelError> {
-    let ins: [[u8; 32]; 5] = [
-        sized_range::<32>(&inputs[0..32])?,
-        sized_range::<32>(&inputs[32..64])?,
-        sized_range::<32>(&inputs[64..96])?,
-        sized_range::<32>(&inputs[96..128])?,
-        sized_range::<32>(&inputs[128..160])?,
-    ];
-    verify_proof::<5>(proof, ins, &VERIFYINGKEY)
+/// Verifies a 256 byte Groth16 seal against the claim using the constants of a prover version.
+pub fn verify_risc0(
+    constants: &ProverConstants,
+    proof: &[u8],
+    claim: &ExecutionClaim,
+) -> Result<bool, ChannelError> {
+    let output_digest = output_digest(
+        constants,
+        claim.input_digest,
+        claim.committed_outputs,
+        claim.assumption_digest,
+    );
+    let proof_inputs = prepare_inputs(
+        constants,
+        claim.image_id,
+        claim.execution_digest,
+        output_digest.as_ref(),
+        claim.exit_code_system,
+        claim.exit_code_user,
+    )?;
+    verify_groth16(proof, &proof_inputs)
 }
 
-pub fn verify_risc0_v2_3_1(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelError> {
+/// Every supported risc0 version is wrapped by the same Groth16 circuit, so the verifying key is
+/// shared and only the public inputs differ between versions.
+pub fn verify_groth16(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelError> {
+    if inputs.len() != 160 {
+        return Err(ChannelError::InvalidPublicInputs);
+    }
     let ins: [[u8; 32]; 5] = [
         sized_range::<32>(&inputs[0..32])?,
         sized_range::<32>(&inputs[32..64])?,
@@ -48,15 +68,39 @@ pub fn verify_risc0_v2_3_1(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelE
     verify_proof::<5>(proof, ins, &VERIFYINGKEY)
 }
 
-pub fn verify_risc0_v3_0_3(proof: &[u8], inputs: &[u8]) -> Result<bool, ChannelError> {
-    let ins: [[u8; 32]; 5] = [
-        sized_range::<32>(&inputs[0..32])?,
-        sized_range::<32>(&inputs[32..64])?,
-        sized_range::<32>(&inputs[64..96])?,
-        sized_range::<32>(&inputs[96..128])?,
-        sized_range::<32>(&inputs[128..160])?,
-    ];
-    verify_proof::<5>(proof, ins, &VERIFYINGKEY)
+/// Verifies an SP1 Groth16 seal. The image id of an SP1 program is its verifying key hash and the
+/// public values are the same journal risc0 programs commit, the input digest then the outputs.
+pub fn verify_sp1(
+    vkey: &Groth16Verifyingkey,
+    vkey_selector: &[u8; 4],
+    proof: &[u8],
+    claim: &ExecutionClaim,
+) -> Result<bool, ChannelError> {
+    if proof.len() != SP1_SEAL_LEN {
+        return Err(ChannelError::InvalidProof);
+    }
+    if proof[..4] != vkey_selector[..] {
+        return Err(ChannelError::UnexpectedProofSystem);
+    }
+    let inputs = prepare_inputs_sp1(
+        claim.image_id,
+        &[claim.input_digest, claim.committed_outputs],
+    )?;
+    verify_proof::<2>(&proof[4..], inputs, vkey)
+}
+
+/// The SP1 Groth16 circuit exposes the program verifying key hash and the sha256 of the public
+/// values, truncated to 253 bits so it fits in a bn254 scalar.
+pub fn prepare_inputs_sp1(
+    program_vkey: &str,
+    public_values: &[&[u8]],
+) -> Result<[[u8; 32]; 2], ChannelError> {
+    let vkey = hex::decode(program_vkey.trim_start_matches("0x"))
+        .map_err(|_| ChannelError::InvalidFieldElement)?;
+    let vkey = sized_range::<32>(&vkey).map_err(|_| ChannelError::InvalidFieldElement)?;
+    let mut values_digest = hashv(public_values).to_bytes();
+    values_digest[0] &= 0x1f;
+    Ok([vkey, values_digest])
 }
 
 fn verify_proof<const NI: usize>(
@@ -64,8 +108,12 @@ fn verify_proof<const NI: usize>(
     inputs: [[u8; 32]; NI],
     vkey: &Groth16Verifyingkey,
 ) -> Result<bool, ChannelError> {
+    if proof.len() != GROTH16_PROOF_LEN {
+        return Err(ChannelError::InvalidProof);
+    }
     let ace: Vec<u8> = toggle_endianness_256(&[&proof[0..64], &[0u8][..]].concat());
-    let proof_a: G1 = G1::deserialize_with_mode(&*ace, Compress::No, Validate::No).unwrap();
+    let proof_a: G1 = G1::deserialize_with_mode(&*ace, Compress::No, Validate::No)
+        .map_err(|_| ChannelError::InvalidProof)?;
 
     let mut proof_a_neg = [0u8; 65];
     G1::serialize_with_mode(&proof_a.neg(), &mut proof_a_neg[..], Compress::No)
@@ -91,157 +139,8 @@ fn verify_proof<const NI: usize>(
         .map_err(|_| ChannelError::ProofVerificationFailed)
 }
 
-pub fn output_digest_v1_0_1(
-    input_digest: &[u8],
-    committed_outputs: &[u8],
-    assumption_digest: &[u8],
-) -> [u8; 32] {
-    let jbytes = [input_digest, committed_outputs].concat(); // bad copy here
-    let journal = hashv(&[jbytes.as_slice()]);
-    hashv(&[
-        PROVER_CONSTANTS_V1_0_1.output_hash.as_ref(),
-        journal.as_ref(),
-        assumption_digest,
-        &2u16.to_le_bytes(),
-    ])
-    .to_bytes()
-}
-
-pub fn prepare_inputs_v1_0_1(
-    image_id: &str,
-    execution_digest: &[u8],
-    output_digest: &[u8],
-    system_exit_code: u32,
-    user_exit_code: u32,
-) -> Result<Vec<u8>, ChannelError> {
-    let imgbytes = hex::decode(image_id).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let mut digest = hashv(&[
-        PROVER_CONSTANTS_V1_0_1.receipt_claim_hash.as_ref(),
-        &[0u8; 32],
-        &imgbytes,
-        execution_digest,
-        output_digest,
-        &system_exit_code.to_le_bytes(),
-        &user_exit_code.to_le_bytes(),
-        &4u16.to_le_bytes(),
-    ])
-    .to_bytes();
-    let (c0, c1) = split_digest_reversed(&mut PROVER_CONSTANTS_V1_0_1.control_root.clone())
-        .map_err(|_| ChannelError::InvalidFieldElement)?;
-    let (half1_bytes, half2_bytes) =
-        split_digest_reversed(&mut digest).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let inputs = [
-        c0,
-        c1,
-        half1_bytes.try_into().unwrap(),
-        half2_bytes.try_into().unwrap(),
-        PROVER_CONSTANTS_V1_0_1.bn254_control_id_bytes,
-    ]
-    .concat();
-    Ok(inputs)
-}
-
-pub fn output_digest_v1_2_1(
-    input_digest: &[u8],
-    committed_outputs: &[u8],
-    assumption_digest: &[u8],
-) -> [u8; 32] {
-    let jbytes = [input_digest, committed_outputs].concat(); // bad copy here
-    let journal = hashv(&[jbytes.as_slice()]);
-    hashv(&[
-        PROVER_CONSTANTS_V1_2_1.output_hash.as_ref(),
-        journal.as_ref(),
-        assumption_digest,
-        &2u16.to_le_bytes(),
-    ])
-    .to_bytes()
-}
-
-pub fn prepare_inputs_v1_2_1(
-    image_id: &str,
-    execution_digest: &[u8],
-    output_digest: &[u8],
-    system_exit_code: u32,
-    user_exit_code: u32,
-) -> Result<Vec<u8>, ChannelError> {
-    let imgbytes = hex::decode(image_id).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let mut digest = hashv(&[
-        PROVER_CONSTANTS_V1_2_1.receipt_claim_hash.as_ref(),
-        &[0u8; 32],
-        &imgbytes,
-        execution_digest,
-        output_digest,
-        &system_exit_code.to_le_bytes(),
-        &user_exit_code.to_le_bytes(),
-        &4u16.to_le_bytes(),
-    ])
-    .to_bytes();
-    let (c0, c1) = split_digest_reversed(&mut PROVER_CONSTANTS_V1_2_1.control_root.clone())
-        .map_err(|_| ChannelError::InvalidFieldElement)?;
-    let (half1_bytes, half2_bytes) =
-        split_digest_reversed(&mut digest).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let inputs = [
-        c0,
-        c1,
-        half1_bytes.try_into().unwrap(),
-        half2_bytes.try_into().unwrap(),
-        PROVER_CONSTANTS_V1_2_1.bn254_control_id_bytes,
-    ]
-    .concat();
-    Ok(inputs)
-}
-
-pub fn output_digest_v2_3_1(
-    input_digest: &[u8],
-    committed_outputs: &[u8],
-    assumption_digest: &[u8],
-) -> [u8; 32] {
-    let jbytes = [input_digest, committed_outputs].concat(); // bad copy here
-    let journal = hashv(&[jbytes.as_slice()]);
-    hashv(&[
-        PROVER_CONSTANTS_V2_3_1.output_hash.as_ref(),
-        journal.as_ref(),
-        assumption_digest,
-        &2u16.to_le_bytes(),
-    ])
-    .to_bytes()
-}
-
-pub fn prepare_inputs_v2_3_1(
-    image_id: &str,
-    execution_digest: &[u8],
-    output_digest: &[u8],
-    system_exit_code: u32,
-    user_exit_code: u32,
-) -> Result<Vec<u8>, ChannelError> {
-    let imgbytes = hex::decode(image_id).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let mut digest = hashv(&[
-        PROVER_CONSTANTS_V2_3_1.receipt_claim_hash.as_ref(),
-        &[0u8; 32],
-        &imgbytes,
-        execution_digest,
-        output_digest,
-        &system_exit_code.to_le_bytes(),
-        &user_exit_code.to_le_bytes(),
-        &4u16.to_le_bytes(),
-    ])
-    .to_bytes();
-    let (c0, c1) = split_digest_reversed(&mut PROVER_CONSTANTS_V2_3_1.control_root.clone())
-        .map_err(|_| ChannelError::InvalidFieldElement)?;
-    let (half1_bytes, half2_bytes) =
-        split_digest_reversed(&mut digest).map_err(|_| ChannelError::InvalidFieldElement)?;
-    let inputs = [
-        c0,
-        c1,
-        half1_bytes.try_into().unwrap(),
-        half2_bytes.try_into().unwrap(),
-        PROVER_CONSTANTS_V2_3_1.bn254_control_id_bytes,
-    ]
-    .concat();
-    Ok(inputs)
-}
-
-pub fn output_digest_v3_0_3(
+pub fn output_digest(
+    constants: &ProverConstants,
     input_digest: &[u8],
     committed_outputs: &[u8],
     assumption_digest: &[u8],
@@ -249,7 +148,7 @@ pub fn output_digest_v3_0_3(
     // Avoid extra allocation by hashing multiple slices directly
     let journal = hashv(&[input_digest, committed_outputs]);
     hashv(&[
-        PROVER_CONSTANTS_V3_0_3.output_hash.as_ref(),
+        constants.output_hash.as_ref(),
         journal.as_ref(),
         assumption_digest,
         &2u16.to_le_bytes(),
@@ -257,7 +156,8 @@ pub fn output_digest_v3_0_3(
     .to_bytes()
 }
 
-pub fn prepare_inputs_v3_0_3(
+pub fn prepare_inputs(
+    constants: &ProverConstants,
     image_id: &str,
     execution_digest: &[u8],
     output_digest: &[u8],
@@ -266,7 +166,7 @@ pub fn prepare_inputs_v3_0_3(
 ) -> Result<Vec<u8>, ChannelError> {
     let imgbytes = hex::decode(image_id).map_err(|_| ChannelError::InvalidFieldElement)?;
     let mut digest = hashv(&[
-        PROVER_CONSTANTS_V3_0_3.receipt_claim_hash.as_ref(),
+        constants.receipt_claim_hash.as_ref(),
         &[0u8; 32],
         &imgbytes,
         execution_digest,
@@ -277,7 +177,7 @@ pub fn prepare_inputs_v3_0_3(
     ])
     .to_bytes();
 
-    let (c0, c1) = split_digest_reversed(&mut PROVER_CONSTANTS_V3_0_3.control_root.clone())
+    let (c0, c1) = split_digest_reversed(&mut constants.control_root.clone())
         .map_err(|_| ChannelError::InvalidFieldElement)?;
     let (half1_bytes, half2_bytes) =
         split_digest_reversed(&mut digest).map_err(|_| ChannelError::InvalidFieldElement)?;
@@ -285,9 +185,9 @@ pub fn prepare_inputs_v3_0_3(
     let inputs = [
         c0,
         c1,
-        half1_bytes.try_into().unwrap(),
-        half2_bytes.try_into().unwrap(),
-        PROVER_CONSTANTS_V3_0_3.bn254_control_id_bytes,
+        half1_bytes,
+        half2_bytes,
+        constants.bn254_control_id_bytes,
     ]
     .concat();
 
@@ -357,6 +257,12 @@ fn toggle_endianness<const N: usize>(bytes: &[u8]) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prover::{
+        PROVER_CONSTANTS_V1_0_1, PROVER_CONSTANTS_V1_2_1, PROVER_CONSTANTS_V2_3_1,
+        PROVER_CONSTANTS_V3_0_3, SUPPORTED_PROVERS,
+    };
+    use crate::verifying_key::{SP1_VERIFYINGKEY, SP1_VKEY_SELECTOR};
+    use hex_literal::hex;
 
     #[test]
     fn test_toggle_endianness() {
@@ -431,6 +337,397 @@ mod tests {
         assert!(split_digest_reversed_256(&mut d2).is_err());
     }
 
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    fn recorded_claim() -> ExecutionClaim<'static> {
+        ExecutionClaim {
+            image_id: IMAGE_ID,
+            execution_digest: &[2u8; 32],
+            input_digest: &[1u8; 32],
+            committed_outputs: &[1u8],
+            assumption_digest: &[0u8; 32],
+            exit_code_system: 0,
+            exit_code_user: 0,
+        }
+    }
+
+    fn recorded_inputs(constants: &ProverConstants) -> Vec<u8> {
+        let claim = recorded_claim();
+        let od = output_digest(
+            constants,
+            claim.input_digest,
+            claim.committed_outputs,
+            claim.assumption_digest,
+        );
+        prepare_inputs(
+            constants,
+            claim.image_id,
+            claim.execution_digest,
+            &od,
+            claim.exit_code_system,
+            claim.exit_code_user,
+        )
+        .unwrap()
+    }
+
+    // Public inputs pinned per version, a change to a version's constants shows up here.
+    #[test]
+    fn test_output_digest_recorded() {
+        let claim = recorded_claim();
+        for (_, constants) in SUPPORTED_PROVERS.iter() {
+            assert_eq!(
+                output_digest(
+                    constants,
+                    claim.input_digest,
+                    claim.committed_outputs,
+                    claim.assumption_digest
+                ),
+                hex!("1ce70ad87bb73e42821098a80343d5c6b87936ccaf00f074fd6665e2ccb8f454")
+            );
+        }
+    }
+
+    #[test]
+    fn test_prepare_inputs_recorded_v1_0_1() {
+        assert_eq!(
+            recorded_inputs(&PROVER_CONSTANTS_V1_0_1),
+            hex!(
+                "000000000000000000000000000000000e8ed4340930069162f5fbc957a016a5"
+                "000000000000000000000000000000002e7edecb6fd9ca4733501ee430425d77"
+                "0000000000000000000000000000000047b4bd5d2c109d6637371be83b6dc39b"
+                "0000000000000000000000000000000028ec5381dc08a2a2123732b12176f6bc"
+                "0eb6febcf06c5df079111be116f79bd8c7e85dc9448776ef9a59aaf2624ab551"
+            )
+        );
+    }
+
+    #[test]
+    fn test_prepare_inputs_recorded_v1_2_1() {
+        assert_eq!(
+            recorded_inputs(&PROVER_CONSTANTS_V1_2_1),
+            hex!(
+                "000000000000000000000000000000004d5a42c577a3ab1231be642624d9da8c"
+                "0000000000000000000000000000000069042c9355281d562b4766691ceb35f7"
+                "0000000000000000000000000000000047b4bd5d2c109d6637371be83b6dc39b"
+                "0000000000000000000000000000000028ec5381dc08a2a2123732b12176f6bc"
+                "04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0"
+            )
+        );
+    }
+
+    #[test]
+    fn test_prepare_inputs_recorded_v2_3_1() {
+        assert_eq!(
+            recorded_inputs(&PROVER_CONSTANTS_V2_3_1),
+            hex!(
+                "000000000000000000000000000000001b0da5b86dcff31a0242380356bf52ce"
+                "0000000000000000000000000000000029155a2c00cffdc6221a4fa325c13575"
+                "0000000000000000000000000000000047b4bd5d2c109d6637371be83b6dc39b"
+                "0000000000000000000000000000000028ec5381dc08a2a2123732b12176f6bc"
+                "04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0"
+            )
+        );
+    }
+
+    #[test]
+    fn test_prepare_inputs_recorded_v3_0_3() {
+        assert_eq!(
+            recorded_inputs(&PROVER_CONSTANTS_V3_0_3),
+            hex!(
+                "0000000000000000000000000000000041af18736dc9d7921c859fc95ac84da5"
+                "00000000000000000000000000000000561f8c992a424deb37ccdf4e19c0e7db"
+                "0000000000000000000000000000000047b4bd5d2c109d6637371be83b6dc39b"
+                "0000000000000000000000000000000028ec5381dc08a2a2123732b12176f6bc"
+                "04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0"
+            )
+        );
+    }
+
+    // Receipts RISC Zero recorded for the `risc0-ethereum-contracts` 3.0.1 tests, `TestReceiptV3_0`
+    // and `TestReceiptV2_2`, with the 4 byte verifier selector dropped from the seal. Both guests
+    // commit `just a simple receipt`, which stands in for the input digest.
+    const RECEIPT_JOURNAL: &[u8] = b"just a simple receipt";
+    const RECEIPT_IMAGE_ID_V3_0_3: &str =
+        "11d264ed8dfdee222b820f0278e4d7f55d4b69a5472253a471c102265a91ea1a";
+    const RECEIPT_SEAL_V3_0_3: [u8; 256] = hex!(
+        "2ccb718fd9092cc11546eeded62a44d3ed274076dd3ec154fae8739f3432050b"
+        "2005be2c5dbe6c08bfd04b30601a462540962bc26a2f38c5cfc0a4d76d8f1b80"
+        "15e690a1b230081234867edeedb2f98bcdf33d0471c2aa5e8db63b72333f8715"
+        "27eb5d1fcf0a7af50fb8f42e8699e2c4eda3cd93f4e2a930096ae78e38bea402"
+        "0c5c3d963dc453b4b302170e47c0cf53382255143c8fcef474d8b6eaaa8daaaf"
+        "092c2f650809a3afbd122ef128cb882c2de7a6ccddd2e544b645fa3fedf6bcc9"
+        "2e09be04876a07778231fd5b93305d35fd8af23f040a11682a8c64130370804f"
+        "28f07a76fa538755276e42c04b5f7eb97b04b68b65fa50e3181a0452069a3667"
+    );
+    const RECEIPT_IMAGE_ID_V2_3_1: &str =
+        "39b8aec425bb4e7eb994a0e4b6e9dbeceba907cf70f463cba7dc9786fe2dfb86";
+    const RECEIPT_SEAL_V2_3_1: [u8; 256] = hex!(
+        "1cb19c0439e5cb54ab7dd4d4e0cd8c4809abe8e19ba602b4ce8728dc9e2eafbe"
+        "27eac14be5e561f6cd697429d0567a4f46f62291b58f541c46fbf653dff67254"
+        "24ade3fdf1cc1014e062fa9e6c2e8e3a785fb2f84af10a44adfe761483b1036a"
+        "00bea38909a90b4f557b520cc77f01a33000444dd2c5c572d590e03c55c64639"
+        "2b8f7d3f65ab4b05ce2a339f86210a1762d8cae53bafdeaa105949019e5fb72a"
+        "17506d1c6db5cfbfee339cd9e20abc9871d2d72e6c0e66b9e8f6f5ddce731810"
+        "22cc8347b8ac33a5ee18bb0cecf4d47225112e90e1c4d0b896b7ab77701a8f4c"
+        "1391b544bc1d7ba20e6287ff63fded2de08e02db62b0093be54044819071cd65"
+    );
+    // digest of a halted SystemState, pc and memory root zero
+    const HALTED_POST_STATE: [u8; 32] =
+        hex!("a3acc27117418996340b84e5a90f3ef4c49d22c79e44aad822ec9c313e1eb8e2");
+
+    fn receipt_claim(image_id: &str) -> ExecutionClaim<'_> {
+        ExecutionClaim {
+            image_id,
+            execution_digest: &HALTED_POST_STATE,
+            input_digest: RECEIPT_JOURNAL,
+            committed_outputs: &[],
+            assumption_digest: &[0u8; 32],
+            exit_code_system: 0,
+            exit_code_user: 0,
+        }
+    }
+
+    #[test]
+    fn test_verify_recorded_receipts() {
+        let claim = receipt_claim(RECEIPT_IMAGE_ID_V3_0_3);
+        assert_eq!(
+            verify_risc0(&PROVER_CONSTANTS_V3_0_3, &RECEIPT_SEAL_V3_0_3, &claim),
+            Ok(true)
+        );
+        let claim = receipt_claim(RECEIPT_IMAGE_ID_V2_3_1);
+        assert_eq!(
+            verify_risc0(&PROVER_CONSTANTS_V2_3_1, &RECEIPT_SEAL_V2_3_1, &claim),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_verify_tampered_receipt() {
+        let claim = receipt_claim(RECEIPT_IMAGE_ID_V3_0_3);
+        let rejected = |constants: &ProverConstants, seal: &[u8], claim: &ExecutionClaim| {
+            !matches!(verify_risc0(constants, seal, claim), Ok(true))
+        };
+        // the journal split differently still hashes to the same journal digest
+        let split = ExecutionClaim {
+            input_digest: &RECEIPT_JOURNAL[..4],
+            committed_outputs: &RECEIPT_JOURNAL[4..],
+            ..receipt_claim(RECEIPT_IMAGE_ID_V3_0_3)
+        };
+        assert_eq!(
+            verify_risc0(&PROVER_CONSTANTS_V3_0_3, &RECEIPT_SEAL_V3_0_3, &split),
+            Ok(true)
+        );
+
+        let outputs = ExecutionClaim {
+            committed_outputs: &[1],
+            ..receipt_claim(RECEIPT_IMAGE_ID_V3_0_3)
+        };
+        assert!(rejected(
+            &PROVER_CONSTANTS_V3_0_3,
+            &RECEIPT_SEAL_V3_0_3,
+            &outputs
+        ));
+        let exit_code = ExecutionClaim {
+            exit_code_user: 1,
+            ..receipt_claim(RECEIPT_IMAGE_ID_V3_0_3)
+        };
+        assert!(rejected(
+            &PROVER_CONSTANTS_V3_0_3,
+            &RECEIPT_SEAL_V3_0_3,
+            &exit_code
+        ));
+        let image = receipt_claim(RECEIPT_IMAGE_ID_V2_3_1);
+        assert!(rejected(
+            &PROVER_CONSTANTS_V3_0_3,
+            &RECEIPT_SEAL_V3_0_3,
+            &image
+        ));
+        // proven against another control root
+        assert!(rejected(
+            &PROVER_CONSTANTS_V2_3_1,
+            &RECEIPT_SEAL_V3_0_3,
+            &claim
+        ));
+        let mut seal = RECEIPT_SEAL_V3_0_3;
+        seal[255] ^= 1;
+        assert!(rejected(&PROVER_CONSTANTS_V3_0_3, &seal, &claim));
+    }
+
+    #[test]
+    fn test_prepare_inputs_invalid_image_id() {
+        let od = [0u8; 32];
+        let res = prepare_inputs(&PROVER_CONSTANTS_V3_0_3, "not hex", &[0u8; 32], &od, 0, 0);
+        assert_eq!(res.unwrap_err(), ChannelError::InvalidFieldElement);
+    }
+
+    #[test]
+    fn test_verify_groth16_rejects_short_inputs() {
+        let res = verify_groth16(&[0u8; 256], &[0u8; 128]);
+        assert_eq!(res.unwrap_err(), ChannelError::InvalidPublicInputs);
+    }
+
+    #[test]
+    fn test_verify_groth16_rejects_short_proof() {
+        let res = verify_groth16(&[0u8; 128], &[0u8; 160]);
+        assert_eq!(res.unwrap_err(), ChannelError::InvalidProof);
+    }
+
+    // A key and proof for a throwaway circuit with the two public inputs of the SP1 wrapper, no
+    // SP1 proof is recorded here so this stands in to check the seal layout and input encoding.
+    const SP1_TEST_VKEY_HASH: &str =
+        "0x0054c0e58911dd8b993c6d8f249aa50a2e523114ec4b7ef9dd355c5f6bfbf3ce";
+    const SP1_TEST_VERIFYINGKEY: Groth16Verifyingkey = Groth16Verifyingkey {
+        nr_pubinputs: 3,
+        vk_alpha_g1: [
+            43, 90, 248, 208, 253, 185, 117, 152, 57, 115, 227, 212, 222, 140, 165, 152, 135, 43,
+            11, 57, 53, 73, 51, 72, 58, 159, 104, 29, 237, 225, 150, 38, 38, 54, 205, 45, 125, 154,
+            66, 124, 129, 98, 211, 44, 228, 214, 31, 132, 21, 95, 197, 1, 17, 106, 168, 160, 84,
+            232, 150, 89, 82, 64, 205, 99,
+        ],
+        vk_beta_g2: [
+            43, 178, 63, 43, 217, 173, 190, 243, 161, 130, 244, 206, 111, 230, 199, 133, 64, 17,
+            183, 47, 128, 31, 224, 208, 155, 13, 194, 213, 191, 248, 232, 225, 47, 207, 32, 114,
+            181, 125, 53, 143, 217, 228, 235, 39, 16, 9, 219, 133, 200, 84, 6, 132, 29, 20, 49, 97,
+            244, 100, 19, 27, 198, 137, 72, 90, 27, 72, 130, 193, 150, 15, 167, 83, 68, 21, 62,
+            123, 14, 3, 63, 194, 228, 66, 151, 239, 13, 226, 212, 184, 103, 97, 35, 118, 105, 39,
+            169, 121, 25, 93, 157, 130, 87, 200, 90, 100, 80, 234, 199, 63, 7, 162, 183, 82, 48,
+            83, 174, 254, 78, 80, 199, 68, 219, 19, 183, 90, 114, 232, 205, 104,
+        ],
+        vk_gamme_g2: [
+            23, 127, 87, 33, 56, 140, 152, 132, 46, 45, 35, 40, 12, 165, 251, 105, 235, 8, 87, 61,
+            2, 175, 192, 54, 183, 175, 65, 132, 243, 234, 170, 44, 21, 18, 208, 52, 228, 173, 36,
+            62, 52, 172, 148, 115, 215, 210, 184, 218, 166, 29, 193, 134, 72, 59, 193, 213, 252,
+            90, 175, 166, 184, 58, 131, 177, 36, 228, 93, 223, 112, 39, 64, 115, 149, 8, 55, 237,
+            59, 44, 174, 231, 216, 121, 10, 156, 251, 106, 93, 166, 14, 173, 84, 229, 67, 142, 149,
+            78, 40, 106, 104, 96, 243, 53, 17, 68, 135, 106, 135, 25, 203, 9, 227, 186, 147, 6,
+            125, 79, 120, 95, 224, 30, 147, 80, 214, 207, 193, 150, 138, 240,
+        ],
+        vk_delta_g2: [
+            16, 92, 178, 206, 87, 161, 157, 102, 206, 76, 160, 131, 28, 91, 228, 132, 190, 109,
+            120, 73, 121, 52, 175, 224, 129, 24, 149, 58, 175, 154, 10, 175, 19, 33, 187, 248, 251,
+            226, 49, 29, 227, 143, 86, 211, 6, 55, 199, 32, 58, 98, 97, 201, 53, 100, 171, 204, 47,
+            101, 62, 190, 103, 20, 110, 70, 8, 225, 225, 62, 15, 183, 31, 109, 186, 193, 30, 193,
+            196, 115, 93, 81, 131, 81, 50, 79, 48, 176, 70, 79, 177, 174, 41, 249, 92, 44, 95, 167,
+            14, 146, 132, 87, 237, 245, 188, 190, 52, 114, 112, 163, 53, 200, 38, 141, 116, 74, 10,
+            211, 218, 85, 25, 41, 137, 97, 225, 239, 152, 254, 133, 111,
+        ],
+        vk_ic: &[
+            [
+                38, 78, 108, 46, 7, 232, 169, 85, 12, 135, 44, 196, 166, 24, 216, 38, 104, 66, 96,
+                179, 188, 138, 34, 167, 228, 97, 31, 231, 82, 101, 150, 171, 16, 139, 66, 25, 240,
+                242, 28, 237, 159, 47, 19, 4, 106, 102, 169, 230, 172, 194, 43, 189, 69, 151, 112,
+                224, 187, 90, 6, 45, 130, 199, 36, 121,
+            ],
+            [
+                18, 253, 116, 30, 216, 185, 2, 197, 207, 1, 134, 80, 59, 182, 123, 203, 152, 68,
+                148, 188, 206, 62, 132, 13, 84, 141, 114, 68, 239, 205, 215, 68, 27, 91, 41, 194,
+                1, 235, 238, 227, 81, 21, 226, 42, 85, 203, 131, 64, 76, 108, 118, 130, 117, 196,
+                132, 10, 30, 84, 13, 207, 65, 123, 164, 74,
+            ],
+            [
+                39, 104, 29, 153, 126, 9, 53, 198, 183, 14, 61, 12, 184, 245, 212, 10, 27, 209,
+                120, 146, 253, 101, 70, 111, 26, 88, 87, 72, 18, 231, 229, 42, 16, 168, 150, 23,
+                129, 221, 219, 161, 212, 70, 14, 167, 181, 188, 83, 191, 157, 124, 22, 108, 251,
+                228, 154, 116, 71, 175, 53, 37, 85, 81, 171, 215,
+            ],
+        ],
+    };
+    const SP1_TEST_PROOF: [u8; 256] = hex!(
+        "0192c3f2d3ae32324d0b6c9121f42ae56c7f1a2be186d6c127a93ccd552952ce"
+        "15d0bfd5ac39301c381e3e50620e1786000fca99881f2d436e05f60a1402a6f0"
+        "27a885341e93a4958ffc8381e5d73f0e720c7586dc2c0ad9ef260c6d6c2a76e9"
+        "11833dfaa371122e306c6a0142c7d5f527651370d9dfc9f25480ecdf6b4c40ce"
+        "13ae8ec9b9a97c9b9691a377f5d97c919359f11c8277197ec5e81ab82c9c7994"
+        "213bf22167d20e0fff9d6e88b35dcf4a7d2aa0e61823d8a9a36d694cb728351a"
+        "23936596d003a797aaafeb9cb8e486195be97ea17d8b2a7b638e9bd007e613a8"
+        "0d4ee0ab33354154bf90b81d26b07a7ac482a4d6c955596234e5628fba06d518"
+    );
+
+    fn sp1_seal(selector: &[u8; 4]) -> Vec<u8> {
+        [&selector[..], &SP1_TEST_PROOF[..]].concat()
+    }
+
+    #[test]
+    fn test_prepare_inputs_sp1() {
+        let claim = recorded_claim();
+        let [vkey, values] = prepare_inputs_sp1(
+            claim.image_id,
+            &[claim.input_digest, claim.committed_outputs],
+        )
+        .unwrap();
+        assert_eq!(
+            vkey,
+            hex!("68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960")
+        );
+        // sha256 is ce0417..., the top three bits are cleared
+        assert_eq!(
+            values,
+            hex!("0e041765675ad4d93378e20bd3a7d0d97ddcf3385fb6341581b21d4bc9e3e69e")
+        );
+        let prefixed = prepare_inputs_sp1(&format!("0x{}", IMAGE_ID), &[]).unwrap();
+        assert_eq!(prefixed[0], vkey);
+    }
+
+    #[test]
+    fn test_prepare_inputs_sp1_invalid_vkey() {
+        let res = prepare_inputs_sp1("68f4b0c5", &[]);
+        assert_eq!(res.unwrap_err(), ChannelError::InvalidFieldElement);
+    }
+
+    #[test]
+    fn test_verify_sp1() {
+        let claim = ExecutionClaim {
+            image_id: SP1_TEST_VKEY_HASH,
+            ..recorded_claim()
+        };
+        let seal = sp1_seal(&SP1_VKEY_SELECTOR);
+        assert_eq!(
+            verify_sp1(&SP1_TEST_VERIFYINGKEY, &SP1_VKEY_SELECTOR, &seal, &claim),
+            Ok(true)
+        );
+        // the public values split differently still hash to the same digest
+        let split = ExecutionClaim {
+            input_digest: &[1u8; 16],
+            committed_outputs: &[1u8; 17],
+            ..claim
+        };
+        assert_eq!(
+            verify_sp1(&SP1_TEST_VERIFYINGKEY, &SP1_VKEY_SELECTOR, &seal, &split),
+            Ok(true)
+        );
+        let outputs = ExecutionClaim {
+            committed_outputs: &[2u8],
+            ..split
+        };
+        assert!(!matches!(
+            verify_sp1(&SP1_TEST_VERIFYINGKEY, &SP1_VKEY_SELECTOR, &seal, &outputs),
+            Ok(true)
+        ));
+        // a valid seal for one circuit key says nothing under another
+        assert!(!matches!(
+            verify_sp1(&SP1_VERIFYINGKEY, &SP1_VKEY_SELECTOR, &seal, &claim),
+            Ok(true)
+        ));
+    }
+
+    #[test]
+    fn test_verify_sp1_rejects_wrong_selector() {
+        let claim = ExecutionClaim {
+            image_id: SP1_TEST_VKEY_HASH,
+            ..recorded_claim()
+        };
+        let seal = sp1_seal(&[1, 2, 3, 4]);
+        let res = verify_sp1(&SP1_TEST_VERIFYINGKEY, &SP1_VKEY_SELECTOR, &seal, &claim);
+        assert_eq!(res.unwrap_err(), ChannelError::UnexpectedProofSystem);
+        let res = verify_sp1(
+            &SP1_TEST_VERIFYINGKEY,
+            &SP1_VKEY_SELECTOR,
+            &SP1_TEST_PROOF,
+            &claim,
+        );
+        assert_eq!(res.unwrap_err(), ChannelError::InvalidProof);
+    }
+
     #[test]
     fn test_sized_range() {
         let slice = [1u8; 32];
@@ -1,3 +1,6 @@
+use bonsol_interface::prover_version::{
+    ProverVersion, VERSION_V1_0_1, VERSION_V1_2_1, VERSION_V2_3_1, VERSION_V3_0_3,
+};
 use hex_literal::hex;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -44,6 +47,22 @@ pub const PROVER_CONSTANTS_V3_0_3: ProverConstants = ProverConstants {
     receipt_claim_hash: hex!("cb1fefcd1f2d9a64975cbbbf6e161e2914434b0cbb9960b84df5d717e86b48af"),
 };
 
+/// Prover versions this program can settle proofs for, paired with the constants their receipts
+/// commit to. Supporting a new risc0 release only requires adding an entry here.
+pub const SUPPORTED_PROVERS: [(ProverVersion, ProverConstants); 4] = [
+    (VERSION_V1_0_1, PROVER_CONSTANTS_V1_0_1),
+    (VERSION_V1_2_1, PROVER_CONSTANTS_V1_2_1),
+    (VERSION_V2_3_1, PROVER_CONSTANTS_V2_3_1),
+    (VERSION_V3_0_3, PROVER_CONSTANTS_V3_0_3),
+];
+
+pub fn prover_constants(version: &ProverVersion) -> Option<ProverConstants> {
+    SUPPORTED_PROVERS
+        .iter()
+        .find(|(v, _)| v == version)
+        .map(|(_, constants)| *constants)
+}
+
 impl Default for ProverConstants {
     fn default() -> Self {
         PROVER_CONSTANTS_V3_0_3
@@ -60,6 +79,31 @@ mod tests {
         assert_eq!(prover_constants, PROVER_CONSTANTS_V3_0_3);
     }
 
+    #[test]
+    fn test_prover_constants_lookup() {
+        assert_eq!(
+            prover_constants(&VERSION_V1_0_1),
+            Some(PROVER_CONSTANTS_V1_0_1)
+        );
+        assert_eq!(
+            prover_constants(&VERSION_V1_2_1),
+            Some(PROVER_CONSTANTS_V1_2_1)
+        );
+        assert_eq!(
+            prover_constants(&VERSION_V2_3_1),
+            Some(PROVER_CONSTANTS_V2_3_1)
+        );
+        assert_eq!(
+            prover_constants(&VERSION_V3_0_3),
+            Some(PROVER_CONSTANTS_V3_0_3)
+        );
+        assert_eq!(
+            prover_constants(&ProverVersion::default()),
+            Some(ProverConstants::default())
+        );
+        assert_eq!(prover_constants(&ProverVersion::UnsupportedVersion), None);
+    }
+
     #[test]
     fn test_prover_constant_1_0_1() {
         let prover_constants = PROVER_CONSTANTS_V1_0_1;
@@ -0,0 +1,316 @@
+//! How a status submission settles an execution request. The program runs `check_status` when it
+//! processes a `StatusV1`, indexers and auditors run the same function off chain to re-check a
+//! settled proof from the request and the submission transaction:
+//!
+//! ```ignore
+//! let er = bonsol::settlement::execution_request(&execute_ix.data)?;
+//! for (index, verdict) in bonsol::settlement::check_status_message(&er, &status_tx.message) {
+//!     println!("{index}: {:?}", verdict);
+//! }
+//! ```
+//!
+//! The execution request can be read from the `ExecuteV1` instruction that created it, the
+//! execution account is closed to an exit code once it settles.
+//!
+//! These checks stay in the program crate rather than bonsol-interface because they end in the
+//! Groth16 verifiers, whose verifying keys and pairing code only the program carries, and the
+//! program has to settle with exactly the code off chain callers run. Off chain callers depend on
+//! the program crate with the `no-entrypoint` feature.
+
+use bonsol_interface::{
+    bonsol_schema::{
+        parse_ix_data, root_as_execution_request_v1, ChannelInstructionIxType, ExecutionRequestV1,
+        ExitCode, StatusV1,
+    },
+    prover_version::ProverVersion,
+};
+use solana_program::message::VersionedMessage;
+
+use crate::{
+    assertions::check_bytes_match, error::ChannelError, proof_handling::ExecutionClaim,
+    verifier::Verifier,
+};
+
+/// The outcome of a status submission the program accepts. Submissions it rejects, leaving the
+/// request pending, are the `ChannelError` returned instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The proof verifies against the claim, the prover is paid and the callback invoked.
+    Verified { prover_version: ProverVersion },
+    /// A complete submission whose proof does not verify.
+    VerifyFailed { prover_version: ProverVersion },
+    /// The submission is missing the proof, a digest or the committed outputs, or the proof has
+    /// the wrong length for the requested proof system.
+    ProvingFailed,
+}
+
+impl Verdict {
+    /// The exit code the execution account is closed with.
+    pub const fn exit_code(&self) -> ExitCode {
+        match self {
+            Verdict::Verified { .. } => ExitCode::Success,
+            Verdict::VerifyFailed { .. } => ExitCode::VerifyError,
+            Verdict::ProvingFailed => ExitCode::ProvingError,
+        }
+    }
+}
+
+/// Checks a status submission against the execution request it settles. Expiry depends on the
+/// slot the submission landed in and is left to the caller.
+pub fn check_status(er: &ExecutionRequestV1, st: &StatusV1) -> Result<Verdict, ChannelError> {
+    if st.proof_system() != er.proof_system() {
+        return Err(ChannelError::UnexpectedProofSystem);
+    }
+    let prover_version =
+        ProverVersion::try_from(er.prover_version()).unwrap_or(ProverVersion::default());
+    let verifier = Verifier::for_request(er.proof_system(), &prover_version);
+    let proof = st
+        .proof()
+        .filter(|x| verifier.as_ref().is_some_and(|v| x.len() == v.seal_len()));
+    let (Some(verifier), Some(proof), Some(exed), Some(asud), Some(input_digest), Some(co)) = (
+        verifier,
+        proof,
+        st.execution_digest().map(|x| x.bytes()),
+        st.assumption_digest().map(|x| x.bytes()),
+        st.input_digest().map(|x| x.bytes()),
+        st.committed_outputs().map(|x| x.bytes()),
+    ) else {
+        return Ok(Verdict::ProvingFailed);
+    };
+    if er.verify_input_hash() {
+        let expected = er.input_digest().ok_or(ChannelError::InputDigestRequired)?;
+        check_bytes_match(
+            expected.bytes(),
+            input_digest,
+            ChannelError::InputsDontMatch,
+        )?;
+    }
+    let claim = ExecutionClaim {
+        image_id: er
+            .image_id()
+            .ok_or(ChannelError::InvalidExecutionAccountData)?,
+        execution_digest: exed,
+        input_digest,
+        committed_outputs: co,
+        assumption_digest: asud,
+        exit_code_system: st.exit_code_system(),
+        exit_code_user: st.exit_code_user(),
+    };
+    if verifier.verify(proof.bytes(), &claim)? {
+        Ok(Verdict::Verified { prover_version })
+    } else {
+        Ok(Verdict::VerifyFailed { prover_version })
+    }
+}
+
+/// Reads the execution request from the data of the `ExecuteV1` instruction that created it, or
+/// from the bare `ExecutionRequestV1` bytes a pending execution account holds.
+pub fn execution_request(data: &[u8]) -> Result<ExecutionRequestV1<'_>, ChannelError> {
+    // bare request bytes can verify as a ChannelInstruction, never with a valid nested request
+    parse_ix_data(data)
+        .ok()
+        .filter(|ix| ix.ix_type() == ChannelInstructionIxType::ExecuteV1)
+        .and_then(|ix| ix.execute_v1_verified())
+        .map_or_else(
+            || {
+                root_as_execution_request_v1(data)
+                    .map_err(|_| ChannelError::InvalidExecutionAccountData)
+            },
+            Ok,
+        )
+}
+
+/// Checks the data of a `StatusV1` instruction.
+pub fn check_status_instruction(
+    er: &ExecutionRequestV1,
+    data: &[u8],
+) -> Result<Verdict, ChannelError> {
+    let ix = parse_ix_data(data).map_err(|_| ChannelError::InvalidInstructionParse)?;
+    if ix.ix_type() != ChannelInstructionIxType::StatusV1 {
+        return Err(ChannelError::InvalidInstruction);
+    }
+    let st = ix
+        .status_v1_verified()
+        .ok_or(ChannelError::InvalidInstruction)?;
+    if st.execution_id() != er.execution_id() {
+        return Err(ChannelError::InvalidExecutionId);
+    }
+    check_status(er, &st)
+}
+
+/// Checks every `StatusV1` instruction a transaction sends to bonsol, keyed by instruction index.
+pub fn check_status_message(
+    er: &ExecutionRequestV1,
+    message: &VersionedMessage,
+) -> Vec<(usize, Result<Verdict, ChannelError>)> {
+    let keys = message.static_account_keys();
+    message
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter(|(_, ix)| ix.program_id(keys) == &crate::ID)
+        .filter(|(_, ix)| {
+            parse_ix_data(&ix.data).is_ok_and(|p| p.ix_type() == ChannelInstructionIxType::StatusV1)
+        })
+        .map(|(i, ix)| (i, check_status_instruction(er, &ix.data)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof_handling::RISC0_SEAL_LEN;
+    use bonsol_interface::bonsol_schema::{
+        ChannelInstruction, ChannelInstructionArgs, ExecutionRequestV1Args, ProofSystem,
+        StatusTypes, StatusV1Args,
+    };
+    use flatbuffers::FlatBufferBuilder;
+    use solana_program::{instruction::Instruction, message::Message, pubkey::Pubkey};
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    fn request(verify_input_hash: bool) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let execution_id = fbb.create_string("exec");
+        let image_id = fbb.create_string(IMAGE_ID);
+        let input_digest = fbb.create_vector(&[1u8; 32]);
+        let er = ExecutionRequestV1::create(
+            &mut fbb,
+            &ExecutionRequestV1Args {
+                execution_id: Some(execution_id),
+                image_id: Some(image_id),
+                input_digest: Some(input_digest),
+                verify_input_hash,
+                max_block_height: 100,
+                ..Default::default()
+            },
+        );
+        fbb.finish(er, None);
+        fbb.finished_data().to_vec()
+    }
+
+    fn status(proof: Option<&[u8]>, input_digest: &[u8], proof_system: ProofSystem) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let execution_id = fbb.create_string("exec");
+        let proof = proof.map(|p| fbb.create_vector(p));
+        let digest = fbb.create_vector(&[2u8; 32]);
+        let input_digest = fbb.create_vector(input_digest);
+        let assumption_digest = fbb.create_vector(&[0u8; 32]);
+        let committed_outputs = fbb.create_vector(&[1u8]);
+        let st = StatusV1::create(
+            &mut fbb,
+            &StatusV1Args {
+                execution_id: Some(execution_id),
+                status: StatusTypes::Completed,
+                proof,
+                execution_digest: Some(digest),
+                input_digest: Some(input_digest),
+                committed_outputs: Some(committed_outputs),
+                assumption_digest: Some(assumption_digest),
+                exit_code_system: 0,
+                exit_code_user: 0,
+                proof_system,
+            },
+        );
+        fbb.finish(st, None);
+        let payload = fbb.finished_data().to_vec();
+        let mut fbb = FlatBufferBuilder::new();
+        let payload = fbb.create_vector(&payload);
+        let ix = ChannelInstruction::create(
+            &mut fbb,
+            &ChannelInstructionArgs {
+                ix_type: ChannelInstructionIxType::StatusV1,
+                status_v1: Some(payload),
+                ..Default::default()
+            },
+        );
+        fbb.finish(ix, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn test_missing_proof_fails_proving() {
+        let er = request(true);
+        let er = execution_request(&er).unwrap();
+        let data = status(None, &[1u8; 32], ProofSystem::Risc0Groth16);
+        let verdict = check_status_instruction(&er, &data).unwrap();
+        assert_eq!(verdict, Verdict::ProvingFailed);
+        assert_eq!(verdict.exit_code(), ExitCode::ProvingError);
+        // a seal of the wrong length counts as missing
+        let data = status(Some(&[0u8; 12]), &[1u8; 32], ProofSystem::Risc0Groth16);
+        assert_eq!(
+            check_status_instruction(&er, &data).unwrap(),
+            Verdict::ProvingFailed
+        );
+    }
+
+    #[test]
+    fn test_rejections() {
+        let er = request(true);
+        let er = execution_request(&er).unwrap();
+        let proof = [0u8; RISC0_SEAL_LEN];
+        let mismatch = status(Some(&proof), &[9u8; 32], ProofSystem::Risc0Groth16);
+        assert_eq!(
+            check_status_instruction(&er, &mismatch),
+            Err(ChannelError::InputsDontMatch)
+        );
+        let sp1 = status(Some(&proof), &[1u8; 32], ProofSystem::Sp1Groth16);
+        assert_eq!(
+            check_status_instruction(&er, &sp1),
+            Err(ChannelError::UnexpectedProofSystem)
+        );
+        assert!(check_status_instruction(&er, &request(true)).is_err());
+    }
+
+    #[test]
+    fn test_garbage_proof_does_not_verify() {
+        let er = request(false);
+        let er = execution_request(&er).unwrap();
+        let data = status(
+            Some(&[1u8; RISC0_SEAL_LEN]),
+            &[9u8; 32],
+            ProofSystem::Risc0Groth16,
+        );
+        // the seal points are not on the curve, so the pairing check errors instead of failing
+        assert_eq!(
+            check_status_instruction(&er, &data),
+            Err(ChannelError::ProofVerificationFailed)
+        );
+    }
+
+    #[test]
+    fn test_execution_request_from_execute_instruction() {
+        let er = request(true);
+        let mut fbb = FlatBufferBuilder::new();
+        let payload = fbb.create_vector(&er);
+        let ix = ChannelInstruction::create(
+            &mut fbb,
+            &ChannelInstructionArgs {
+                ix_type: ChannelInstructionIxType::ExecuteV1,
+                execute_v1: Some(payload),
+                ..Default::default()
+            },
+        );
+        fbb.finish(ix, None);
+        let parsed = execution_request(fbb.finished_data()).unwrap();
+        assert_eq!(parsed.execution_id(), Some("exec"));
+        assert!(execution_request(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_check_status_message() {
+        let er = request(true);
+        let er = execution_request(&er).unwrap();
+        let prover = Pubkey::new_unique();
+        let data = status(None, &[1u8; 32], ProofSystem::Risc0Groth16);
+        let message = Message::new(
+            &[
+                Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]),
+                Instruction::new_with_bytes(crate::ID, &data, vec![]),
+            ],
+            Some(&prover),
+        );
+        let verdicts = check_status_message(&er, &VersionedMessage::Legacy(message));
+        assert_eq!(verdicts, vec![(1, Ok(Verdict::ProvingFailed))]);
+    }
+}
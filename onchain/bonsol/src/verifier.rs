@@ -0,0 +1,99 @@
+use bonsol_interface::{bonsol_schema::ProofSystem, prover_version::ProverVersion};
+use groth16_solana::groth16::Groth16Verifyingkey;
+
+use crate::{
+    error::ChannelError,
+    proof_handling::{verify_risc0, verify_sp1, ExecutionClaim, RISC0_SEAL_LEN, SP1_SEAL_LEN},
+    prover::{prover_constants, ProverConstants},
+    verifying_key::{SP1_VERIFYINGKEY, SP1_VKEY_SELECTOR},
+};
+
+/// Settles the proofs of a single proof system. Status submissions are routed to the verifier of
+/// the proof system the execution request was made with.
+pub enum Verifier {
+    Risc0Groth16(ProverConstants),
+    Sp1Groth16 {
+        vkey: &'static Groth16Verifyingkey<'static>,
+        vkey_selector: [u8; 4],
+    },
+}
+
+impl Verifier {
+    /// SP1 programs are all wrapped by the one pinned circuit, so the risc0 prover version of the
+    /// request does not select anything for them.
+    pub fn for_request(proof_system: ProofSystem, prover_version: &ProverVersion) -> Option<Self> {
+        match proof_system {
+            ProofSystem::Risc0Groth16 => {
+                prover_constants(prover_version).map(Verifier::Risc0Groth16)
+            }
+            ProofSystem::Sp1Groth16 => Some(Verifier::Sp1Groth16 {
+                vkey: &SP1_VERIFYINGKEY,
+                vkey_selector: SP1_VKEY_SELECTOR,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Proof systems an execution request may ask for. Requests for anything else are rejected at
+    /// execution time rather than leaving the prover unable to settle.
+    pub fn is_supported(proof_system: ProofSystem) -> bool {
+        bonsol_interface::is_supported_proof_system(proof_system)
+    }
+
+    pub const fn seal_len(&self) -> usize {
+        match self {
+            Verifier::Risc0Groth16(_) => RISC0_SEAL_LEN,
+            Verifier::Sp1Groth16 { .. } => SP1_SEAL_LEN,
+        }
+    }
+
+    pub fn verify(&self, proof: &[u8], claim: &ExecutionClaim) -> Result<bool, ChannelError> {
+        match self {
+            Verifier::Risc0Groth16(constants) => verify_risc0(constants, proof, claim),
+            Verifier::Sp1Groth16 {
+                vkey,
+                vkey_selector,
+            } => verify_sp1(vkey, vkey_selector, proof, claim),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bonsol_interface::prover_version::{VERSION_V1_0_1, VERSION_V3_0_3};
+
+    #[test]
+    fn test_risc0_verifier_lookup() {
+        let v = Verifier::for_request(ProofSystem::Risc0Groth16, &VERSION_V1_0_1).unwrap();
+        assert_eq!(v.seal_len(), RISC0_SEAL_LEN);
+        assert!(Verifier::for_request(
+            ProofSystem::Risc0Groth16,
+            &ProverVersion::UnsupportedVersion
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_sp1_verifier_lookup() {
+        for version in [VERSION_V3_0_3, ProverVersion::UnsupportedVersion] {
+            let v = Verifier::for_request(ProofSystem::Sp1Groth16, &version).unwrap();
+            assert_eq!(v.seal_len(), SP1_SEAL_LEN);
+            assert!(matches!(
+                v,
+                Verifier::Sp1Groth16 {
+                    vkey_selector: SP1_VKEY_SELECTOR,
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_proof_system() {
+        assert!(Verifier::for_request(ProofSystem(7), &VERSION_V3_0_3).is_none());
+        assert!(!Verifier::is_supported(ProofSystem(7)));
+        assert!(Verifier::is_supported(ProofSystem::Sp1Groth16));
+        assert!(Verifier::is_supported(ProofSystem::Risc0Groth16));
+    }
+}
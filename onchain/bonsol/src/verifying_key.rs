@@ -79,3 +79,138 @@ pub const VERIFYINGKEY: Groth16Verifyingkey = Groth16Verifyingkey {
         ],
     ],
 };
+
+/// The Groth16 verifying key of the SP1 v5.0.0 circuit that wraps every SP1 program, decompressed
+/// from the `groth16_vk.bin` shipped with `sp1-verifier`, see `tests/fixtures`.
+pub const SP1_VERIFYINGKEY: Groth16Verifyingkey = Groth16Verifyingkey {
+    nr_pubinputs: 3,
+
+    vk_alpha_g1: [
+        45, 77, 154, 167, 227, 2, 217, 223, 65, 116, 157, 85, 7, 148, 157, 5, 219, 234, 51, 251,
+        177, 108, 100, 59, 34, 245, 153, 162, 190, 109, 242, 226, 20, 190, 221, 80, 60, 55, 206,
+        176, 97, 216, 236, 96, 32, 159, 227, 69, 206, 137, 131, 10, 25, 35, 3, 1, 240, 118, 202,
+        255, 0, 77, 25, 38,
+    ],
+
+    vk_beta_g2: [
+        9, 103, 3, 47, 203, 247, 118, 209, 175, 201, 133, 248, 136, 119, 241, 130, 211, 132, 128,
+        166, 83, 242, 222, 202, 169, 121, 76, 188, 59, 243, 6, 12, 14, 24, 120, 71, 173, 76, 121,
+        131, 116, 208, 214, 115, 43, 245, 1, 132, 125, 214, 139, 192, 224, 113, 36, 30, 2, 19, 188,
+        127, 193, 61, 183, 171, 48, 76, 251, 209, 224, 138, 112, 74, 153, 245, 232, 71, 217, 63,
+        140, 60, 170, 253, 222, 196, 107, 122, 13, 55, 157, 166, 154, 77, 17, 35, 70, 167, 23, 57,
+        193, 177, 164, 87, 168, 199, 49, 49, 35, 210, 77, 47, 145, 146, 248, 150, 183, 198, 62,
+        234, 5, 169, 213, 127, 6, 84, 122, 208, 206, 200,
+    ],
+
+    vk_gamme_g2: [
+        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
+        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
+        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
+        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
+        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
+        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211,
+        123, 76, 230, 204, 1, 102, 250, 125, 170,
+    ],
+
+    vk_delta_g2: [
+        28, 199, 203, 141, 231, 21, 103, 95, 33, 240, 30, 204, 155, 70, 210, 54, 224, 134, 94, 12,
+        192, 32, 2, 69, 33, 153, 130, 105, 132, 95, 116, 230, 3, 255, 65, 244, 186, 12, 55, 254,
+        44, 175, 39, 53, 77, 40, 228, 184, 248, 61, 59, 118, 119, 122, 99, 179, 39, 215, 54, 191,
+        251, 1, 34, 237, 46, 211, 177, 155, 94, 179, 157, 176, 209, 154, 61, 114, 220, 213, 144,
+        172, 133, 169, 113, 222, 246, 20, 244, 253, 17, 181, 107, 60, 94, 83, 196, 76, 23, 56, 123,
+        75, 156, 240, 57, 39, 144, 155, 79, 28, 61, 128, 252, 214, 134, 90, 252, 74, 38, 74, 4, 73,
+        159, 126, 47, 109, 246, 144, 221, 133,
+    ],
+
+    vk_ic: &[
+        [
+            38, 9, 30, 28, 175, 176, 173, 138, 78, 160, 166, 148, 205, 55, 67, 235, 245, 36, 119,
+            146, 51, 219, 115, 76, 69, 29, 40, 181, 138, 169, 117, 142, 0, 159, 245, 10, 107, 139,
+            17, 195, 202, 111, 219, 38, 144, 161, 36, 248, 206, 37, 72, 159, 239, 166, 90, 62, 120,
+            46, 123, 167, 11, 102, 105, 14,
+        ],
+        [
+            6, 28, 63, 208, 253, 61, 162, 93, 38, 7, 194, 39, 208, 144, 204, 167, 80, 237, 54, 198,
+            236, 135, 135, 85, 229, 55, 193, 196, 137, 81, 251, 76, 15, 161, 122, 233, 194, 3, 51,
+            121, 223, 123, 92, 101, 239, 240, 225, 7, 5, 94, 154, 39, 62, 97, 25, 162, 18, 221, 9,
+            235, 81, 112, 114, 25,
+        ],
+        [
+            4, 234, 178, 65, 56, 138, 121, 129, 127, 224, 224, 226, 234, 208, 178, 236, 79, 253,
+            236, 81, 161, 96, 40, 222, 224, 32, 99, 79, 209, 41, 231, 28, 7, 35, 98, 86, 210, 28,
+            96, 208, 47, 11, 219, 249, 92, 255, 131, 224, 62, 169, 225, 111, 202, 86, 177, 141, 85,
+            68, 176, 136, 154, 101, 193, 245,
+        ],
+    ],
+};
+
+/// SP1 prefixes its Groth16 seals with the first 4 bytes of the sha256 of the circuit key.
+pub const SP1_VKEY_SELECTOR: [u8; 4] = [0xa4, 0x59, 0x4c, 0x59];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+    use solana_program::hash::hashv;
+
+    const SP1_GROTH16_VK: &[u8] = include_bytes!("../tests/fixtures/sp1_groth16_vk_v5.0.0.bin");
+
+    // gnark compresses big endian with its own flags in the top bits, arkworks little endian
+    fn gnark_to_ark_compressed(point: &[u8]) -> Vec<u8> {
+        let mut point = point.to_vec();
+        let flag = match point[0] & 0xc0 {
+            0x80 => 0x00,
+            0xc0 => 0x80,
+            flag => flag,
+        };
+        point[0] = point[0] & 0x3f | flag;
+        point.reverse();
+        point
+    }
+
+    // groth16-solana takes each coordinate big endian, the imaginary part of a G2 coordinate first
+    fn uncompressed<P: CanonicalDeserialize, F: CanonicalSerialize>(
+        point: &[u8],
+        coords: impl Fn(&P) -> (F, F),
+        chunk: usize,
+    ) -> Vec<u8> {
+        let point = P::deserialize_with_mode(
+            &*gnark_to_ark_compressed(point),
+            Compress::Yes,
+            Validate::Yes,
+        )
+        .unwrap();
+        let (x, y) = coords(&point);
+        let mut bytes = Vec::new();
+        x.serialize_uncompressed(&mut bytes).unwrap();
+        y.serialize_uncompressed(&mut bytes).unwrap();
+        bytes
+            .chunks(chunk)
+            .flat_map(|c| c.iter().rev().copied())
+            .collect()
+    }
+
+    fn g1(point: &[u8]) -> Vec<u8> {
+        uncompressed(point, |p: &ark_bn254::G1Affine| (p.x, p.y), 32)
+    }
+
+    fn g2(point: &[u8]) -> Vec<u8> {
+        uncompressed(point, |p: &ark_bn254::G2Affine| (p.x, p.y), 64)
+    }
+
+    #[test]
+    fn test_sp1_verifying_key_matches_release() {
+        let vk = SP1_GROTH16_VK;
+        assert_eq!(hashv(&[vk]).to_bytes()[..4], SP1_VKEY_SELECTOR);
+        assert_eq!(g1(&vk[..32]), SP1_VERIFYINGKEY.vk_alpha_g1);
+        assert_eq!(g2(&vk[64..128]), SP1_VERIFYINGKEY.vk_beta_g2);
+        assert_eq!(g2(&vk[128..192]), SP1_VERIFYINGKEY.vk_gamme_g2);
+        assert_eq!(g2(&vk[224..288]), SP1_VERIFYINGKEY.vk_delta_g2);
+        let num_k = u32::from_be_bytes(vk[288..292].try_into().unwrap()) as usize;
+        assert_eq!(num_k, SP1_VERIFYINGKEY.vk_ic.len());
+        assert_eq!(num_k, SP1_VERIFYINGKEY.nr_pubinputs);
+        for (i, ic) in SP1_VERIFYINGKEY.vk_ic.iter().enumerate() {
+            assert_eq!(g1(&vk[292 + 32 * i..324 + 32 * i]), ic);
+        }
+    }
+}
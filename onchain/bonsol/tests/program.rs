@@ -0,0 +1,842 @@
+//! In-process tests for the bonsol program, run with `cargo test-sbf` from `onchain/bonsol`.
+//! Tests that need a real Groth16 receipt read it from `tests/fixtures`, see the README there.
+#![cfg(feature = "test-sbf")]
+
+use bonsol::error::ChannelError;
+use bonsol_interface::{
+    bonsol_schema::{
+        parse_ix_data, root_as_deploy_v1, root_as_execution_request_v1, ChannelInstruction,
+        ChannelInstructionArgs, ChannelInstructionIxType, ChannelInstructionT, ClaimV1,
+        ClaimV1Args, ExitCode, ProgramInputType, ProofSystem, StatusTypes, StatusV1, StatusV1Args,
+    },
+    claim_state::ClaimStateV1,
+    instructions::{
+        add_provers_v1, deploy_v1, execute_v1, CallbackConfig, ExecutionConfig, InputRef,
+    },
+    util::{
+        deployment_address, execution_address, execution_claim_address, input_digest,
+        prover_set_address,
+    },
+};
+use flatbuffers::FlatBufferBuilder;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable,
+    clock::Clock,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+use std::sync::OnceLock;
+
+const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+const INPUT: &[u8] = b"{\"attestation\":\"test\"}";
+const TIP: u64 = 1_000_000;
+const EXPIRY_SLOTS: u64 = 100;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    requester: Keypair,
+}
+
+impl Harness {
+    async fn new() -> Self {
+        Self::with_accounts(vec![]).await
+    }
+
+    async fn with_accounts(accounts: Vec<(Pubkey, Account)>) -> Self {
+        let mut pt = ProgramTest::new("bonsol", bonsol::ID, None);
+        pt.prefer_bpf(true);
+        pt.set_compute_max_units(1_400_000);
+        for (key, account) in accounts {
+            pt.add_account(key, account);
+        }
+        let ctx = pt.start_with_context().await;
+        let mut harness = Harness {
+            ctx,
+            requester: Keypair::new(),
+        };
+        let requester = harness.requester.pubkey();
+        harness.fund(&requester).await;
+        harness
+    }
+
+    async fn funded_keypair(&mut self) -> Keypair {
+        let kp = Keypair::new();
+        self.fund(&kp.pubkey()).await;
+        kp
+    }
+
+    async fn fund(&mut self, key: &Pubkey) {
+        let ix = system_instruction::transfer(&self.ctx.payer.pubkey(), key, 10_000_000_000);
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    async fn process(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn account(&mut self, key: &Pubkey) -> Option<Account> {
+        self.ctx.banks_client.get_account(*key).await.unwrap()
+    }
+
+    async fn slot(&mut self) -> u64 {
+        self.ctx
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .slot
+    }
+
+    async fn warp(&mut self, slots: u64) {
+        let slot = self.slot().await;
+        self.ctx.warp_to_slot(slot + slots).unwrap();
+    }
+
+    async fn deploy(&mut self, image_id: &str) -> Result<(), BanksClientError> {
+        let requester = self.requester.insecure_clone();
+        let ix = deploy_v1(
+            &requester.pubkey(),
+            image_id,
+            1024,
+            "simple",
+            "https://example.com/simple",
+            vec![ProgramInputType::Public],
+        )
+        .unwrap();
+        self.process(&[ix], &[&requester]).await
+    }
+
+    async fn execute(
+        &mut self,
+        execution_id: &str,
+        config: ExecutionConfig<'_>,
+        callback: Option<CallbackConfig>,
+        authorized_provers: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let requester = self.requester.insecure_clone();
+        let expiry = self.slot().await + EXPIRY_SLOTS;
+        let ix = execute_v1(
+            &requester.pubkey(),
+            &requester.pubkey(),
+            IMAGE_ID,
+            execution_id,
+            vec![InputRef::public(INPUT)],
+            TIP,
+            expiry,
+            config,
+            callback,
+            None,
+            authorized_provers,
+        )
+        .unwrap();
+        self.process(&[ix], &[&requester]).await
+    }
+
+    async fn claim(
+        &mut self,
+        claimer: &Keypair,
+        execution_id: &str,
+        block_commitment: u64,
+        prover_set: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let ix = claim_ix(
+            &claimer.pubkey(),
+            &self.requester.pubkey(),
+            execution_id,
+            block_commitment,
+            prover_set,
+        );
+        self.process(&[ix], &[claimer]).await
+    }
+
+    async fn status(
+        &mut self,
+        prover: &Keypair,
+        execution_id: &str,
+        callback_program: Option<Pubkey>,
+        extra_accounts: Vec<AccountMeta>,
+        status: &StatusPayload<'_>,
+    ) -> Result<(), BanksClientError> {
+        let ix = status_ix(
+            &prover.pubkey(),
+            &self.requester.pubkey(),
+            execution_id,
+            callback_program,
+            extra_accounts,
+            status,
+        );
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        self.process(&[budget, ix], &[prover]).await
+    }
+
+    async fn execution_account(&mut self, execution_id: &str) -> Account {
+        let (exec, _) = execution_address(&self.requester.pubkey(), execution_id.as_bytes());
+        self.account(&exec).await.unwrap()
+    }
+
+    async fn claim_state(&mut self, execution_id: &str) -> ClaimStateV1 {
+        let (exec, _) = execution_address(&self.requester.pubkey(), execution_id.as_bytes());
+        let (exec_claim, _) = execution_claim_address(exec.as_ref());
+        let account = self.account(&exec_claim).await.unwrap();
+        ClaimStateV1::load_claim_owned(&account.data).unwrap()
+    }
+
+    /// Deploys the test image and submits a request that verifies the digest of `INPUT`.
+    async fn pending_execution(&mut self, execution_id: &str) {
+        self.deploy(IMAGE_ID).await.unwrap();
+        self.execute(execution_id, default_config(), None, vec![])
+            .await
+            .unwrap();
+    }
+}
+
+struct StatusPayload<'a> {
+    proof: &'a [u8],
+    execution_digest: &'a [u8],
+    input_digest: &'a [u8],
+    assumption_digest: &'a [u8],
+    committed_outputs: &'a [u8],
+    exit_code_system: u32,
+    exit_code_user: u32,
+}
+
+fn request_digest() -> &'static [u8] {
+    static DIGEST: OnceLock<[u8; 32]> = OnceLock::new();
+    DIGEST.get_or_init(|| input_digest(&[INPUT]))
+}
+
+fn default_config() -> ExecutionConfig<'static> {
+    ExecutionConfig {
+        verify_input_hash: true,
+        input_hash: Some(request_digest()),
+        forward_output: true,
+        ..Default::default()
+    }
+}
+
+fn channel_ix(
+    ix_type: ChannelInstructionIxType,
+    payload: &[u8],
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut fbb = FlatBufferBuilder::new();
+    let payload = fbb.create_vector(payload);
+    let args = match ix_type {
+        ChannelInstructionIxType::ClaimV1 => ChannelInstructionArgs {
+            ix_type,
+            claim_v1: Some(payload),
+            ..Default::default()
+        },
+        ChannelInstructionIxType::StatusV1 => ChannelInstructionArgs {
+            ix_type,
+            status_v1: Some(payload),
+            ..Default::default()
+        },
+        _ => unreachable!("build other instructions with bonsol_interface::instructions"),
+    };
+    let root = ChannelInstruction::create(&mut fbb, &args);
+    fbb.finish(root, None);
+    Instruction::new_with_bytes(bonsol::ID, fbb.finished_data(), accounts)
+}
+
+fn claim_ix(
+    claimer: &Pubkey,
+    requester: &Pubkey,
+    execution_id: &str,
+    block_commitment: u64,
+    prover_set: Option<Pubkey>,
+) -> Instruction {
+    let (exec, _) = execution_address(requester, execution_id.as_bytes());
+    let (exec_claim, _) = execution_claim_address(exec.as_ref());
+    let mut accounts = vec![
+        AccountMeta::new(exec, false),
+        AccountMeta::new_readonly(*requester, false),
+        AccountMeta::new(exec_claim, false),
+        AccountMeta::new(*claimer, true),
+        AccountMeta::new(*claimer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(prover_set) = prover_set {
+        accounts.push(AccountMeta::new_readonly(prover_set, false));
+    }
+    let mut fbb = FlatBufferBuilder::new();
+    let eid = fbb.create_string(execution_id);
+    let claim = ClaimV1::create(
+        &mut fbb,
+        &ClaimV1Args {
+            execution_id: Some(eid),
+            block_commitment,
+        },
+    );
+    fbb.finish(claim, None);
+    channel_ix(
+        ChannelInstructionIxType::ClaimV1,
+        fbb.finished_data(),
+        accounts,
+    )
+}
+
+fn status_ix(
+    prover: &Pubkey,
+    requester: &Pubkey,
+    execution_id: &str,
+    callback_program: Option<Pubkey>,
+    extra_accounts: Vec<AccountMeta>,
+    status: &StatusPayload,
+) -> Instruction {
+    let (exec, _) = execution_address(requester, execution_id.as_bytes());
+    let mut accounts = vec![
+        AccountMeta::new(*requester, false),
+        AccountMeta::new(exec, false),
+        AccountMeta::new_readonly(callback_program.unwrap_or(bonsol::ID), false),
+        AccountMeta::new(*prover, true),
+    ];
+    accounts.extend(extra_accounts);
+    let mut fbb = FlatBufferBuilder::new();
+    let eid = fbb.create_string(execution_id);
+    let proof = fbb.create_vector(status.proof);
+    let execution_digest = fbb.create_vector(status.execution_digest);
+    let input_digest = fbb.create_vector(status.input_digest);
+    let assumption_digest = fbb.create_vector(status.assumption_digest);
+    let committed_outputs = fbb.create_vector(status.committed_outputs);
+    let st = StatusV1::create(
+        &mut fbb,
+        &StatusV1Args {
+            execution_id: Some(eid),
+            status: StatusTypes::Completed,
+            proof: Some(proof),
+            execution_digest: Some(execution_digest),
+            input_digest: Some(input_digest),
+            assumption_digest: Some(assumption_digest),
+            committed_outputs: Some(committed_outputs),
+            exit_code_system: status.exit_code_system,
+            exit_code_user: status.exit_code_user,
+            proof_system: ProofSystem::Risc0Groth16,
+        },
+    );
+    fbb.finish(st, None);
+    channel_ix(
+        ChannelInstructionIxType::StatusV1,
+        fbb.finished_data(),
+        accounts,
+    )
+}
+
+fn custom_error(err: BanksClientError) -> u32 {
+    match err.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+        other => panic!("expected a custom program error, got {:?}", other),
+    }
+}
+
+fn garbage_status(input_digest: &[u8]) -> StatusPayload<'_> {
+    StatusPayload {
+        proof: &[3u8; 256],
+        execution_digest: &[2u8; 32],
+        input_digest,
+        assumption_digest: &[0u8; 32],
+        committed_outputs: &[1u8],
+        exit_code_system: 0,
+        exit_code_user: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_deploy() {
+    let mut h = Harness::new().await;
+    h.deploy(IMAGE_ID).await.unwrap();
+    let (deployment, _) = deployment_address(IMAGE_ID);
+    let account = h.account(&deployment).await.unwrap();
+    assert_eq!(account.owner, bonsol::ID);
+    let deploy = root_as_deploy_v1(&account.data).unwrap();
+    assert_eq!(deploy.image_id(), Some(IMAGE_ID));
+    assert_eq!(
+        deploy.owner().unwrap().bytes(),
+        h.requester.pubkey().as_ref()
+    );
+
+    let err = h.deploy(IMAGE_ID).await.unwrap_err();
+    assert_eq!(
+        custom_error(err),
+        ChannelError::DeploymentAlreadyExists as u32
+    );
+}
+
+#[tokio::test]
+async fn test_execute() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let account = h.execution_account("exec-1").await;
+    assert_eq!(account.owner, bonsol::ID);
+    let er = root_as_execution_request_v1(&account.data).unwrap();
+    assert_eq!(er.execution_id(), Some("exec-1"));
+    assert_eq!(er.image_id(), Some(IMAGE_ID));
+    assert_eq!(er.tip(), TIP);
+    assert_eq!(er.input_digest().unwrap().bytes(), request_digest());
+
+    // the execution account can not be reused
+    let err = h
+        .execute("exec-1", default_config(), None, vec![])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(err),
+        ChannelError::InvalidExecutionAccount as u32
+    );
+}
+
+#[tokio::test]
+async fn test_execute_requires_deployment() {
+    let mut h = Harness::new().await;
+    let err = h
+        .execute("exec-1", default_config(), None, vec![])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(err),
+        ChannelError::InvalidDeploymentAccount as u32
+    );
+}
+
+#[tokio::test]
+async fn test_execute_input_digest_mismatch() {
+    let mut h = Harness::new().await;
+    h.deploy(IMAGE_ID).await.unwrap();
+    let config = ExecutionConfig {
+        input_hash: Some(&[9u8; 32]),
+        ..default_config()
+    };
+    let err = h.execute("exec-1", config, None, vec![]).await.unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::InputsDontMatch as u32);
+}
+
+#[tokio::test]
+async fn test_execute_rejects_unsupported_proof_system() {
+    let mut h = Harness::new().await;
+    h.deploy(IMAGE_ID).await.unwrap();
+    let requester = h.requester.insecure_clone();
+    let expiry = h.slot().await + EXPIRY_SLOTS;
+    let mut ix = execute_v1(
+        &requester.pubkey(),
+        &requester.pubkey(),
+        IMAGE_ID,
+        "exec-1",
+        vec![InputRef::public(INPUT)],
+        TIP,
+        expiry,
+        default_config(),
+        None,
+        None,
+        vec![],
+    )
+    .unwrap();
+    // clients refuse to build this request, re-encode it the way a hand rolled one would be
+    let mut er = parse_ix_data(&ix.data)
+        .unwrap()
+        .execute_v1_verified()
+        .unwrap()
+        .unpack();
+    er.proof_system = ProofSystem(7);
+    let mut fbb = FlatBufferBuilder::new();
+    let packed = er.pack(&mut fbb);
+    fbb.finish(packed, None);
+    let mut channel_ix = ChannelInstructionT::default();
+    channel_ix.execute_v1 = Some(fbb.finished_data().to_vec());
+    let mut fbb = FlatBufferBuilder::new();
+    let packed = channel_ix.pack(&mut fbb);
+    fbb.finish(packed, None);
+    ix.data = fbb.finished_data().to_vec();
+    let err = h.process(&[ix], &[&requester]).await.unwrap_err();
+    assert_eq!(
+        custom_error(err),
+        ChannelError::UnexpectedProofSystem as u32
+    );
+}
+
+#[tokio::test]
+async fn test_execute_compact_storage() {
+    let mut h = Harness::new().await;
+    h.pending_execution("full").await;
+    let config = ExecutionConfig {
+        compact_storage: true,
+        ..default_config()
+    };
+    h.execute("compact", config, None, vec![]).await.unwrap();
+    let full = h.execution_account("full").await;
+    let compact = h.execution_account("compact").await;
+    assert!(compact.data.len() < full.data.len());
+    let er = root_as_execution_request_v1(&compact.data).unwrap();
+    assert!(er.input().is_none());
+    assert!(er.request_digest().is_some());
+}
+
+#[tokio::test]
+async fn test_claim() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    let claim = h.claim_state("exec-1").await;
+    assert_eq!(claim.claimer, prover.pubkey().to_bytes());
+    assert_eq!(claim.block_commitment, commitment);
+}
+
+#[tokio::test]
+async fn test_reclaim_after_commitment() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let first = h.funded_keypair().await;
+    let second = h.funded_keypair().await;
+    let commitment = h.slot().await + 5;
+    h.claim(&first, "exec-1", commitment, None).await.unwrap();
+
+    let err = h
+        .claim(&second, "exec-1", commitment + 10, None)
+        .await
+        .unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::ActiveClaimExists as u32);
+
+    h.warp(10).await;
+    let commitment = h.slot().await + 10;
+    h.claim(&second, "exec-1", commitment, None).await.unwrap();
+    let claim = h.claim_state("exec-1").await;
+    assert_eq!(claim.claimer, second.pubkey().to_bytes());
+}
+
+#[tokio::test]
+async fn test_expired_claim_cleans_up() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    h.warp(EXPIRY_SLOTS + 10).await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    let account = h.execution_account("exec-1").await;
+    assert_eq!(account.data, vec![ChannelError::ExecutionExpired as u8]);
+}
+
+#[tokio::test]
+async fn test_status_expired() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    h.warp(EXPIRY_SLOTS + 10).await;
+    let err = h
+        .status(
+            &prover,
+            "exec-1",
+            None,
+            vec![],
+            &garbage_status(request_digest()),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::ExecutionExpired as u32);
+}
+
+#[tokio::test]
+async fn test_status_invalid_proof() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    let before = h.execution_account("exec-1").await;
+    assert!(h
+        .status(
+            &prover,
+            "exec-1",
+            None,
+            vec![],
+            &garbage_status(request_digest())
+        )
+        .await
+        .is_err());
+    // a rejected proof leaves the request open for a valid one
+    let after = h.execution_account("exec-1").await;
+    assert_eq!(before.data, after.data);
+}
+
+#[tokio::test]
+async fn test_status_without_proof_fails_execution() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    let status = StatusPayload {
+        proof: &[],
+        ..garbage_status(request_digest())
+    };
+    h.status(&prover, "exec-1", None, vec![], &status)
+        .await
+        .unwrap();
+    let account = h.execution_account("exec-1").await;
+    assert_eq!(account.data, vec![ExitCode::ProvingError as u8]);
+}
+
+#[tokio::test]
+async fn test_status_input_digest_mismatch() {
+    let mut h = Harness::new().await;
+    h.pending_execution("exec-1").await;
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "exec-1", commitment, None).await.unwrap();
+    let err = h
+        .status(&prover, "exec-1", None, vec![], &garbage_status(&[9u8; 32]))
+        .await
+        .unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::InputsDontMatch as u32);
+}
+
+#[tokio::test]
+async fn test_authorized_provers() {
+    let mut h = Harness::new().await;
+    h.deploy(IMAGE_ID).await.unwrap();
+    let allowed = h.funded_keypair().await;
+    let other = h.funded_keypair().await;
+    h.execute("exec-1", default_config(), None, vec![allowed.pubkey()])
+        .await
+        .unwrap();
+    let commitment = h.slot().await + 10;
+    let err = h
+        .claim(&other, "exec-1", commitment, None)
+        .await
+        .unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::UnauthorizedProver as u32);
+    h.claim(&allowed, "exec-1", commitment, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_prover_set() {
+    let mut h = Harness::new().await;
+    h.deploy(IMAGE_ID).await.unwrap();
+    let allowed = h.funded_keypair().await;
+    let other = h.funded_keypair().await;
+    let requester = h.requester.insecure_clone();
+    let ix = add_provers_v1(
+        &requester.pubkey(),
+        &requester.pubkey(),
+        "trusted",
+        &[allowed.pubkey()],
+    )
+    .unwrap();
+    h.process(&[ix], &[&requester]).await.unwrap();
+    let (prover_set, _) = prover_set_address(&requester.pubkey(), b"trusted");
+    let config = ExecutionConfig {
+        prover_set: Some(prover_set),
+        ..default_config()
+    };
+    h.execute("exec-1", config, None, vec![]).await.unwrap();
+
+    let commitment = h.slot().await + 10;
+    let err = h
+        .claim(&other, "exec-1", commitment, Some(prover_set))
+        .await
+        .unwrap_err();
+    assert_eq!(custom_error(err), ChannelError::UnauthorizedProver as u32);
+    let err = h
+        .claim(&allowed, "exec-1", commitment, None)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(err),
+        ChannelError::InvalidProverSetAccount as u32
+    );
+    h.claim(&allowed, "exec-1", commitment, Some(prover_set))
+        .await
+        .unwrap();
+}
+
+/// A Groth16 receipt recorded from a real prover, see `tests/fixtures/README.md`.
+#[derive(serde::Deserialize)]
+struct RecordedStatus {
+    image_id: String,
+    input_digest: String,
+    committed_outputs: String,
+    execution_digest: String,
+    assumption_digest: String,
+    exit_code_system: u32,
+    exit_code_user: u32,
+    proof: String,
+}
+
+struct RecordedBytes {
+    input_digest: Vec<u8>,
+    committed_outputs: Vec<u8>,
+    execution_digest: Vec<u8>,
+    assumption_digest: Vec<u8>,
+    proof: Vec<u8>,
+}
+
+impl RecordedStatus {
+    fn load() -> Self {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/status_v3_0_3.json"
+        );
+        let data = std::fs::read(path).expect("missing recorded receipt fixture");
+        serde_json::from_slice(&data).unwrap()
+    }
+
+    fn bytes(&self) -> RecordedBytes {
+        RecordedBytes {
+            input_digest: hex::decode(&self.input_digest).unwrap(),
+            committed_outputs: hex::decode(&self.committed_outputs).unwrap(),
+            execution_digest: hex::decode(&self.execution_digest).unwrap(),
+            assumption_digest: hex::decode(&self.assumption_digest).unwrap(),
+            proof: hex::decode(&self.proof).unwrap(),
+        }
+    }
+}
+
+impl RecordedBytes {
+    fn status(&self, recorded: &RecordedStatus) -> StatusPayload<'_> {
+        StatusPayload {
+            proof: &self.proof,
+            execution_digest: &self.execution_digest,
+            input_digest: &self.input_digest,
+            assumption_digest: &self.assumption_digest,
+            committed_outputs: &self.committed_outputs,
+            exit_code_system: recorded.exit_code_system,
+            exit_code_user: recorded.exit_code_user,
+        }
+    }
+}
+
+/// Deploys the recorded image and claims a request whose input digest matches the receipt.
+async fn recorded_execution(
+    h: &mut Harness,
+    recorded: &RecordedStatus,
+    bytes: &RecordedBytes,
+    callback: Option<CallbackConfig>,
+) -> Keypair {
+    h.deploy(&recorded.image_id).await.unwrap();
+    let requester = h.requester.insecure_clone();
+    let expiry = h.slot().await + EXPIRY_SLOTS;
+    let ix = execute_v1(
+        &requester.pubkey(),
+        &requester.pubkey(),
+        &recorded.image_id,
+        "recorded",
+        // resolved by the prover, the program can not check the digest at execution time
+        vec![InputRef::url(b"https://example.com/recorded-input")],
+        TIP,
+        expiry,
+        ExecutionConfig {
+            verify_input_hash: true,
+            input_hash: Some(&bytes.input_digest),
+            forward_output: true,
+            ..Default::default()
+        },
+        callback,
+        None,
+        vec![],
+    )
+    .unwrap();
+    h.process(&[ix], &[&requester]).await.unwrap();
+    let prover = h.funded_keypair().await;
+    let commitment = h.slot().await + 10;
+    h.claim(&prover, "recorded", commitment, None)
+        .await
+        .unwrap();
+    prover
+}
+
+#[tokio::test]
+async fn test_status_recorded_proof() {
+    let recorded = RecordedStatus::load();
+    let bytes = recorded.bytes();
+    let mut h = Harness::new().await;
+    let prover = recorded_execution(&mut h, &recorded, &bytes, None).await;
+    let balance = h.account(&prover.pubkey()).await.unwrap().lamports;
+    h.status(&prover, "recorded", None, vec![], &bytes.status(&recorded))
+        .await
+        .unwrap();
+    let account = h.execution_account("recorded").await;
+    assert_eq!(account.data, vec![ExitCode::Success as u8]);
+    let paid = h.account(&prover.pubkey()).await.unwrap().lamports;
+    assert!(paid > balance);
+}
+
+#[tokio::test]
+async fn test_status_callback_extra_accounts() {
+    let recorded = RecordedStatus::load();
+    let bytes = recorded.bytes();
+    // any upgradeable loader owned account passes the execute check, the extra account
+    // validation fails before the callback is invoked
+    let callback_program = Pubkey::new_unique();
+    let program_account = Account {
+        lamports: 1_000_000_000,
+        data: vec![],
+        owner: bpf_loader_upgradeable::id(),
+        executable: true,
+        rent_epoch: 0,
+    };
+    let mut h = Harness::with_accounts(vec![(callback_program, program_account)]).await;
+    let writable = Pubkey::new_unique();
+    let readonly = Pubkey::new_unique();
+    let callback = CallbackConfig {
+        program_id: callback_program,
+        instruction_prefix: vec![1],
+        extra_accounts: vec![
+            AccountMeta::new(writable, false),
+            AccountMeta::new_readonly(readonly, false),
+        ],
+    };
+    let prover = recorded_execution(&mut h, &recorded, &bytes, Some(callback)).await;
+    let status = bytes.status(&recorded);
+
+    let cases = vec![
+        // missing an account
+        vec![AccountMeta::new(writable, false)],
+        // wrong account
+        vec![
+            AccountMeta::new(writable, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ],
+        // writable account passed as readonly
+        vec![
+            AccountMeta::new_readonly(writable, false),
+            AccountMeta::new_readonly(readonly, false),
+        ],
+    ];
+    for extra_accounts in cases {
+        let err = h
+            .status(
+                &prover,
+                "recorded",
+                Some(callback_program),
+                extra_accounts,
+                &status,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            custom_error(err),
+            ChannelError::InvalidCallbackExtraAccounts as u32
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use bonsol::{
+    proof_handling::{output_digest, prepare_inputs, split_digest_reversed_256},
+    prover::{ProverConstants, PROVER_CONSTANTS_V3_0_3},
+};
+use proptest::prelude::*;
+use risc0_zkvm::{
+    sha::{Digest, Digestible},
+    Assumption, Assumptions, ExitCode, Groth16ReceiptVerifierParameters, MaybePruned, Output,
+    ReceiptClaim,
+};
+
+/// The constants the program pins for the risc0 version the workspace builds against.
+const CONSTANTS: ProverConstants = PROVER_CONSTANTS_V3_0_3;
+
+fn digest() -> impl Strategy<Value = Digest> {
+    any::<[u8; 32]>().prop_map(Digest::from)
+}
+
+fn exit_code() -> impl Strategy<Value = ExitCode> {
+    prop_oneof![
+        any::<u32>().prop_map(ExitCode::Halted),
+        any::<u32>().prop_map(ExitCode::Paused),
+        Just(ExitCode::SystemSplit),
+        Just(ExitCode::SessionLimit),
+    ]
+}
+
+fn assumptions() -> impl Strategy<Value = Assumptions> {
+    prop::collection::vec((digest(), digest()), 0..4).prop_map(|a| {
+        Assumptions(
+            a.into_iter()
+                .map(|(claim, control_root)| {
+                    MaybePruned::Value(Assumption {
+                        claim,
+                        control_root,
+                    })
+                })
+                .collect(),
+        )
+    })
+}
+
+/// A journal as bonsol guests commit it, the input digest followed by the outputs.
+fn journal() -> impl Strategy<Value = ([u8; 32], Vec<u8>)> {
+    (
+        any::<[u8; 32]>(),
+        prop::collection::vec(any::<u8>(), 0..512),
+    )
+}
+
+fn output(input_digest: &[u8; 32], committed_outputs: &[u8], assumptions: Assumptions) -> Output {
+    Output {
+        journal: MaybePruned::Value([input_digest.as_slice(), committed_outputs].concat()),
+        assumptions: MaybePruned::Value(assumptions),
+    }
+}
+
+proptest! {
+    #[test]
+    fn output_digest_matches_risc0(
+        (input_digest, committed_outputs) in journal(),
+        assumptions in assumptions(),
+    ) {
+        let assumption_digest = assumptions.digest();
+        let expected = output(&input_digest, &committed_outputs, assumptions).digest();
+        let actual = output_digest(
+            &CONSTANTS,
+            &input_digest,
+            &committed_outputs,
+            assumption_digest.as_bytes(),
+        );
+        prop_assert_eq!(actual.as_slice(), expected.as_bytes());
+    }
+
+    #[test]
+    fn claim_digest_matches_risc0(
+        image_id in digest(),
+        post in digest(),
+        (input_digest, committed_outputs) in journal(),
+        assumptions in assumptions(),
+        exit_code in exit_code(),
+    ) {
+        let assumption_digest = assumptions.digest();
+        let (system, user) = exit_code.into_pair();
+        let claim = ReceiptClaim {
+            pre: MaybePruned::Pruned(image_id),
+            post: MaybePruned::Pruned(post),
+            exit_code,
+            input: MaybePruned::Value(None),
+            output: MaybePruned::Value(Some(output(
+                &input_digest,
+                &committed_outputs,
+                assumptions,
+            ))),
+        };
+
+        let od = output_digest(
+            &CONSTANTS,
+            &input_digest,
+            &committed_outputs,
+            assumption_digest.as_bytes(),
+        );
+        let inputs = prepare_inputs(
+            &CONSTANTS,
+            &hex::encode(image_id.as_bytes()),
+            post.as_bytes(),
+            &od,
+            system,
+            user,
+        )
+        .unwrap();
+
+        let (a0, a1) = split_digest_reversed_256(&mut claim.digest().as_bytes().to_vec()).unwrap();
+        prop_assert_eq!(&inputs[64..96], a0.as_slice());
+        prop_assert_eq!(&inputs[96..128], a1.as_slice());
+    }
+}
+
+#[test]
+fn image_id_is_hex_of_the_pre_state_digest() {
+    // Execution requests carry the image id as risc0 prints it, `prepare_inputs` decodes it back
+    let image_id = Digest::from([9u8; 32]);
+    assert_eq!(image_id.to_string(), hex::encode(image_id.as_bytes()));
+}
+
+#[test]
+fn groth16_parameters_match_risc0() {
+    let params = Groth16ReceiptVerifierParameters::default();
+    let inputs = prepare_inputs(
+        &CONSTANTS,
+        &hex::encode([0u8; 32]),
+        &[0u8; 32],
+        &[0u8; 32],
+        0,
+        0,
+    )
+    .unwrap();
+
+    let (c0, c1) = split_digest_reversed_256(&mut params.control_root.as_bytes().to_vec()).unwrap();
+    assert_eq!(&inputs[0..32], c0.as_slice());
+    assert_eq!(&inputs[32..64], c1.as_slice());
+
+    let mut bn254_control_id = params.bn254_control_id.as_bytes().to_vec();
+    bn254_control_id.reverse();
+    assert_eq!(&inputs[128..160], bn254_control_id.as_slice());
+    assert_eq!(
+        CONSTANTS.bn254_control_id_bytes.as_slice(),
+        bn254_control_id
+    );
+}
+
+#[test]
+fn tags_match_risc0() {
+    // The program hashes the tags of the risc0 structs rather than the tag strings
+    use risc0_zkvm::sha::{Impl, Sha256};
+    assert_eq!(
+        Impl::hash_bytes(b"risc0.Output").as_bytes(),
+        CONSTANTS.output_hash.as_slice()
+    );
+    assert_eq!(
+        Impl::hash_bytes(b"risc0.ReceiptClaim").as_bytes(),
+        CONSTANTS.receipt_claim_hash.as_slice()
+    );
+}
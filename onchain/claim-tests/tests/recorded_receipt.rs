@@ -0,0 +1,172 @@
+//! Round trips a recorded Groth16 receipt through risc0's verifier and the program's. The receipt
+//! is the one the program tests settle, see `onchain/bonsol/tests/fixtures`. The ignored test
+//! produces one live the way the node does.
+use bonsol::{
+    proof_handling::{verify_risc0, ExecutionClaim},
+    prover::PROVER_CONSTANTS_V3_0_3,
+};
+use bonsol_compression::{check_stark_compression_tools_path, risc0_compress_proof};
+use bonsol_prover::{
+    image::Image,
+    input_resolver::{ProgramInput, ResolvedInput},
+    prover::{get_risc0_prover, new_risc0_exec_env},
+    util::LogShipper,
+    ProgramInputType,
+};
+use hex::FromHex;
+use risc0_zkvm::{
+    recursion::identity_p254,
+    sha::{Digest, Digestible},
+    Groth16Receipt, Groth16ReceiptVerifierParameters, InnerReceipt, MaybePruned, Receipt,
+    ReceiptClaim, VerifierContext,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct RecordedStatus {
+    image_id: String,
+    input_digest: String,
+    committed_outputs: String,
+    execution_digest: String,
+    assumption_digest: String,
+    exit_code_system: u32,
+    exit_code_user: u32,
+    proof: String,
+}
+
+fn recorded() -> RecordedStatus {
+    serde_json::from_str(include_str!(
+        "../../bonsol/tests/fixtures/status_v3_0_3.json"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn recorded_receipt_verifies_on_chain() {
+    let recorded = recorded();
+    let image_id = Digest::from_hex(&recorded.image_id).unwrap();
+    let input_digest = hex::decode(&recorded.input_digest).unwrap();
+    let committed_outputs = hex::decode(&recorded.committed_outputs).unwrap();
+    let seal = hex::decode(&recorded.proof).unwrap();
+    let journal = [input_digest.as_slice(), &committed_outputs].concat();
+
+    let claim = ReceiptClaim::ok(image_id, journal.clone());
+    let receipt = Receipt::new(
+        InnerReceipt::Groth16(Groth16Receipt::new(
+            seal.clone(),
+            MaybePruned::Value(claim.clone()),
+            Groth16ReceiptVerifierParameters::default().digest(),
+        )),
+        journal,
+    );
+    receipt.verify(image_id).unwrap();
+
+    // the fields the node submits, taken from the claim the way `risc0_compress_proof` does
+    let (exit_code_system, exit_code_user) = claim.exit_code.into_pair();
+    let execution_digest = claim.post.digest();
+    let output = claim.output.as_value().unwrap().as_ref().unwrap();
+    let assumption_digest = output.assumptions.digest();
+    assert_eq!(
+        hex::encode(execution_digest.as_bytes()),
+        recorded.execution_digest
+    );
+    assert_eq!(
+        hex::encode(assumption_digest.as_bytes()),
+        recorded.assumption_digest
+    );
+    assert_eq!(
+        (exit_code_system, exit_code_user),
+        (recorded.exit_code_system, recorded.exit_code_user)
+    );
+
+    let claim = ExecutionClaim {
+        image_id: &recorded.image_id,
+        execution_digest: execution_digest.as_bytes(),
+        input_digest: &input_digest,
+        committed_outputs: &committed_outputs,
+        assumption_digest: assumption_digest.as_bytes(),
+        exit_code_system,
+        exit_code_user,
+    };
+    assert_eq!(
+        verify_risc0(&PROVER_CONSTANTS_V3_0_3, &seal, &claim),
+        Ok(true)
+    );
+
+    let tampered = [committed_outputs.as_slice(), &[1]].concat();
+    let claim = ExecutionClaim {
+        committed_outputs: &tampered,
+        ..claim
+    };
+    assert!(verify_risc0(&PROVER_CONSTANTS_V3_0_3, &seal, &claim).is_err());
+}
+
+/// Proves `images/simple` and compresses the receipt with `risc0_compress_proof` like the node,
+/// then verifies it with the program. Set `BONSOL_TEST_IMAGE` to the built `images/simple` binary
+/// and `STARK_COMPRESSION_TOOLS_PATH` to the stark compression tools, which run on x86 only.
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "requires BONSOL_TEST_IMAGE and STARK_COMPRESSION_TOOLS_PATH"]
+async fn live_receipt_verifies_on_chain() {
+    let image_path = std::env::var("BONSOL_TEST_IMAGE").unwrap();
+    let tools_path = std::env::var("STARK_COMPRESSION_TOOLS_PATH").unwrap();
+    check_stark_compression_tools_path(&tools_path).unwrap();
+    let image = Image::new(image_path.into()).await.unwrap();
+    let memory_image = image.get_memory_image().unwrap();
+    let inputs = vec![
+        ProgramInput::Resolved(ResolvedInput {
+            index: 0,
+            data: br#"{"attestation":"test"}"#.to_vec(),
+            input_type: ProgramInputType::Public,
+        }),
+        ProgramInput::Resolved(ResolvedInput {
+            index: 1,
+            data: b"test".to_vec(),
+            input_type: ProgramInputType::Private,
+        }),
+    ];
+
+    // the succinct receipt and assumptions digest `risc0_prove` hands the node
+    let (journal, assumption_digest, receipt) = tokio::task::spawn_blocking(move || {
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let stdout = LogShipper::new(tx.clone(), "simple", "live");
+        let stderr = LogShipper::new(tx, "simple", "live");
+        let session = new_risc0_exec_env(memory_image, inputs, stdout, stderr)
+            .unwrap()
+            .run()
+            .unwrap();
+        let prover = get_risc0_prover().unwrap();
+        let info = prover
+            .prove_session(&VerifierContext::default(), &session)
+            .unwrap();
+        let InnerReceipt::Composite(composite) = &info.receipt.inner else {
+            panic!("expected a composite receipt");
+        };
+        let succinct = prover.composite_to_succinct(composite).unwrap();
+        let claim = succinct.claim.as_value().unwrap();
+        let output = claim.output.as_value().unwrap().as_ref().unwrap();
+        let assumption_digest = output.assumptions.digest();
+        (
+            info.receipt.journal.bytes,
+            assumption_digest,
+            identity_p254(&succinct).unwrap(),
+        )
+    })
+    .await
+    .unwrap();
+    let compressed = risc0_compress_proof(&tools_path, receipt).await.unwrap();
+
+    let (input_digest, committed_outputs) = journal.split_at(32);
+    let claim = ExecutionClaim {
+        image_id: &image.id,
+        execution_digest: &compressed.execution_digest,
+        input_digest,
+        committed_outputs,
+        assumption_digest: assumption_digest.as_bytes(),
+        exit_code_system: compressed.exit_code_system,
+        exit_code_user: compressed.exit_code_user,
+    };
+    assert_eq!(
+        verify_risc0(&PROVER_CONSTANTS_V3_0_3, &compressed.proof, &claim),
+        Ok(true)
+    );
+}
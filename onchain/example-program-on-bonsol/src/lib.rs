@@ -1,5 +1,5 @@
 use bonsol_interface::callback::{handle_callback, BonsolCallback};
-use bonsol_interface::instructions::{execute_v1, CallbackConfig, ExecutionConfig, InputRef};
+use bonsol_interface::instructions::{CallbackConfig, ExecutionRequestBuilder, InputRef};
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
@@ -62,23 +62,14 @@ fn main<'a>(
             )?;
             let tip = 1000;
             let expiration = Clock::get()?.slot + expiration; //high expiration since we run this on potatoes in CI
-            let ix = execute_v1(
-                requester.key,
-                payer.key,
-                SIMPLE_IMAGE_ID,
-                execution_id,
-                vec![
-                    InputRef::public("{\"attestation\":\"test\"}".as_bytes()),
-                    InputRef::private(private_input_url),
-                ],
-                tip,
-                expiration,
-                ExecutionConfig {
-                    verify_input_hash: true,
-                    input_hash: Some(input_hash),
-                    forward_output: true,
-                },
-                Some(CallbackConfig {
+            let ix = ExecutionRequestBuilder::new(SIMPLE_IMAGE_ID, execution_id)
+                .input(InputRef::public("{\"attestation\":\"test\"}".as_bytes()))
+                .input(InputRef::private(private_input_url))
+                .input_digest(input_hash)?
+                .tip(tip)
+                .expiry(expiration)?
+                .forward_output(true)
+                .callback(CallbackConfig {
                     program_id: crate::id(),
                     instruction_prefix: vec![1],
                     extra_accounts: vec![
@@ -87,11 +78,8 @@ fn main<'a>(
                         AccountMeta::new_readonly(EA2, false),
                         AccountMeta::new_readonly(EA3, false),
                     ],
-                }),
-                None,
-                vec![],
-            )
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
+                })?
+                .instruction(requester.key, payer.key)?;
             invoke_signed(&ix, accounts, &[&[execution_id.as_bytes(), &[bump]]])?;
             let mut data = requester.try_borrow_mut_data()?;
             data.copy_from_slice(&execution_account.key.to_bytes());
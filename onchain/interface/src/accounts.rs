@@ -0,0 +1,492 @@
+use bonsol_schema::{
+    root_as_deploy_v1, root_as_execution_request_v1, DeployV1, ExecutionRequestV1, ExitCode,
+    InputType, ProgramInputType, ProofSystem, ProverVersion,
+};
+
+use crate::claim_state::ClaimStateV1;
+use crate::error::ClientError;
+use crate::prover_set::ProverSetV1;
+use crate::util::deployment_address;
+
+#[cfg(feature = "on-chain")]
+use solana_program::pubkey::Pubkey;
+
+#[cfg(not(feature = "on-chain"))]
+use solana_sdk::pubkey::Pubkey;
+
+const CLAIM_STATE_LEN: usize = std::mem::size_of::<ClaimStateV1>();
+
+/// The state of an execution account. A pending request holds the `ExecutionRequestV1`, once the
+/// request is settled, expired or cancelled the program shrinks it to a single exit code byte.
+pub enum ExecutionAccount<'a> {
+    Pending(ExecutionRequestV1<'a>),
+    Completed(ExitCode),
+}
+
+impl<'a> ExecutionAccount<'a> {
+    pub fn load(data: &'a [u8]) -> Result<Self, ClientError> {
+        if let [code] = data {
+            return exit_code(*code)
+                .map(ExecutionAccount::Completed)
+                .ok_or(ClientError::InvalidExecutionAccount);
+        }
+        root_as_execution_request_v1(data)
+            .map(ExecutionAccount::Pending)
+            .map_err(|_| ClientError::InvalidExecutionAccount)
+    }
+}
+
+/// Any account owned by the bonsol program, decoded into owned values for display and JSON.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
+pub enum BonsolAccount {
+    Deployment(Deployment),
+    ExecutionRequest(ExecutionRequest),
+    Completed {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "exitCode", serialize_with = "ser::display")
+        )]
+        exit_code: ExitCode,
+    },
+    Claim(Claim),
+    ProverSet(ProverSet),
+}
+
+impl BonsolAccount {
+    /// Decodes the data of a bonsol owned account. Only deployments can be told apart by their
+    /// address, the other layouts are recognised by their length or a successful verification.
+    pub fn decode(address: &Pubkey, data: &[u8]) -> Result<Self, ClientError> {
+        match data.len() {
+            1 => {
+                return exit_code(data[0])
+                    .map(|exit_code| BonsolAccount::Completed { exit_code })
+                    .ok_or(ClientError::InvalidExecutionAccount)
+            }
+            CLAIM_STATE_LEN => {
+                let claim = ClaimStateV1::load_claim_owned(data)?;
+                return Ok(BonsolAccount::Claim(Claim {
+                    claimer: Pubkey::from(claim.claimer),
+                    claimed_at: claim.claimed_at,
+                    block_commitment: claim.block_commitment,
+                }));
+            }
+            _ => {}
+        }
+        if let Ok(deploy) = root_as_deploy_v1(data) {
+            if deploy
+                .image_id()
+                .is_some_and(|id| deployment_address(id).0 == *address)
+            {
+                return Ok(BonsolAccount::Deployment(Deployment::from_flatbuffer(
+                    &deploy,
+                )));
+            }
+        }
+        if let Ok(ExecutionAccount::Pending(er)) = ExecutionAccount::load(data) {
+            if er.execution_id().is_some() && er.image_id().is_some() {
+                return Ok(BonsolAccount::ExecutionRequest(
+                    ExecutionRequest::from_flatbuffer(&er),
+                ));
+            }
+        }
+        if let Ok(set) = ProverSetV1::load(data) {
+            return Ok(BonsolAccount::ProverSet(ProverSet {
+                owner: Pubkey::from(*set.owner),
+                provers: set.provers.iter().map(|p| Pubkey::from(*p)).collect(),
+            }));
+        }
+        Err(ClientError::UnrecognizedAccount)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Deployment {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display"))]
+    pub owner: Pubkey,
+    pub image_id: String,
+    pub program_name: String,
+    pub url: String,
+    pub size: u64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug_seq"))]
+    pub inputs: Vec<ProgramInputType>,
+}
+
+impl Deployment {
+    pub fn from_flatbuffer(deploy: &DeployV1) -> Self {
+        Deployment {
+            owner: key(deploy.owner().map(|o| o.bytes())),
+            image_id: deploy.image_id().unwrap_or_default().to_string(),
+            program_name: deploy.program_name().unwrap_or_default().to_string(),
+            url: deploy.url().unwrap_or_default().to_string(),
+            size: deploy.size_(),
+            inputs: deploy
+                .inputs()
+                .map(|inputs| inputs.iter().collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExecutionRequest {
+    pub execution_id: String,
+    pub image_id: String,
+    pub tip: u64,
+    pub max_block_height: u64,
+    pub verify_input_hash: bool,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub input_digest: Option<Vec<u8>>,
+    pub forward_output: bool,
+    /// Empty for compact requests, their inputs are only in the execute transaction
+    pub inputs: Vec<Input>,
+    pub compact_storage: bool,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub request_digest: Option<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub prover_version: ProverVersion,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub proof_system: ProofSystem,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_display"))]
+    pub callback_program_id: Option<Pubkey>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub callback_instruction_prefix: Option<Vec<u8>>,
+    pub callback_extra_accounts: Vec<CallbackAccount>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display_seq"))]
+    pub authorized_provers: Vec<Pubkey>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_display"))]
+    pub prover_set: Option<Pubkey>,
+}
+
+impl ExecutionRequest {
+    pub fn from_flatbuffer(er: &ExecutionRequestV1) -> Self {
+        ExecutionRequest {
+            execution_id: er.execution_id().unwrap_or_default().to_string(),
+            image_id: er.image_id().unwrap_or_default().to_string(),
+            tip: er.tip(),
+            max_block_height: er.max_block_height(),
+            verify_input_hash: er.verify_input_hash(),
+            input_digest: er.input_digest().map(|d| d.bytes().to_vec()),
+            forward_output: er.forward_output(),
+            inputs: er
+                .input()
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .map(|i| Input {
+                            input_type: i.input_type(),
+                            data: i.data().map(|d| d.bytes().to_vec()).unwrap_or_default(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            compact_storage: er.compact_storage(),
+            request_digest: er.request_digest().map(|d| d.bytes().to_vec()),
+            prover_version: er.prover_version(),
+            proof_system: er.proof_system(),
+            callback_program_id: er.callback_program_id().map(|k| key(Some(k.bytes()))),
+            callback_instruction_prefix: er
+                .callback_instruction_prefix()
+                .map(|p| p.bytes().to_vec()),
+            callback_extra_accounts: er
+                .callback_extra_accounts()
+                .map(|accounts| {
+                    accounts
+                        .iter()
+                        .map(|a| CallbackAccount {
+                            pubkey: Pubkey::from(<[u8; 32]>::from(a.pubkey())),
+                            writable: a.writable() != 0,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            authorized_provers: er
+                .authorized_provers()
+                .map(|provers| {
+                    provers
+                        .iter()
+                        .map(|p| Pubkey::from(<[u8; 32]>::from(p.bytes())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            prover_set: er.prover_set().map(|k| key(Some(k.bytes()))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Input {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub input_type: InputType,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::hex"))]
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CallbackAccount {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display"))]
+    pub pubkey: Pubkey,
+    pub writable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Claim {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display"))]
+    pub claimer: Pubkey,
+    pub claimed_at: u64,
+    pub block_commitment: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ProverSet {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display"))]
+    pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display_seq"))]
+    pub provers: Vec<Pubkey>,
+}
+
+fn exit_code(code: u8) -> Option<ExitCode> {
+    match code {
+        0 => Some(ExitCode::Success),
+        1 => Some(ExitCode::VerifyError),
+        2 => Some(ExitCode::ProvingError),
+        3 => Some(ExitCode::InputError),
+        4 => Some(ExitCode::Expired),
+        _ => None,
+    }
+}
+
+// keys are stored as byte vectors, a malformed one decodes to the default key rather than failing
+fn key(bytes: Option<&[u8]>) -> Pubkey {
+    bytes
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+        .map(Pubkey::from)
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod ser {
+    use std::fmt::{Debug, Display};
+
+    use serde::ser::{SerializeSeq, Serializer};
+
+    pub fn display<S: Serializer, T: Display>(value: &T, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(value)
+    }
+
+    pub fn opt_display<S: Serializer, T: Display>(
+        value: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => s.collect_str(v),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn display_seq<S: Serializer, T: Display>(values: &[T], s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(Some(values.len()))?;
+        for v in values {
+            seq.serialize_element(&v.to_string())?;
+        }
+        seq.end()
+    }
+
+    pub fn debug<S: Serializer, T: Debug>(value: &T, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(&format_args!("{:?}", value))
+    }
+
+    pub fn debug_seq<S: Serializer, T: Debug>(values: &[T], s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(Some(values.len()))?;
+        for v in values {
+            seq.serialize_element(&format!("{:?}", v))?;
+        }
+        seq.end()
+    }
+
+    pub fn hex<S: Serializer>(value: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&hex::encode(value))
+    }
+
+    pub fn opt_hex<S: Serializer>(value: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => s.serialize_str(&hex::encode(v)),
+            None => s.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{deploy_v1, CallbackConfig, ExecutionRequestBuilder, InputRef};
+    use crate::util::execution_address;
+    use bonsol_schema::parse_ix_data;
+    use solana_sdk::instruction::AccountMeta;
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    fn deployment_data(owner: &Pubkey) -> Vec<u8> {
+        let ix = deploy_v1(
+            owner,
+            IMAGE_ID,
+            1024,
+            "simple",
+            "https://example.com/simple",
+            vec![ProgramInputType::Public, ProgramInputType::Private],
+        )
+        .unwrap();
+        let channel_ix = parse_ix_data(&ix.data).unwrap();
+        channel_ix.deploy_v1().unwrap().bytes().to_vec()
+    }
+
+    fn execution_data(callback_program: &Pubkey, extra: &Pubkey) -> Vec<u8> {
+        let requester = Pubkey::new_unique();
+        let ix = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .input(InputRef::public(b"{}"))
+            .input_digest(&[5u8; 32])
+            .unwrap()
+            .tip(12)
+            .expiry(100)
+            .unwrap()
+            .callback(CallbackConfig {
+                program_id: *callback_program,
+                instruction_prefix: vec![1],
+                extra_accounts: vec![AccountMeta::new(*extra, false)],
+            })
+            .unwrap()
+            .instruction(&requester, &requester)
+            .unwrap();
+        let channel_ix = parse_ix_data(&ix.data).unwrap();
+        channel_ix.execute_v1().unwrap().bytes().to_vec()
+    }
+
+    #[test]
+    fn test_decode_deployment() {
+        let owner = Pubkey::new_unique();
+        let data = deployment_data(&owner);
+        let account = BonsolAccount::decode(&deployment_address(IMAGE_ID).0, &data).unwrap();
+        assert_eq!(
+            account,
+            BonsolAccount::Deployment(Deployment {
+                owner,
+                image_id: IMAGE_ID.to_string(),
+                program_name: "simple".to_string(),
+                url: "https://example.com/simple".to_string(),
+                size: 1024,
+                inputs: vec![ProgramInputType::Public, ProgramInputType::Private],
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_execution_request() {
+        let callback_program = Pubkey::new_unique();
+        let extra = Pubkey::new_unique();
+        let data = execution_data(&callback_program, &extra);
+        let address = execution_address(&Pubkey::new_unique(), b"exec").0;
+        let BonsolAccount::ExecutionRequest(er) = BonsolAccount::decode(&address, &data).unwrap()
+        else {
+            panic!("expected an execution request");
+        };
+        assert_eq!(er.execution_id, "exec");
+        assert_eq!(er.tip, 12);
+        assert_eq!(er.max_block_height, 100);
+        assert_eq!(er.input_digest, Some(vec![5u8; 32]));
+        assert_eq!(er.inputs[0].input_type, InputType::PublicData);
+        assert_eq!(er.callback_program_id, Some(callback_program));
+        assert_eq!(
+            er.callback_extra_accounts,
+            vec![CallbackAccount {
+                pubkey: extra,
+                writable: true
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decode_completed() {
+        let address = Pubkey::new_unique();
+        assert_eq!(
+            BonsolAccount::decode(&address, &[4]).unwrap(),
+            BonsolAccount::Completed {
+                exit_code: ExitCode::Expired
+            }
+        );
+        assert!(matches!(
+            ExecutionAccount::load(&[0]),
+            Ok(ExecutionAccount::Completed(ExitCode::Success))
+        ));
+        assert!(BonsolAccount::decode(&address, &[9]).is_err());
+    }
+
+    #[test]
+    fn test_decode_claim() {
+        let claimer = Pubkey::new_unique();
+        let claim = ClaimStateV1::from_claim_ix(&claimer, 10, 20);
+        let data = bytemuck::bytes_of(&claim);
+        assert_eq!(
+            BonsolAccount::decode(&Pubkey::new_unique(), data).unwrap(),
+            BonsolAccount::Claim(Claim {
+                claimer,
+                claimed_at: 10,
+                block_commitment: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_prover_set() {
+        let owner = Pubkey::new_unique();
+        let prover = Pubkey::new_unique();
+        let data = ProverSetV1::to_bytes(&owner.to_bytes(), &[prover.to_bytes()]);
+        assert_eq!(
+            BonsolAccount::decode(&Pubkey::new_unique(), &data).unwrap(),
+            BonsolAccount::ProverSet(ProverSet {
+                owner,
+                provers: vec![prover],
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unrecognized() {
+        assert!(matches!(
+            BonsolAccount::decode(&Pubkey::new_unique(), &[]),
+            Err(ClientError::UnrecognizedAccount)
+        ));
+    }
+
+    #[test]
+    fn test_account_json() {
+        let json = serde_json::to_value(BonsolAccount::Completed {
+            exit_code: ExitCode::Success,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "completed", "exitCode": "Success"})
+        );
+
+        let data = execution_data(&Pubkey::new_unique(), &Pubkey::new_unique());
+        let account = BonsolAccount::decode(&Pubkey::new_unique(), &data).unwrap();
+        let json = serde_json::to_value(&account).unwrap();
+        assert_eq!(json["type"], "executionRequest");
+        assert_eq!(json["inputDigest"], hex::encode([5u8; 32]));
+        assert_eq!(json["inputs"][0]["inputType"], "PublicData");
+        assert_eq!(json["proofSystem"], "Risc0Groth16");
+    }
+}
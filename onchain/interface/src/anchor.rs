@@ -0,0 +1,256 @@
+//! Anchor bindings for calling into and being called back by bonsol, enabled with the `anchor`
+//! feature.
+//!
+//! ```ignore
+//! use bonsol_interface::anchor::{Bonsol, DeploymentAccount, ExecuteV1};
+//!
+//! #[derive(Accounts)]
+//! pub struct Request<'info> {
+//!     #[account(mut, seeds = [b"requester"], bump)]
+//!     pub requester: SystemAccount<'info>,
+//!     #[account(mut)]
+//!     pub payer: Signer<'info>,
+//!     /// CHECK: created by bonsol
+//!     #[account(mut)]
+//!     pub execution: UncheckedAccount<'info>,
+//!     pub deployment: Account<'info, DeploymentAccount>,
+//!     pub bonsol_program: Program<'info, Bonsol>,
+//!     pub system_program: Program<'info, System>,
+//! }
+//!
+//! bonsol_interface::anchor::execute_v1(
+//!     CpiContext::new_with_signer(ctx.accounts.bonsol_program.to_account_info(), accounts, seeds),
+//!     &request,
+//! )?;
+//! ```
+
+use std::ops::Deref;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::accounts::{Deployment, ExecutionAccount, ExecutionRequest};
+use crate::instructions::ExecutionRequestBuilder;
+use bonsol_schema::{root_as_deploy_v1, ExitCode};
+
+/// The bonsol program, for `Program<'info, Bonsol>`.
+#[derive(Debug, Clone)]
+pub struct Bonsol;
+
+impl Id for Bonsol {
+    fn id() -> Pubkey {
+        crate::ID
+    }
+}
+
+/// The accounts of an `ExecuteV1` cpi. Without a callback `callback_program` is the bonsol
+/// program itself.
+#[derive(Clone)]
+pub struct ExecuteV1<'info> {
+    pub requester: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub execution: AccountInfo<'info>,
+    pub deployment: AccountInfo<'info>,
+    pub callback_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for ExecuteV1<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(*self.requester.key, true),
+            AccountMeta::new(*self.payer.key, true),
+            AccountMeta::new(*self.execution.key, false),
+            AccountMeta::new_readonly(*self.deployment.key, false),
+            AccountMeta::new_readonly(*self.callback_program.key, false),
+            AccountMeta::new_readonly(*self.system_program.key, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for ExecuteV1<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.requester.clone(),
+            self.payer.clone(),
+            self.execution.clone(),
+            self.deployment.clone(),
+            self.callback_program.clone(),
+            self.system_program.clone(),
+        ]
+    }
+}
+
+/// Requests an execution through cpi. The accounts of the context must be the ones the request
+/// derives, a request with a callback to another program fails with `InvalidCallbackProgram`.
+pub fn execute_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, ExecuteV1<'info>>,
+    request: &ExecutionRequestBuilder,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let ix = request
+        .instruction_with_accounts(
+            accounts.requester.key,
+            accounts.payer.key,
+            accounts.execution.key,
+            accounts.deployment.key,
+        )
+        .map_err(ProgramError::from)?;
+    if ix.accounts[4].pubkey != *accounts.callback_program.key {
+        return Err(ProgramError::from(crate::error::ClientError::InvalidCallbackProgram).into());
+    }
+    invoke_signed(&ix, &ctx.to_account_infos(), ctx.signer_seeds).map_err(Into::into)
+}
+
+/// A deployment, for `Account<'info, DeploymentAccount>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeploymentAccount(pub Deployment);
+
+impl AccountDeserialize for DeploymentAccount {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let deploy = root_as_deploy_v1(buf).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        Ok(DeploymentAccount(Deployment::from_flatbuffer(&deploy)))
+    }
+}
+
+// bonsol owns the account, anchor never writes it back
+impl AccountSerialize for DeploymentAccount {}
+
+impl Owner for DeploymentAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl Deref for DeploymentAccount {
+    type Target = Deployment;
+
+    fn deref(&self) -> &Deployment {
+        &self.0
+    }
+}
+
+/// An execution account, for `Account<'info, ExecutionRequestAccount>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionRequestAccount {
+    Pending(ExecutionRequest),
+    Completed(ExitCode),
+}
+
+impl AccountDeserialize for ExecutionRequestAccount {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        match ExecutionAccount::load(buf).map_err(|_| ErrorCode::AccountDidNotDeserialize)? {
+            ExecutionAccount::Pending(er) => Ok(ExecutionRequestAccount::Pending(
+                ExecutionRequest::from_flatbuffer(&er),
+            )),
+            ExecutionAccount::Completed(code) => Ok(ExecutionRequestAccount::Completed(code)),
+        }
+    }
+}
+
+impl AccountSerialize for ExecutionRequestAccount {}
+
+impl Owner for ExecutionRequestAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::idl::build::IdlBuild for DeploymentAccount {}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::idl::build::IdlBuild for ExecutionRequestAccount {}
+
+/// The data bonsol appends to a callback's instruction prefix. Taken as the only argument of a
+/// callback instruction it reads the rest of the instruction data, so the prefix of the callback
+/// config is the instruction's discriminator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BonsolCallbackData(pub Vec<u8>);
+
+impl AnchorDeserialize for BonsolCallbackData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+        Ok(BonsolCallbackData(data))
+    }
+}
+
+impl AnchorSerialize for BonsolCallbackData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::idl::build::IdlBuild for BonsolCallbackData {}
+
+/// Validates a bonsol callback in an anchor instruction and evaluates to the
+/// `Result<BonsolCallback>` of `handle_callback`. The first field of the accounts struct must be
+/// the execution account, which bonsol signs the callback with.
+///
+/// ```ignore
+/// pub fn on_result(ctx: Context<OnResult>, data: BonsolCallbackData) -> Result<()> {
+///     let output = bonsol_callback!(ctx, IMAGE_ID, &ctx.accounts.state.execution, data)?;
+///     ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! bonsol_callback {
+    ($ctx:expr, $image_id:expr, $execution_account:expr, $data:expr) => {{
+        use ::anchor_lang::ToAccountInfos as _;
+        let infos = $ctx.accounts.to_account_infos();
+        $crate::callback::handle_callback($image_id, $execution_account, &infos, &$data.0)
+            .map_err(::anchor_lang::error::Error::from)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::deploy_v1;
+    use bonsol_schema::{parse_ix_data, ProgramInputType};
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    #[test]
+    fn test_deployment_account() {
+        let owner = Pubkey::new_unique();
+        let ix = deploy_v1(
+            &owner,
+            IMAGE_ID,
+            10,
+            "simple",
+            "https://example.com",
+            vec![ProgramInputType::Public],
+        )
+        .unwrap();
+        let data = parse_ix_data(&ix.data)
+            .unwrap()
+            .deploy_v1()
+            .unwrap()
+            .bytes()
+            .to_vec();
+        let account = DeploymentAccount::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(account.owner, owner);
+        assert_eq!(account.image_id, IMAGE_ID);
+        assert!(DeploymentAccount::try_deserialize(&mut [0u8; 3].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_execution_request_account() {
+        let completed = [ExitCode::Success as u8];
+        assert_eq!(
+            ExecutionRequestAccount::try_deserialize(&mut completed.as_slice()).unwrap(),
+            ExecutionRequestAccount::Completed(ExitCode::Success)
+        );
+    }
+
+    #[test]
+    fn test_callback_data_reads_to_end() {
+        let mut data = vec![1; 32];
+        data.extend_from_slice(b"output");
+        let parsed = BonsolCallbackData::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(parsed.0, data);
+    }
+}
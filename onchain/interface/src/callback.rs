@@ -42,6 +42,9 @@ pub fn handle_callback<'a>(
     if er.image_id() != Some(image_id) {
         return Err(ClientError::InvalidCallbackImageId.into());
     }
+    if stripped_data.len() < 32 {
+        return Err(ClientError::InvalidCallbackData.into());
+    }
     let (input_digest, committed_outputs) = stripped_data.split_at(32);
     Ok(BonsolCallback {
         input_digest,
@@ -0,0 +1,430 @@
+use bonsol_schema::{
+    parse_ix_data, ChannelInstructionIxType, ClaimV1, ProofSystem, ProverSetOp, ProverSetUpdateV1,
+    StatusTypes, StatusV1,
+};
+
+use crate::accounts::{Deployment, ExecutionRequest};
+use crate::error::ClientError;
+
+#[cfg(feature = "serde")]
+use crate::accounts::ser;
+
+#[cfg(feature = "on-chain")]
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+#[cfg(not(feature = "on-chain"))]
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+/// An account the program reads at a fixed position, with the flags it requires of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountRole {
+    pub name: &'static str,
+    pub signer: bool,
+    pub writable: bool,
+}
+
+const fn role(name: &'static str, signer: bool, writable: bool) -> AccountRole {
+    AccountRole {
+        name,
+        signer,
+        writable,
+    }
+}
+
+// The positional accounts of each instruction as the program reads them. Accounts past the end
+// are the callback's extra accounts for execute and status, and the prover set for claims.
+pub const EXECUTE_V1_ACCOUNTS: &[AccountRole] = &[
+    role("requester", true, true),
+    role("payer", true, true),
+    role("execution", false, true),
+    role("deployment", false, false),
+    role("callbackProgram", false, false),
+    role("systemProgram", false, false),
+];
+pub const STATUS_V1_ACCOUNTS: &[AccountRole] = &[
+    role("requester", false, true),
+    role("execution", false, true),
+    role("callbackProgram", false, false),
+    role("prover", true, true),
+];
+pub const DEPLOY_V1_ACCOUNTS: &[AccountRole] = &[
+    role("deployer", true, true),
+    role("payer", true, true),
+    role("deployment", false, true),
+    role("systemProgram", false, false),
+];
+pub const CLAIM_V1_ACCOUNTS: &[AccountRole] = &[
+    role("execution", false, true),
+    role("requester", false, false),
+    role("executionClaim", false, true),
+    role("claimer", true, true),
+    role("payer", true, true),
+    role("systemProgram", false, false),
+];
+pub const PROVER_SET_UPDATE_V1_ACCOUNTS: &[AccountRole] = &[
+    role("owner", true, true),
+    role("payer", true, true),
+    role("proverSet", false, true),
+    role("systemProgram", false, false),
+];
+
+/// A bonsol instruction with its payload decoded and its accounts named.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DecodedInstruction {
+    pub instruction: BonsolInstruction,
+    pub accounts: Vec<InstructionAccount>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum BonsolInstruction {
+    ExecuteV1(ExecutionRequest),
+    StatusV1(StatusInstruction),
+    DeployV1(Deployment),
+    ClaimV1(ClaimInstruction),
+    ProverSetUpdateV1(ProverSetUpdate),
+}
+
+impl BonsolInstruction {
+    /// Names of the accounts the program expects, in order.
+    pub fn account_roles(&self) -> &'static [AccountRole] {
+        match self {
+            BonsolInstruction::ExecuteV1(_) => EXECUTE_V1_ACCOUNTS,
+            BonsolInstruction::StatusV1(_) => STATUS_V1_ACCOUNTS,
+            BonsolInstruction::DeployV1(_) => DEPLOY_V1_ACCOUNTS,
+            BonsolInstruction::ClaimV1(_) => CLAIM_V1_ACCOUNTS,
+            BonsolInstruction::ProverSetUpdateV1(_) => PROVER_SET_UPDATE_V1_ACCOUNTS,
+        }
+    }
+
+    fn extra_account_role(&self) -> &'static str {
+        match self {
+            BonsolInstruction::ExecuteV1(_) | BonsolInstruction::StatusV1(_) => {
+                "callbackExtraAccount"
+            }
+            BonsolInstruction::ClaimV1(_) => "proverSet",
+            _ => "unused",
+        }
+    }
+}
+
+/// An instruction account with the role its position has in the program. The signer and
+/// writable flags are unknown when only the keys were given.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct InstructionAccount {
+    pub role: &'static str,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display"))]
+    pub pubkey: Pubkey,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub is_signer: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub is_writable: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct StatusInstruction {
+    pub execution_id: String,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub status: StatusTypes,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub proof: Option<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub execution_digest: Option<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub input_digest: Option<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub committed_outputs: Option<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::opt_hex"))]
+    pub assumption_digest: Option<Vec<u8>>,
+    pub exit_code_system: u32,
+    pub exit_code_user: u32,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub proof_system: ProofSystem,
+}
+
+impl StatusInstruction {
+    pub fn from_flatbuffer(status: &StatusV1) -> Self {
+        let bytes = |v: Option<flatbuffers::Vector<u8>>| v.map(|v| v.bytes().to_vec());
+        StatusInstruction {
+            execution_id: status.execution_id().unwrap_or_default().to_string(),
+            status: status.status(),
+            proof: bytes(status.proof()),
+            execution_digest: bytes(status.execution_digest()),
+            input_digest: bytes(status.input_digest()),
+            committed_outputs: bytes(status.committed_outputs()),
+            assumption_digest: bytes(status.assumption_digest()),
+            exit_code_system: status.exit_code_system(),
+            exit_code_user: status.exit_code_user(),
+            proof_system: status.proof_system(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ClaimInstruction {
+    pub execution_id: String,
+    pub block_commitment: u64,
+}
+
+impl ClaimInstruction {
+    pub fn from_flatbuffer(claim: &ClaimV1) -> Self {
+        ClaimInstruction {
+            execution_id: claim.execution_id().unwrap_or_default().to_string(),
+            block_commitment: claim.block_commitment(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ProverSetUpdate {
+    pub set_id: String,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::debug"))]
+    pub op: ProverSetOp,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "ser::display_seq"))]
+    pub provers: Vec<Pubkey>,
+}
+
+impl ProverSetUpdate {
+    pub fn from_flatbuffer(update: &ProverSetUpdateV1) -> Self {
+        ProverSetUpdate {
+            set_id: update.set_id().unwrap_or_default().to_string(),
+            op: update.op(),
+            provers: update
+                .provers()
+                .map(|provers| {
+                    provers
+                        .iter()
+                        .map(|p| Pubkey::from(<[u8; 32]>::from(p.bytes())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Decodes the data of a bonsol instruction, the nested payload is verified like the program
+/// does before any field is read.
+pub fn decode_instruction_data(data: &[u8]) -> Result<BonsolInstruction, ClientError> {
+    let ix = parse_ix_data(data).map_err(|_| ClientError::UnrecognizedInstruction)?;
+    let decoded = match ix.ix_type() {
+        ChannelInstructionIxType::ExecuteV1 => ix
+            .execute_v1_verified()
+            .map(|er| BonsolInstruction::ExecuteV1(ExecutionRequest::from_flatbuffer(&er))),
+        ChannelInstructionIxType::StatusV1 => ix
+            .status_v1_verified()
+            .map(|st| BonsolInstruction::StatusV1(StatusInstruction::from_flatbuffer(&st))),
+        ChannelInstructionIxType::DeployV1 => ix
+            .deploy_v1_verified()
+            .map(|d| BonsolInstruction::DeployV1(Deployment::from_flatbuffer(&d))),
+        ChannelInstructionIxType::ClaimV1 => ix
+            .claim_v1_verified()
+            .map(|c| BonsolInstruction::ClaimV1(ClaimInstruction::from_flatbuffer(&c))),
+        ChannelInstructionIxType::ProverSetUpdateV1 => ix
+            .prover_set_update_v1_verified()
+            .map(|u| BonsolInstruction::ProverSetUpdateV1(ProverSetUpdate::from_flatbuffer(&u))),
+        _ => None,
+    };
+    decoded.ok_or(ClientError::UnrecognizedInstruction)
+}
+
+/// Decodes a bonsol instruction and names its accounts.
+pub fn decode_instruction(ix: &Instruction) -> Result<DecodedInstruction, ClientError> {
+    if ix.program_id != crate::ID {
+        return Err(ClientError::UnrecognizedInstruction);
+    }
+    let instruction = decode_instruction_data(&ix.data)?;
+    let accounts = name_accounts(
+        &instruction,
+        ix.accounts
+            .iter()
+            .map(|a| (a.pubkey, Some(a.is_signer), Some(a.is_writable))),
+    );
+    Ok(DecodedInstruction {
+        instruction,
+        accounts,
+    })
+}
+
+/// Decodes instruction data given only the account keys, as when they are copied from an explorer.
+pub fn decode_instruction_with_keys(
+    data: &[u8],
+    keys: &[Pubkey],
+) -> Result<DecodedInstruction, ClientError> {
+    let instruction = decode_instruction_data(data)?;
+    let accounts = name_accounts(&instruction, keys.iter().map(|k| (*k, None, None)));
+    Ok(DecodedInstruction {
+        instruction,
+        accounts,
+    })
+}
+
+fn name_accounts(
+    instruction: &BonsolInstruction,
+    accounts: impl Iterator<Item = (Pubkey, Option<bool>, Option<bool>)>,
+) -> Vec<InstructionAccount> {
+    let roles = instruction.account_roles();
+    accounts
+        .enumerate()
+        .map(|(i, (pubkey, is_signer, is_writable))| InstructionAccount {
+            role: roles
+                .get(i)
+                .map(|r| r.name)
+                .unwrap_or_else(|| instruction.extra_account_role()),
+            pubkey,
+            is_signer,
+            is_writable,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::{
+        add_provers_v1, deploy_v1, CallbackConfig, ExecutionRequestBuilder, InputRef,
+    };
+    use bonsol_schema::ProgramInputType;
+    use solana_sdk::instruction::AccountMeta;
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    #[test]
+    fn test_decode_execute() {
+        let requester = Pubkey::new_unique();
+        let extra = Pubkey::new_unique();
+        let mut ix = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .input(InputRef::public(b"{}"))
+            .verify_input_hash(false)
+            .tip(7)
+            .expiry(99)
+            .unwrap()
+            .callback(CallbackConfig {
+                program_id: Pubkey::new_unique(),
+                instruction_prefix: vec![1],
+                extra_accounts: vec![AccountMeta::new(extra, false)],
+            })
+            .unwrap()
+            .instruction(&requester, &requester)
+            .unwrap();
+        ix.accounts.push(AccountMeta::new(extra, false));
+        let decoded = decode_instruction(&ix).unwrap();
+        let BonsolInstruction::ExecuteV1(er) = &decoded.instruction else {
+            panic!("expected an execute instruction");
+        };
+        assert_eq!(er.execution_id, "exec");
+        assert_eq!(er.tip, 7);
+        assert_eq!(er.max_block_height, 99);
+        let roles: Vec<&str> = decoded.accounts.iter().map(|a| a.role).collect();
+        assert_eq!(
+            roles,
+            [
+                "requester",
+                "payer",
+                "execution",
+                "deployment",
+                "callbackProgram",
+                "systemProgram",
+                "callbackExtraAccount"
+            ]
+        );
+        assert_eq!(decoded.accounts[0].is_signer, Some(true));
+        assert_eq!(decoded.accounts[6].pubkey, extra);
+    }
+
+    #[test]
+    fn test_decode_deploy_with_keys() {
+        let owner = Pubkey::new_unique();
+        let ix = deploy_v1(
+            &owner,
+            IMAGE_ID,
+            10,
+            "simple",
+            "https://example.com",
+            vec![ProgramInputType::Public],
+        )
+        .unwrap();
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|a| a.pubkey).collect();
+        let decoded = decode_instruction_with_keys(&ix.data, &keys).unwrap();
+        assert!(matches!(
+            &decoded.instruction,
+            BonsolInstruction::DeployV1(d) if d.image_id == IMAGE_ID && d.owner == owner
+        ));
+        assert_eq!(decoded.accounts[2].role, "deployment");
+        assert_eq!(decoded.accounts[2].is_writable, None);
+    }
+
+    #[test]
+    fn test_decode_prover_set_update() {
+        let owner = Pubkey::new_unique();
+        let prover = Pubkey::new_unique();
+        let ix = add_provers_v1(&owner, &owner, "set", &[prover]).unwrap();
+        let decoded = decode_instruction(&ix).unwrap();
+        assert_eq!(
+            decoded.instruction,
+            BonsolInstruction::ProverSetUpdateV1(ProverSetUpdate {
+                set_id: "set".to_string(),
+                op: ProverSetOp::Add,
+                provers: vec![prover],
+            })
+        );
+        assert_eq!(decoded.accounts[2].role, "proverSet");
+    }
+
+    #[test]
+    fn test_roles_match_instruction_builders() {
+        let owner = Pubkey::new_unique();
+        let execute = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .verify_input_hash(false)
+            .expiry(1)
+            .unwrap()
+            .instruction(&owner, &owner)
+            .unwrap();
+        let deploy =
+            deploy_v1(&owner, IMAGE_ID, 1, "simple", "https://example.com", vec![]).unwrap();
+        let prover_set = add_provers_v1(&owner, &owner, "set", &[owner]).unwrap();
+        for (ix, roles) in [
+            (execute, EXECUTE_V1_ACCOUNTS),
+            (deploy, DEPLOY_V1_ACCOUNTS),
+            (prover_set, PROVER_SET_UPDATE_V1_ACCOUNTS),
+        ] {
+            assert_eq!(ix.accounts.len(), roles.len());
+            for (meta, role) in ix.accounts.iter().zip(roles) {
+                assert_eq!(meta.is_signer, role.signer, "{}", role.name);
+                assert_eq!(meta.is_writable, role.writable, "{}", role.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_other_programs() {
+        let ix = Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]);
+        assert!(matches!(
+            decode_instruction(&ix),
+            Err(ClientError::UnrecognizedInstruction)
+        ));
+        assert!(decode_instruction_data(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_decoded_json() {
+        let owner = Pubkey::new_unique();
+        let ix = add_provers_v1(&owner, &owner, "set", &[owner]).unwrap();
+        let json = serde_json::to_value(decode_instruction(&ix).unwrap()).unwrap();
+        assert_eq!(json["instruction"]["type"], "ProverSetUpdateV1");
+        assert_eq!(json["instruction"]["op"], "Add");
+        assert_eq!(json["accounts"][0]["role"], "owner");
+        assert_eq!(json["accounts"][0]["pubkey"], owner.to_string());
+        assert_eq!(json["accounts"][0]["isSigner"], true);
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(feature = "on-chain")]
 use solana_program::program_error::ProgramError;
 
-#[cfg(not(feature = "on-chain"))]
+#[cfg(all(not(feature = "on-chain"), feature = "solana-sdk"))]
 use solana_sdk::program_error::ProgramError;
 
 #[derive(thiserror::Error, Debug)]
@@ -24,10 +24,40 @@ pub enum ClientError {
     InvalidCallbackImageId,
     #[error("Execution Request Reused")]
     ExecutionRequestReused,
+    #[error("InvalidProverSetAccount")]
+    InvalidProverSetAccount,
+    #[error("InputDigestRequired")]
+    InputDigestRequired,
+    #[error("InvalidInputDigest")]
+    InvalidInputDigest,
+    #[error("MaxBlockHeightRequired")]
+    MaxBlockHeightRequired,
+    #[error("CallbackInstructionPrefixRequired")]
+    CallbackInstructionPrefixRequired,
+    #[error("TooManyCallbackExtraAccounts")]
+    TooManyCallbackExtraAccounts,
+    #[error("InvalidExecutionAccount")]
+    InvalidExecutionAccount,
+    #[error("UnrecognizedAccount")]
+    UnrecognizedAccount,
+    #[error("UnrecognizedInstruction")]
+    UnrecognizedInstruction,
+    #[error("InstructionBufferTooSmall")]
+    InstructionBufferTooSmall,
+    #[error("UnsupportedProofSystem")]
+    UnsupportedProofSystem,
 }
 
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
 impl From<ClientError> for ProgramError {
     fn from(val: ClientError) -> Self {
         ProgramError::Custom(val as u32)
     }
 }
+
+#[cfg(feature = "pinocchio")]
+impl From<ClientError> for pinocchio::program_error::ProgramError {
+    fn from(val: ClientError) -> Self {
+        pinocchio::program_error::ProgramError::Custom(val as u32)
+    }
+}
@@ -0,0 +1,147 @@
+//! An Anchor style IDL of the bonsol program, generated from the account layouts in `decoder`.
+//!
+//! Bonsol instructions carry a `ChannelInstruction` flatbuffer rather than borsh arguments, so the
+//! IDL has no discriminators or args. It describes which accounts each instruction takes, in what
+//! order and with which flags, for explorers and Anchor clients that only need the layouts. The
+//! checked in copy lives at `onchain/interface/idl/bonsol.json`.
+
+use serde::Serialize;
+
+use crate::decoder::{
+    AccountRole, CLAIM_V1_ACCOUNTS, DEPLOY_V1_ACCOUNTS, EXECUTE_V1_ACCOUNTS,
+    PROVER_SET_UPDATE_V1_ACCOUNTS, STATUS_V1_ACCOUNTS,
+};
+
+pub const IDL_SPEC: &str = "0.1.0";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Idl {
+    pub address: String,
+    pub metadata: IdlMetadata,
+    pub instructions: Vec<IdlInstruction>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdlMetadata {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub spec: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdlInstruction {
+    pub name: &'static str,
+    pub docs: Vec<&'static str>,
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<IdlAccount>,
+    pub args: Vec<()>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub writable: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub signer: bool,
+}
+
+pub fn idl() -> Idl {
+    Idl {
+        address: crate::ID.to_string(),
+        metadata: IdlMetadata {
+            name: "bonsol",
+            version: env!("CARGO_PKG_VERSION"),
+            spec: IDL_SPEC,
+            description: "Verifiable off-chain computation for Solana",
+        },
+        instructions: vec![
+            instruction(
+                "execute_v1",
+                "Requests an execution of a deployed image. Followed by the callback's extra accounts.",
+                EXECUTE_V1_ACCOUNTS,
+            ),
+            instruction(
+                "status_v1",
+                "Submits the proof of a claimed execution. Followed by the callback's extra accounts.",
+                STATUS_V1_ACCOUNTS,
+            ),
+            instruction(
+                "deploy_v1",
+                "Registers an image under its image id.",
+                DEPLOY_V1_ACCOUNTS,
+            ),
+            instruction(
+                "claim_v1",
+                "Claims an execution for a prover. Followed by the request's prover set, if any.",
+                CLAIM_V1_ACCOUNTS,
+            ),
+            instruction(
+                "prover_set_update_v1",
+                "Adds provers to or removes them from a requester's prover set.",
+                PROVER_SET_UPDATE_V1_ACCOUNTS,
+            ),
+        ],
+    }
+}
+
+fn instruction(name: &'static str, doc: &'static str, accounts: &[AccountRole]) -> IdlInstruction {
+    IdlInstruction {
+        name,
+        docs: vec![
+            doc,
+            "Instruction data is a ChannelInstruction flatbuffer, build it with bonsol-interface.",
+        ],
+        discriminator: vec![],
+        accounts: accounts
+            .iter()
+            .map(|role| IdlAccount {
+                name: snake_case(role.name),
+                writable: role.writable,
+                signer: role.signer,
+            })
+            .collect(),
+        args: vec![],
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDL_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/idl/bonsol.json");
+
+    /// Run with `BONSOL_UPDATE_IDL=1` to rewrite the checked in IDL after changing a layout.
+    #[test]
+    fn test_idl_is_up_to_date() {
+        let generated = serde_json::to_string_pretty(&idl()).unwrap() + "\n";
+        if std::env::var_os("BONSOL_UPDATE_IDL").is_some() {
+            std::fs::write(IDL_PATH, &generated).unwrap();
+        }
+        let checked_in = std::fs::read_to_string(IDL_PATH).unwrap();
+        assert_eq!(
+            checked_in, generated,
+            "idl/bonsol.json is stale, rerun with BONSOL_UPDATE_IDL=1"
+        );
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("executionClaim"), "execution_claim");
+        assert_eq!(snake_case("payer"), "payer");
+    }
+}
@@ -1,12 +1,15 @@
 use bonsol_schema::{
     execution_request_v1_generated, Account, ChannelInstruction, ChannelInstructionArgs,
     ChannelInstructionIxType, DeployV1, DeployV1Args, ExecutionRequestV1, ExecutionRequestV1Args,
-    InputBuilder, InputType, ProgramInputType, ProverVersion, PublicKey,
+    ExecutionRequestV1T, InputBuilder, InputType, ProgramInputType, ProofSystem, ProverSetOp,
+    ProverSetUpdateV1, ProverSetUpdateV1Args, ProverVersion, PublicKey,
 };
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 
 use crate::error::ClientError;
-use crate::util::{deployment_address, execution_address};
+use crate::prover_set::MAX_PROVER_SET_ID_LEN;
+use crate::util::{deployment_address, execution_address, prover_set_address};
+use crate::{is_supported_proof_system, MAX_CALLBACK_EXTRA_ACCOUNTS};
 
 #[cfg(feature = "on-chain")]
 use {
@@ -77,6 +80,15 @@ pub struct ExecutionConfig<'a> {
     pub verify_input_hash: bool,
     pub input_hash: Option<&'a [u8]>,
     pub forward_output: bool,
+    /// The zkvm and proof wrapping the prover must use, nodes without a matching backend will not claim
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub proof_system: ProofSystem,
+    /// A `ProverSetV1` account, provers in it may claim alongside any `authorized_provers`
+    #[cfg_attr(feature = "serde", serde(default, with = "serde_helpers::optpubkey"))]
+    pub prover_set: Option<Pubkey>,
+    /// Store the request without its inputs to save rent, the inputs stay in the transaction
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compact_storage: bool,
 }
 
 #[cfg(feature = "serde")]
@@ -85,7 +97,8 @@ pub mod serde_helpers {
         use std::str::FromStr;
 
         use serde::{self, Deserialize, Deserializer, Serializer};
-        use solana_sdk::pubkey::Pubkey;
+
+        use super::super::Pubkey;
 
         pub fn serialize<S>(value: &Pubkey, serializer: S) -> Result<S::Ok, S::Error>
         where
@@ -107,7 +120,8 @@ pub mod serde_helpers {
         use std::str::FromStr;
 
         use serde::{self, Deserialize, Deserializer, Serializer};
-        use solana_sdk::pubkey::Pubkey;
+
+        use super::super::Pubkey;
 
         pub fn serialize<S>(value: &Option<Pubkey>, serializer: S) -> Result<S::Ok, S::Error>
         where
@@ -123,10 +137,9 @@ pub mod serde_helpers {
         where
             D: Deserializer<'de>,
         {
-            let s = String::deserialize(deserializer)?;
-            Pubkey::from_str(&s)
-                .map_err(serde::de::Error::custom)
-                .map(Some)
+            Option::<String>::deserialize(deserializer)?
+                .map(|s| Pubkey::from_str(&s).map_err(serde::de::Error::custom))
+                .transpose()
         }
     }
 }
@@ -146,6 +159,9 @@ impl Default for ExecutionConfig<'_> {
             verify_input_hash: true,
             input_hash: None,
             forward_output: false,
+            proof_system: ProofSystem::Risc0Groth16,
+            prover_set: None,
+            compact_storage: false,
         }
     }
 }
@@ -160,6 +176,7 @@ pub struct CallbackConfig {
     pub extra_accounts: Vec<AccountMeta>,
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct InputRef<'a> {
     pub input_type: InputType,
     pub data: &'a [u8],
@@ -202,13 +219,373 @@ impl<'a> InputRef<'a> {
     }
 }
 
+/// Builds an execution request field by field. Setters reject values the program would refuse as
+/// soon as they are given, so a request that builds is one the program will accept the shape of.
+///
+/// ```ignore
+/// let ix = ExecutionRequestBuilder::new(image_id, execution_id)
+///     .input(InputRef::public(b"{}"))
+///     .input_digest(&digest)?
+///     .tip(1000)
+///     .expiry(slot + 100)?
+///     .instruction(&requester, &payer)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecutionRequestBuilder<'a> {
+    image_id: &'a str,
+    execution_id: &'a str,
+    inputs: Vec<InputRef<'a>>,
+    tip: u64,
+    expiry: u64,
+    verify_input_hash: bool,
+    input_digest: Option<&'a [u8]>,
+    forward_output: bool,
+    proof_system: ProofSystem,
+    prover_set: Option<Pubkey>,
+    compact_storage: bool,
+    callback: Option<CallbackConfig>,
+    prover_version: Option<ProverVersion>,
+    authorized_provers: Vec<Pubkey>,
+}
+
+impl<'a> ExecutionRequestBuilder<'a> {
+    /// Starts a request with the defaults of `ExecutionConfig`, the input digest is verified so
+    /// either `input_digest` or `verify_input_hash(false)` must be set before building.
+    pub fn new(image_id: &'a str, execution_id: &'a str) -> Self {
+        let config = ExecutionConfig::default();
+        ExecutionRequestBuilder {
+            image_id,
+            execution_id,
+            inputs: Vec::new(),
+            tip: 0,
+            expiry: 0,
+            verify_input_hash: config.verify_input_hash,
+            input_digest: None,
+            forward_output: config.forward_output,
+            proof_system: config.proof_system,
+            prover_set: config.prover_set,
+            compact_storage: config.compact_storage,
+            callback: None,
+            prover_version: None,
+            authorized_provers: Vec::new(),
+        }
+    }
+
+    /// Starts from a decoded request, such as the JSON form of `ExecutionRequestV1T` that the cli
+    /// and other tools share, running every field through the same checks as the setters. The
+    /// `request_digest` is set by the program and ignored.
+    pub fn from_request(request: &'a ExecutionRequestV1T) -> Result<Self, ClientError> {
+        let (Some(image_id), Some(execution_id)) = (&request.image_id, &request.execution_id)
+        else {
+            return Err(ClientError::InvalidInput);
+        };
+        let mut builder = ExecutionRequestBuilder::new(image_id, execution_id)
+            .inputs(request.input.iter().flatten().map(|input| {
+                InputRef::new(input.input_type, input.data.as_deref().unwrap_or_default())
+            }))
+            .tip(request.tip)
+            .forward_output(request.forward_output)
+            .proof_system(request.proof_system)?
+            .compact_storage(request.compact_storage)
+            .prover_version(request.prover_version)
+            .authorized_provers(
+                request
+                    .authorized_provers
+                    .iter()
+                    .flatten()
+                    .map(|key| Pubkey::new_from_array(key.bytes))
+                    .collect(),
+            );
+        if request.max_block_height != 0 {
+            builder = builder.expiry(request.max_block_height)?;
+        }
+        if let Some(digest) = &request.input_digest {
+            builder = builder.input_digest(digest)?;
+        }
+        builder = builder.verify_input_hash(request.verify_input_hash);
+        if let Some(prover_set) = &request.prover_set {
+            builder = builder.prover_set(
+                Pubkey::try_from(prover_set.as_slice())
+                    .map_err(|_| ClientError::InvalidProverSetAccount)?,
+            );
+        }
+        if let Some(program_id) = &request.callback_program_id {
+            builder = builder.callback(CallbackConfig {
+                program_id: Pubkey::try_from(program_id.as_slice())
+                    .map_err(|_| ClientError::InvalidCallbackProgram)?,
+                instruction_prefix: request
+                    .callback_instruction_prefix
+                    .clone()
+                    .unwrap_or_default(),
+                extra_accounts: request
+                    .callback_extra_accounts
+                    .iter()
+                    .flatten()
+                    .map(|a| AccountMeta {
+                        pubkey: Pubkey::new_from_array(a.pubkey),
+                        is_signer: false,
+                        is_writable: a.writable != 0,
+                    })
+                    .collect(),
+            })?;
+        }
+        Ok(builder)
+    }
+
+    pub fn input(mut self, input: InputRef<'a>) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    pub fn inputs(mut self, inputs: impl IntoIterator<Item = InputRef<'a>>) -> Self {
+        self.inputs.extend(inputs);
+        self
+    }
+
+    pub fn tip(mut self, tip: u64) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /// The slot after which the request can no longer be claimed.
+    pub fn expiry(mut self, max_block_height: u64) -> Result<Self, ClientError> {
+        if max_block_height == 0 {
+            return Err(ClientError::MaxBlockHeightRequired);
+        }
+        self.expiry = max_block_height;
+        Ok(self)
+    }
+
+    /// The `input_digest` of the inputs as the guest reads them, this also turns on verification.
+    pub fn input_digest(mut self, digest: &'a [u8]) -> Result<Self, ClientError> {
+        if digest.len() != 32 {
+            return Err(ClientError::InvalidInputDigest);
+        }
+        self.verify_input_hash = true;
+        self.input_digest = Some(digest);
+        Ok(self)
+    }
+
+    pub fn verify_input_hash(mut self, verify_input_hash: bool) -> Self {
+        self.verify_input_hash = verify_input_hash;
+        self
+    }
+
+    pub fn forward_output(mut self, forward_output: bool) -> Self {
+        self.forward_output = forward_output;
+        self
+    }
+
+    /// The proof system nodes must prove with, one the program has no verifier for is rejected.
+    pub fn proof_system(mut self, proof_system: ProofSystem) -> Result<Self, ClientError> {
+        if !is_supported_proof_system(proof_system) {
+            return Err(ClientError::UnsupportedProofSystem);
+        }
+        self.proof_system = proof_system;
+        Ok(self)
+    }
+
+    pub fn prover_set(mut self, prover_set: Pubkey) -> Self {
+        self.prover_set = Some(prover_set);
+        self
+    }
+
+    pub fn compact_storage(mut self, compact_storage: bool) -> Self {
+        self.compact_storage = compact_storage;
+        self
+    }
+
+    /// The callback is invoked with the prefix as its instruction discriminator, and extra
+    /// accounts are passed through as they are stored, so none of them can be a signer.
+    pub fn callback(mut self, callback: CallbackConfig) -> Result<Self, ClientError> {
+        if callback.instruction_prefix.is_empty() {
+            return Err(ClientError::CallbackInstructionPrefixRequired);
+        }
+        if callback.extra_accounts.len() > MAX_CALLBACK_EXTRA_ACCOUNTS {
+            return Err(ClientError::TooManyCallbackExtraAccounts);
+        }
+        if callback.extra_accounts.iter().any(|a| a.is_signer) {
+            return Err(ClientError::InvalidCallbackExtraAccounts);
+        }
+        self.callback = Some(callback);
+        Ok(self)
+    }
+
+    pub fn prover_version(mut self, prover_version: ProverVersion) -> Self {
+        self.prover_version = Some(prover_version);
+        self
+    }
+
+    pub fn authorized_provers(mut self, authorized_provers: Vec<Pubkey>) -> Self {
+        self.authorized_provers = authorized_provers;
+        self
+    }
+
+    /// Checks the fields that have no default and can only be judged once the request is complete.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        if self.verify_input_hash && self.input_digest.is_none() {
+            return Err(ClientError::InputDigestRequired);
+        }
+        if self.expiry == 0 {
+            return Err(ClientError::MaxBlockHeightRequired);
+        }
+        Ok(())
+    }
+
+    /// The `ExecuteV1` instruction with the execution and deployment accounts derived from the
+    /// requester and image id.
+    pub fn instruction(
+        &self,
+        requester: &Pubkey,
+        payer: &Pubkey,
+    ) -> Result<Instruction, ClientError> {
+        let (execution_account, _) = execution_address(requester, self.execution_id.as_bytes());
+        let (deployment_account, _) = deployment_address(self.image_id);
+        self.instruction_with_accounts(requester, payer, &execution_account, &deployment_account)
+    }
+
+    /// The `ExecuteV1` instruction for callers that already hold the derived accounts, saving the
+    /// pda searches on chain.
+    pub fn instruction_with_accounts(
+        &self,
+        requester: &Pubkey,
+        payer: &Pubkey,
+        execution_account: &Pubkey,
+        deployment_account: &Pubkey,
+    ) -> Result<Instruction, ClientError> {
+        Ok(Instruction::new_with_bytes(
+            crate::ID,
+            &self.instruction_data()?,
+            self.accounts(requester, payer, execution_account, deployment_account),
+        ))
+    }
+
+    /// The serialized `ChannelInstruction`, for programs that assemble the cpi themselves.
+    pub fn instruction_data(&self) -> Result<Vec<u8>, ClientError> {
+        self.validate()?;
+        Ok(self.encode())
+    }
+
+    fn accounts(
+        &self,
+        requester: &Pubkey,
+        payer: &Pubkey,
+        execution_account: &Pubkey,
+        deployment_account: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        let callback_program = self
+            .callback
+            .as_ref()
+            .map(|cb| cb.program_id)
+            .unwrap_or(crate::ID);
+        vec![
+            AccountMeta::new(*requester, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*execution_account, false),
+            AccountMeta::new_readonly(*deployment_account, false),
+            AccountMeta::new_readonly(callback_program, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ]
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let (callback_program_id, callback_instruction_prefix, extra_accounts) =
+            if let Some(cb) = &self.callback {
+                let cb_program_id = fbb.create_vector(cb.program_id.as_ref());
+                let cb_instruction_prefix = fbb.create_vector(cb.instruction_prefix.as_slice());
+                let ealen = cb.extra_accounts.len();
+                fbb.start_vector::<WIPOffset<Account>>(ealen);
+                for ea in cb.extra_accounts.iter().rev() {
+                    let pkbytes = arrayref::array_ref!(ea.pubkey.as_ref(), 0, 32);
+                    let eab = Account::new(ea.is_writable as u8, pkbytes);
+                    fbb.push(eab);
+                }
+                (
+                    Some(cb_program_id),
+                    Some(cb_instruction_prefix),
+                    Some(fbb.end_vector(ealen)),
+                )
+            } else {
+                (None, None, None)
+            };
+        let mut inputs_vec = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs.iter() {
+            let data_off = fbb.create_vector(input.data);
+            let mut ibb = InputBuilder::new(&mut fbb);
+            ibb.add_data(data_off);
+            ibb.add_input_type(input.input_type);
+            let input = ibb.finish();
+            inputs_vec.push(input);
+        }
+        let fb_inputs = fbb.create_vector(&inputs_vec);
+        let image_id = fbb.create_string(self.image_id);
+        let execution_id = fbb.create_string(self.execution_id);
+
+        let input_digest = self.input_digest.map(|ih| fbb.create_vector(ih));
+        let prover_set = self.prover_set.map(|ps| fbb.create_vector(ps.as_ref()));
+        let authorized_provers = if self.authorized_provers.is_empty() {
+            None
+        } else {
+            Some(
+                fbb.create_vector_from_iter(
+                    self.authorized_provers
+                        .iter()
+                        .map(|key| PublicKey::new(key.as_array())),
+                ),
+            )
+        };
+
+        // typically cli will pass None for the optional prover_version indicating bonsol should handle
+        // the default case here
+        let prover_version = self.prover_version.unwrap_or_default();
+        let fbb_execute = ExecutionRequestV1::create(
+            &mut fbb,
+            &ExecutionRequestV1Args {
+                tip: self.tip,
+                execution_id: Some(execution_id),
+                image_id: Some(image_id),
+                callback_program_id,
+                callback_instruction_prefix,
+                forward_output: self.forward_output,
+                verify_input_hash: self.verify_input_hash,
+                input: Some(fb_inputs),
+                max_block_height: self.expiry,
+                input_digest,
+                callback_extra_accounts: extra_accounts,
+                prover_version,
+                authorized_provers,
+                proof_system: self.proof_system,
+                prover_set,
+                compact_storage: self.compact_storage,
+                request_digest: None,
+            },
+        );
+        fbb.finish(fbb_execute, None);
+        let ix_data = fbb.finished_data();
+        let mut fbb = FlatBufferBuilder::new();
+        let ix = fbb.create_vector(ix_data);
+        let fbb_ix = ChannelInstruction::create(
+            &mut fbb,
+            &ChannelInstructionArgs {
+                ix_type: ChannelInstructionIxType::ExecuteV1,
+                execute_v1: Some(ix),
+                ..Default::default()
+            },
+        );
+        fbb.finish(fbb_ix, None);
+        fbb.finished_data().to_vec()
+    }
+}
+
 /// Executes a bonsol program.
-/// This sends and instruction to the bonsol program which requests execution from the bonsol network
+/// This sends and instruction to the bonsol program which requests execution from the bonsol network.
+/// Prefer `ExecutionRequestBuilder`, which names each field and checks it as it is set.
 pub fn execute_v1<'a>(
     requester: &Pubkey,
     payer: &Pubkey,
-    image_id: &str,
-    execution_id: &str,
+    image_id: &'a str,
+    execution_id: &'a str,
     inputs: Vec<InputRef<'a>>,
     tip: u64,
     expiration: u64,
@@ -243,104 +620,100 @@ pub fn execute_v1_with_accounts<'a>(
     payer: &Pubkey,
     execution_account: &Pubkey,
     deployment_account: &Pubkey,
-    image_id: &str,
-    execution_id: &str,
-    inputs: Vec<InputRef>,
+    image_id: &'a str,
+    execution_id: &'a str,
+    inputs: Vec<InputRef<'a>>,
     tip: u64,
     expiration: u64,
-    config: ExecutionConfig,
+    config: ExecutionConfig<'a>,
     callback: Option<CallbackConfig>,
     prover_version: Option<ProverVersion>,
     authorized_provers: Vec<Pubkey>,
 ) -> Result<Instruction, ClientError> {
     config.validate()?;
-    let mut fbb = FlatBufferBuilder::new();
-    let mut callback_pubkey = None; // avoid clone
-    let (callback_program_id, callback_instruction_prefix, extra_accounts) =
-        if let Some(cb) = callback {
-            callback_pubkey = Some(cb.program_id);
-            let cb_program_id = fbb.create_vector(cb.program_id.as_ref());
-            let cb_instruction_prefix = fbb.create_vector(cb.instruction_prefix.as_slice());
-            let ealen = cb.extra_accounts.len();
-            fbb.start_vector::<WIPOffset<Account>>(ealen);
-            for ea in cb.extra_accounts.iter().rev() {
-                let pkbytes = arrayref::array_ref!(ea.pubkey.as_ref(), 0, 32);
-                let eab = Account::new(ea.is_writable as u8, pkbytes);
-                fbb.push(eab);
-            }
-            (
-                Some(cb_program_id),
-                Some(cb_instruction_prefix),
-                Some(fbb.end_vector(ealen)),
-            )
-        } else {
-            (None, None, None)
-        };
+    let mut request = ExecutionRequestBuilder::new(image_id, execution_id)
+        .inputs(inputs)
+        .tip(tip)
+        .expiry(expiration)?
+        .forward_output(config.forward_output)
+        .proof_system(config.proof_system)?
+        .compact_storage(config.compact_storage)
+        .authorized_provers(authorized_provers);
+    if let Some(input_hash) = config.input_hash {
+        request = request.input_digest(input_hash)?;
+    }
+    request = request.verify_input_hash(config.verify_input_hash);
+    if let Some(prover_set) = config.prover_set {
+        request = request.prover_set(prover_set);
+    }
+    if let Some(callback) = callback {
+        request = request.callback(callback)?;
+    }
+    if let Some(prover_version) = prover_version {
+        request = request.prover_version(prover_version);
+    }
+    request.instruction_with_accounts(requester, payer, execution_account, deployment_account)
+}
+
+/// Adds provers to the owner's prover set, creating the set on first use.
+pub fn add_provers_v1(
+    owner: &Pubkey,
+    payer: &Pubkey,
+    set_id: &str,
+    provers: &[Pubkey],
+) -> Result<Instruction, ClientError> {
+    prover_set_update_v1(owner, payer, set_id, ProverSetOp::Add, provers)
+}
+
+/// Removes provers from the owner's prover set, requests referencing it pick the change up at
+/// their next claim.
+pub fn remove_provers_v1(
+    owner: &Pubkey,
+    payer: &Pubkey,
+    set_id: &str,
+    provers: &[Pubkey],
+) -> Result<Instruction, ClientError> {
+    prover_set_update_v1(owner, payer, set_id, ProverSetOp::Remove, provers)
+}
+
+fn prover_set_update_v1(
+    owner: &Pubkey,
+    payer: &Pubkey,
+    set_id: &str,
+    op: ProverSetOp,
+    provers: &[Pubkey],
+) -> Result<Instruction, ClientError> {
+    if set_id.is_empty() || set_id.len() > MAX_PROVER_SET_ID_LEN || provers.is_empty() {
+        return Err(ClientError::InvalidInput);
+    }
+    let (prover_set, _) = prover_set_address(owner, set_id.as_bytes());
     let accounts = vec![
-        AccountMeta::new(*requester, true),
+        AccountMeta::new(*owner, true),
         AccountMeta::new(*payer, true),
-        AccountMeta::new(*execution_account, false),
-        AccountMeta::new_readonly(*deployment_account, false),
-        AccountMeta::new_readonly(callback_pubkey.unwrap_or(crate::ID), false),
+        AccountMeta::new(prover_set, false),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
-    let inputlen = inputs.len();
-    let mut inputs_vec = Vec::with_capacity(inputlen);
-    for input in inputs {
-        let data_off = fbb.create_vector(input.data);
-        let mut ibb = InputBuilder::new(&mut fbb);
-        ibb.add_data(data_off);
-        ibb.add_input_type(input.input_type);
-        let input = ibb.finish();
-        inputs_vec.push(input);
-    }
-    let fb_inputs = fbb.create_vector(&inputs_vec);
-    let image_id = fbb.create_string(image_id);
-    let execution_id = fbb.create_string(execution_id);
-
-    let input_digest = config.input_hash.map(|ih| fbb.create_vector(ih));
-    let authorized_provers = if authorized_provers.is_empty() {
-        None
-    } else {
-        Some(
-            fbb.create_vector_from_iter(
-                authorized_provers
-                    .iter()
-                    .map(|key| PublicKey::new(key.as_array())),
-            ),
-        )
-    };
-
-    // typically cli will pass None for the optional prover_version indicating bonsol should handle
-    // the default case here
-    let prover_version = prover_version.unwrap_or_default();
-    let fbb_execute = ExecutionRequestV1::create(
+    let mut fbb = FlatBufferBuilder::new();
+    let set_id = fbb.create_string(set_id);
+    let provers =
+        fbb.create_vector_from_iter(provers.iter().map(|key| PublicKey::new(key.as_array())));
+    let fbb_update = ProverSetUpdateV1::create(
         &mut fbb,
-        &ExecutionRequestV1Args {
-            tip,
-            execution_id: Some(execution_id),
-            image_id: Some(image_id),
-            callback_program_id,
-            callback_instruction_prefix,
-            forward_output: config.forward_output,
-            verify_input_hash: config.verify_input_hash,
-            input: Some(fb_inputs),
-            max_block_height: expiration,
-            input_digest,
-            callback_extra_accounts: extra_accounts,
-            prover_version,
-            authorized_provers,
+        &ProverSetUpdateV1Args {
+            set_id: Some(set_id),
+            op,
+            provers: Some(provers),
         },
     );
-    fbb.finish(fbb_execute, None);
+    fbb.finish(fbb_update, None);
     let ix_data = fbb.finished_data();
     let mut fbb = FlatBufferBuilder::new();
     let ix = fbb.create_vector(ix_data);
     let fbb_ix = ChannelInstruction::create(
         &mut fbb,
         &ChannelInstructionArgs {
-            ix_type: ChannelInstructionIxType::ExecuteV1,
-            execute_v1: Some(ix),
+            ix_type: ChannelInstructionIxType::ProverSetUpdateV1,
+            prover_set_update_v1: Some(ix),
             ..Default::default()
         },
     );
@@ -348,3 +721,276 @@ pub fn execute_v1_with_accounts<'a>(
     let ix_data = fbb.finished_data();
     Ok(Instruction::new_with_bytes(crate::ID, ix_data, accounts))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bonsol_schema::parse_ix_data;
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+    const DIGEST: [u8; 32] = [3u8; 32];
+
+    fn callback(extra_accounts: Vec<AccountMeta>) -> CallbackConfig {
+        CallbackConfig {
+            program_id: Pubkey::new_unique(),
+            instruction_prefix: vec![1],
+            extra_accounts,
+        }
+    }
+
+    #[test]
+    fn test_builder_matches_execute_v1() {
+        let requester = Pubkey::new_unique();
+        let cb = callback(vec![AccountMeta::new(Pubkey::new_unique(), false)]);
+        let prover = Pubkey::new_unique();
+        let built = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .input(InputRef::public(b"{}"))
+            .input(InputRef::private(b"https://example.com"))
+            .input_digest(&DIGEST)
+            .unwrap()
+            .tip(1000)
+            .expiry(200)
+            .unwrap()
+            .forward_output(true)
+            .callback(cb.clone())
+            .unwrap()
+            .authorized_provers(vec![prover])
+            .instruction(&requester, &requester)
+            .unwrap();
+        let positional = execute_v1(
+            &requester,
+            &requester,
+            IMAGE_ID,
+            "exec",
+            vec![
+                InputRef::public(b"{}"),
+                InputRef::private(b"https://example.com"),
+            ],
+            1000,
+            200,
+            ExecutionConfig {
+                verify_input_hash: true,
+                input_hash: Some(&DIGEST),
+                forward_output: true,
+                ..Default::default()
+            },
+            Some(cb),
+            None,
+            vec![prover],
+        )
+        .unwrap();
+        assert_eq!(built, positional);
+
+        let er = parse_ix_data(&built.data)
+            .unwrap()
+            .execute_v1_verified()
+            .unwrap();
+        assert_eq!(er.max_block_height(), 200);
+        assert_eq!(er.input_digest().unwrap().bytes(), DIGEST);
+        assert_eq!(er.input().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_builder_instruction_data() {
+        let request = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .verify_input_hash(false)
+            .expiry(1)
+            .unwrap();
+        let requester = Pubkey::new_unique();
+        let ix = request.instruction(&requester, &requester).unwrap();
+        assert_eq!(request.instruction_data().unwrap(), ix.data);
+        assert_eq!(ix.accounts[4].pubkey, crate::ID);
+    }
+
+    #[test]
+    fn test_builder_from_request() {
+        let prover = Pubkey::new_unique();
+        let extra = AccountMeta::new(Pubkey::new_unique(), false);
+        let request = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .input(InputRef::public(b"{}"))
+            .input(InputRef::url(b"https://example.com"))
+            .input_digest(&DIGEST)
+            .unwrap()
+            .tip(10)
+            .expiry(100)
+            .unwrap()
+            .forward_output(true)
+            .proof_system(ProofSystem::Sp1Groth16)
+            .unwrap()
+            .prover_set(Pubkey::new_unique())
+            .authorized_provers(vec![prover])
+            .callback(callback(vec![extra]))
+            .unwrap();
+        let data = request.instruction_data().unwrap();
+        let decoded = parse_ix_data(&data)
+            .unwrap()
+            .execute_v1_verified()
+            .unwrap()
+            .unpack();
+        let rebuilt = ExecutionRequestBuilder::from_request(&decoded).unwrap();
+        assert_eq!(rebuilt.instruction_data().unwrap(), data);
+
+        let mut missing_id = decoded.clone();
+        missing_id.execution_id = None;
+        assert!(matches!(
+            ExecutionRequestBuilder::from_request(&missing_id),
+            Err(ClientError::InvalidInput)
+        ));
+
+        let mut unsupported = decoded.clone();
+        unsupported.proof_system = ProofSystem(7);
+        assert!(matches!(
+            ExecutionRequestBuilder::from_request(&unsupported),
+            Err(ClientError::UnsupportedProofSystem)
+        ));
+        assert!(matches!(
+            request.proof_system(ProofSystem(7)),
+            Err(ClientError::UnsupportedProofSystem)
+        ));
+    }
+
+    #[test]
+    fn test_builder_requires_input_digest() {
+        let request = ExecutionRequestBuilder::new(IMAGE_ID, "exec")
+            .expiry(1)
+            .unwrap();
+        assert!(matches!(
+            request.instruction_data(),
+            Err(ClientError::InputDigestRequired)
+        ));
+        assert!(matches!(
+            request.input_digest(&DIGEST[..31]),
+            Err(ClientError::InvalidInputDigest)
+        ));
+    }
+
+    #[test]
+    fn test_builder_requires_expiry() {
+        let request = ExecutionRequestBuilder::new(IMAGE_ID, "exec").verify_input_hash(false);
+        assert!(matches!(
+            request.clone().expiry(0),
+            Err(ClientError::MaxBlockHeightRequired)
+        ));
+        assert!(matches!(
+            request.instruction_data(),
+            Err(ClientError::MaxBlockHeightRequired)
+        ));
+    }
+
+    #[test]
+    fn test_builder_checks_callback() {
+        let request = ExecutionRequestBuilder::new(IMAGE_ID, "exec");
+        let mut no_prefix = callback(vec![]);
+        no_prefix.instruction_prefix.clear();
+        assert!(matches!(
+            request.clone().callback(no_prefix),
+            Err(ClientError::CallbackInstructionPrefixRequired)
+        ));
+        let too_many = (0..=MAX_CALLBACK_EXTRA_ACCOUNTS)
+            .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+        assert!(matches!(
+            request.clone().callback(callback(too_many)),
+            Err(ClientError::TooManyCallbackExtraAccounts)
+        ));
+        let signer = vec![AccountMeta::new(Pubkey::new_unique(), true)];
+        assert!(matches!(
+            request.callback(callback(signer)),
+            Err(ClientError::InvalidCallbackExtraAccounts)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_execution_config_prover_set_json() {
+        let prover_set = Pubkey::new_unique();
+        let json = format!(
+            r#"{{"verifyInputHash":false,"inputHash":null,"forwardOutput":false,"proverSet":"{prover_set}"}}"#
+        );
+        let config: ExecutionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(config.prover_set, Some(prover_set));
+        let json =
+            r#"{"verifyInputHash":false,"inputHash":null,"forwardOutput":false,"proverSet":null}"#;
+        let config: ExecutionConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.prover_set, None);
+        let json = r#"{"verifyInputHash":false,"inputHash":null,"forwardOutput":false}"#;
+        let config: ExecutionConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.prover_set, None);
+        let json = r#"{"verifyInputHash":false,"inputHash":null,"forwardOutput":false,"proverSet":"nope"}"#;
+        assert!(serde_json::from_str::<ExecutionConfig>(json).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_execution_config_proof_system_json() {
+        let config = ExecutionConfig {
+            verify_input_hash: false,
+            proof_system: ProofSystem::Sp1Groth16,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""proofSystem":"Sp1Groth16""#));
+        let config: ExecutionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(config.proof_system, ProofSystem::Sp1Groth16);
+        let json = r#"{"verifyInputHash":false,"inputHash":null,"forwardOutput":false}"#;
+        let config: ExecutionConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.proof_system, ProofSystem::Risc0Groth16);
+    }
+
+    #[test]
+    fn test_execute_v1_runs_builder_checks() {
+        let requester = Pubkey::new_unique();
+        let config = || ExecutionConfig {
+            input_hash: Some(&DIGEST),
+            ..Default::default()
+        };
+        let execute = |expiry, config, callback| {
+            execute_v1(
+                &requester,
+                &requester,
+                IMAGE_ID,
+                "exec",
+                vec![InputRef::public(b"{}")],
+                1000,
+                expiry,
+                config,
+                callback,
+                None,
+                vec![],
+            )
+        };
+        assert!(execute(200, config(), None).is_ok());
+        assert!(matches!(
+            execute(0, config(), None),
+            Err(ClientError::MaxBlockHeightRequired)
+        ));
+        assert!(matches!(
+            execute(
+                200,
+                ExecutionConfig {
+                    input_hash: Some(&DIGEST[..31]),
+                    ..Default::default()
+                },
+                None
+            ),
+            Err(ClientError::InvalidInputDigest)
+        ));
+        assert!(matches!(
+            execute(
+                200,
+                ExecutionConfig {
+                    proof_system: ProofSystem(7),
+                    ..config()
+                },
+                None
+            ),
+            Err(ClientError::UnsupportedProofSystem)
+        ));
+        let mut cb = callback(vec![]);
+        cb.instruction_prefix.clear();
+        assert!(matches!(
+            execute(200, config(), Some(cb)),
+            Err(ClientError::CallbackInstructionPrefixRequired)
+        ));
+    }
+}
@@ -0,0 +1,765 @@
+//! Allocation free helpers for programs built on pinocchio style entrypoints.
+//!
+//! Enabled with the `pinocchio` feature, usually with default features off so neither
+//! `solana-program` nor `solana-sdk` is linked:
+//!
+//! ```toml
+//! bonsol-interface = { version = "0.7", default-features = false, features = ["pinocchio"] }
+//! ```
+//!
+//! Requests are encoded into a caller provided buffer and sent with `invoke_signed`, nothing here
+//! touches the heap.
+//!
+//! ```ignore
+//! let mut buf = [0u8; 512];
+//! ExecuteV1 {
+//!     tip: 1000,
+//!     input_digest: Some(&digest),
+//!     inputs: &[Input::public(b"{}")],
+//!     ..ExecuteV1::new(IMAGE_ID, execution_id, slot + 100)
+//! }
+//! .invoke(&accounts, &mut buf, &[])?;
+//! ```
+
+use bonsol_schema::{ChannelInstructionIxType, InputType, ProofSystem, ProverVersion};
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::error::ClientError;
+use crate::MAX_CALLBACK_EXTRA_ACCOUNTS;
+
+/// The bonsol program id, `BoNsHRcyLLNdtnoDf8hiCNZpyehMC4FDMxs6NTxFi3ew`.
+pub const ID: Pubkey = [
+    160, 118, 79, 191, 227, 135, 54, 223, 131, 76, 230, 57, 158, 245, 138, 222, 6, 182, 29, 158,
+    84, 135, 79, 29, 99, 94, 231, 219, 190, 68, 35, 136,
+];
+
+pub const SYSTEM_PROGRAM_ID: Pubkey = [0; 32];
+
+pub const EXECUTION_SEED: &[u8] = b"execution";
+pub const DEPLOYMENT_SEED: &[u8] = b"deployment";
+pub const EXECUTION_CLAIM_SEED: &[u8] = b"execution_claim";
+pub const PROVER_SET_SEED: &[u8] = b"prover_set";
+
+pub const fn execution_address_seeds<'a>(
+    requester: &'a Pubkey,
+    execution_id: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [EXECUTION_SEED, requester, execution_id]
+}
+
+/// Seeds of a deployment, `image_id_hash` is the keccak of the image id string.
+pub const fn deployment_address_seeds(image_id_hash: &[u8; 32]) -> [&[u8]; 2] {
+    [DEPLOYMENT_SEED, image_id_hash]
+}
+
+pub const fn execution_claim_address_seeds(execution_address: &Pubkey) -> [&[u8]; 2] {
+    [EXECUTION_CLAIM_SEED, execution_address]
+}
+
+pub const fn prover_set_address_seeds<'a>(owner: &'a Pubkey, set_id: &'a [u8]) -> [&'a [u8]; 3] {
+    [PROVER_SET_SEED, owner, set_id]
+}
+
+pub fn execution_address(requester: &Pubkey, execution_id: &[u8]) -> (Pubkey, u8) {
+    find_program_address(&execution_address_seeds(requester, execution_id), &ID)
+}
+
+pub fn execution_claim_address(execution_address: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&execution_claim_address_seeds(execution_address), &ID)
+}
+
+pub fn prover_set_address(owner: &Pubkey, set_id: &[u8]) -> (Pubkey, u8) {
+    find_program_address(&prover_set_address_seeds(owner, set_id), &ID)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Input<'a> {
+    pub input_type: InputType,
+    pub data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    pub const fn public(data: &'a [u8]) -> Self {
+        Input {
+            input_type: InputType::PublicData,
+            data,
+        }
+    }
+
+    pub const fn url(data: &'a [u8]) -> Self {
+        Input {
+            input_type: InputType::PublicUrl,
+            data,
+        }
+    }
+
+    pub const fn public_account(data: &'a [u8]) -> Self {
+        Input {
+            input_type: InputType::PublicAccountData,
+            data,
+        }
+    }
+
+    pub const fn public_proof(data: &'a [u8]) -> Self {
+        Input {
+            input_type: InputType::PublicProof,
+            data,
+        }
+    }
+
+    pub const fn private(data: &'a [u8]) -> Self {
+        Input {
+            input_type: InputType::Private,
+            data,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CallbackAccount<'a> {
+    pub pubkey: &'a Pubkey,
+    pub writable: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Callback<'a> {
+    pub program_id: &'a Pubkey,
+    pub instruction_prefix: &'a [u8],
+    pub extra_accounts: &'a [CallbackAccount<'a>],
+}
+
+/// The accounts of an `ExecuteV1` cpi. Without a callback `callback_program` is the bonsol
+/// program itself.
+pub struct ExecuteV1Accounts<'a> {
+    pub requester: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub execution: &'a AccountInfo,
+    pub deployment: &'a AccountInfo,
+    pub callback_program: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+/// An execution request borrowed from the caller, the allocation free counterpart of
+/// `ExecutionRequestBuilder` with the same checks.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecuteV1<'a> {
+    pub image_id: &'a str,
+    pub execution_id: &'a str,
+    pub inputs: &'a [Input<'a>],
+    pub tip: u64,
+    pub max_block_height: u64,
+    pub verify_input_hash: bool,
+    pub input_digest: Option<&'a [u8; 32]>,
+    pub forward_output: bool,
+    pub proof_system: ProofSystem,
+    pub prover_version: ProverVersion,
+    pub callback: Option<Callback<'a>>,
+    pub authorized_provers: &'a [Pubkey],
+    pub prover_set: Option<&'a Pubkey>,
+    pub compact_storage: bool,
+}
+
+// vtable slots of ExecutionRequestV1, in schema order
+const TIP: u16 = 0;
+const EXECUTION_ID: u16 = 1;
+const IMAGE_ID: u16 = 2;
+const CALLBACK_PROGRAM_ID: u16 = 3;
+const CALLBACK_INSTRUCTION_PREFIX: u16 = 4;
+const FORWARD_OUTPUT: u16 = 5;
+const VERIFY_INPUT_HASH: u16 = 6;
+const INPUT: u16 = 7;
+const INPUT_DIGEST: u16 = 8;
+const MAX_BLOCK_HEIGHT: u16 = 9;
+const CALLBACK_EXTRA_ACCOUNTS: u16 = 10;
+const PROVER_VERSION: u16 = 11;
+const AUTHORIZED_PROVERS: u16 = 12;
+const PROOF_SYSTEM: u16 = 13;
+const PROVER_SET: u16 = 14;
+const COMPACT_STORAGE: u16 = 15;
+
+// vtable slots of ChannelInstruction and Input
+const IX_TYPE: u16 = 0;
+const EXECUTE_V1: u16 = 1;
+const INPUT_TYPE: u16 = 0;
+const INPUT_DATA: u16 = 1;
+
+/// `Account` is a 33 byte struct forced to an 8 byte alignment.
+const ACCOUNT_STRUCT_LEN: usize = 40;
+
+impl<'a> ExecuteV1<'a> {
+    /// A request with the defaults of `ExecutionConfig`, the input digest is verified so either
+    /// `input_digest` must be set or `verify_input_hash` cleared.
+    pub const fn new(image_id: &'a str, execution_id: &'a str, max_block_height: u64) -> Self {
+        ExecuteV1 {
+            image_id,
+            execution_id,
+            inputs: &[],
+            tip: 0,
+            max_block_height,
+            verify_input_hash: true,
+            input_digest: None,
+            forward_output: false,
+            proof_system: ProofSystem::Risc0Groth16,
+            prover_version: ProverVersion::DEFAULT,
+            callback: None,
+            authorized_provers: &[],
+            prover_set: None,
+            compact_storage: false,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        if self.verify_input_hash && self.input_digest.is_none() {
+            return Err(ClientError::InputDigestRequired);
+        }
+        if self.max_block_height == 0 {
+            return Err(ClientError::MaxBlockHeightRequired);
+        }
+        if let Some(cb) = &self.callback {
+            if cb.instruction_prefix.is_empty() {
+                return Err(ClientError::CallbackInstructionPrefixRequired);
+            }
+            if cb.extra_accounts.len() > MAX_CALLBACK_EXTRA_ACCOUNTS {
+                return Err(ClientError::TooManyCallbackExtraAccounts);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the `ChannelInstruction` into `buf` and returns the written prefix. The layout is
+    /// a regular flatbuffer with the nested request stored inline, so the program verifies and
+    /// reads it like one built with `FlatBufferBuilder`.
+    pub fn encode<'b>(&self, buf: &'b mut [u8]) -> Result<&'b [u8], ClientError> {
+        self.validate()?;
+        let mut w = Writer { buf, len: 0 };
+        let root = w.reserve(4)?;
+        let ix = w.table(&[(EXECUTE_V1, 4), (IX_TYPE, 1)])?;
+        w.put_u8(ix.field(&w, IX_TYPE), ChannelInstructionIxType::ExecuteV1.0);
+        w.patch_offset(root, ix.start);
+
+        // the nested buffer starts 8 byte aligned so its u64 fields stay aligned within it
+        w.align(8, 4)?;
+        let nested_len = w.reserve(4)?;
+        let nested = w.len;
+        self.encode_request(&mut w)?;
+        w.put_u32(nested_len, (w.len - nested) as u32);
+        w.patch_offset(ix.field(&w, EXECUTE_V1), nested_len);
+        Ok(&w.buf[..w.len])
+    }
+
+    fn encode_request(&self, w: &mut Writer) -> Result<(), ClientError> {
+        let root = w.reserve(4)?;
+        let er = w.table(&[
+            (TIP, 8),
+            (MAX_BLOCK_HEIGHT, 8),
+            (EXECUTION_ID, 4),
+            (IMAGE_ID, 4),
+            (CALLBACK_PROGRAM_ID, 4),
+            (CALLBACK_INSTRUCTION_PREFIX, 4),
+            (INPUT, 4),
+            (INPUT_DIGEST, 4),
+            (CALLBACK_EXTRA_ACCOUNTS, 4),
+            (AUTHORIZED_PROVERS, 4),
+            (PROVER_SET, 4),
+            (PROVER_VERSION, 2),
+            (FORWARD_OUTPUT, 1),
+            (VERIFY_INPUT_HASH, 1),
+            (PROOF_SYSTEM, 1),
+            (COMPACT_STORAGE, 1),
+        ])?;
+        w.patch_offset(root, er.start);
+        w.put_u64(er.field(w, TIP), self.tip);
+        w.put_u64(er.field(w, MAX_BLOCK_HEIGHT), self.max_block_height);
+        w.put_u16(er.field(w, PROVER_VERSION), self.prover_version.0);
+        w.put_u8(er.field(w, FORWARD_OUTPUT), self.forward_output as u8);
+        w.put_u8(er.field(w, VERIFY_INPUT_HASH), self.verify_input_hash as u8);
+        w.put_u8(er.field(w, PROOF_SYSTEM), self.proof_system.0);
+        w.put_u8(er.field(w, COMPACT_STORAGE), self.compact_storage as u8);
+
+        let execution_id = w.string(self.execution_id)?;
+        w.patch_offset(er.field(w, EXECUTION_ID), execution_id);
+        let image_id = w.string(self.image_id)?;
+        w.patch_offset(er.field(w, IMAGE_ID), image_id);
+
+        match &self.callback {
+            Some(cb) => {
+                let program_id = w.byte_vector(cb.program_id)?;
+                w.patch_offset(er.field(w, CALLBACK_PROGRAM_ID), program_id);
+                let prefix = w.byte_vector(cb.instruction_prefix)?;
+                w.patch_offset(er.field(w, CALLBACK_INSTRUCTION_PREFIX), prefix);
+                w.align(8, 4)?;
+                let accounts = w.vector_len(cb.extra_accounts.len())?;
+                for account in cb.extra_accounts {
+                    let pos = w.reserve(ACCOUNT_STRUCT_LEN)?;
+                    w.put_u8(pos, account.writable as u8);
+                    w.buf[pos + 1..pos + 33].copy_from_slice(account.pubkey);
+                }
+                w.patch_offset(er.field(w, CALLBACK_EXTRA_ACCOUNTS), accounts);
+            }
+            None => {
+                w.omit(&er, CALLBACK_PROGRAM_ID);
+                w.omit(&er, CALLBACK_INSTRUCTION_PREFIX);
+                w.omit(&er, CALLBACK_EXTRA_ACCOUNTS);
+            }
+        }
+
+        match self.input_digest {
+            Some(digest) => {
+                let digest = w.byte_vector(digest)?;
+                w.patch_offset(er.field(w, INPUT_DIGEST), digest);
+            }
+            None => w.omit(&er, INPUT_DIGEST),
+        }
+        match self.prover_set {
+            Some(prover_set) => {
+                let prover_set = w.byte_vector(prover_set)?;
+                w.patch_offset(er.field(w, PROVER_SET), prover_set);
+            }
+            None => w.omit(&er, PROVER_SET),
+        }
+        if self.authorized_provers.is_empty() {
+            w.omit(&er, AUTHORIZED_PROVERS);
+        } else {
+            w.align(4, 0)?;
+            let provers = w.vector_len(self.authorized_provers.len())?;
+            for prover in self.authorized_provers {
+                w.bytes(prover)?;
+            }
+            w.patch_offset(er.field(w, AUTHORIZED_PROVERS), provers);
+        }
+
+        w.align(4, 0)?;
+        let inputs = w.vector_len(self.inputs.len())?;
+        let offsets = w.reserve(4 * self.inputs.len())?;
+        w.patch_offset(er.field(w, INPUT), inputs);
+        for (i, input) in self.inputs.iter().enumerate() {
+            let table = w.table(&[(INPUT_DATA, 4), (INPUT_TYPE, 1)])?;
+            w.put_u8(table.field(w, INPUT_TYPE), input.input_type.0);
+            w.patch_offset(offsets + 4 * i, table.start);
+            let data = w.byte_vector(input.data)?;
+            w.patch_offset(table.field(w, INPUT_DATA), data);
+        }
+        Ok(())
+    }
+
+    /// Encodes the request into `buf` and invokes bonsol, `signers` are the seeds of any pda
+    /// among the requester and payer.
+    pub fn invoke(
+        &self,
+        accounts: &ExecuteV1Accounts,
+        buf: &mut [u8],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        let data = self.encode(buf)?;
+        let metas = [
+            AccountMeta::writable_signer(accounts.requester.key()),
+            AccountMeta::writable_signer(accounts.payer.key()),
+            AccountMeta::writable(accounts.execution.key()),
+            AccountMeta::readonly(accounts.deployment.key()),
+            AccountMeta::readonly(accounts.callback_program.key()),
+            AccountMeta::readonly(accounts.system_program.key()),
+        ];
+        invoke_signed(
+            &Instruction {
+                program_id: &ID,
+                data,
+                accounts: &metas,
+            },
+            &[
+                accounts.requester,
+                accounts.payer,
+                accounts.execution,
+                accounts.deployment,
+                accounts.callback_program,
+                accounts.system_program,
+            ],
+            signers,
+        )
+    }
+}
+
+/// Position of a table written by `Writer::table` and of its vtable.
+struct Table {
+    vtable: usize,
+    start: usize,
+}
+
+impl Table {
+    fn field(&self, w: &Writer, slot: u16) -> usize {
+        let at = self.vtable + 4 + 2 * slot as usize;
+        self.start + u16::from_le_bytes([w.buf[at], w.buf[at + 1]]) as usize
+    }
+}
+
+/// Writes a flatbuffer front to back. Objects are placed after the fields pointing at them so
+/// every offset is forward, alignment is relative to the start of `buf`.
+struct Writer<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl Writer<'_> {
+    fn reserve(&mut self, n: usize) -> Result<usize, ClientError> {
+        let pos = self.len;
+        let end = pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or(ClientError::InstructionBufferTooSmall)?;
+        self.buf[pos..end].fill(0);
+        self.len = end;
+        Ok(pos)
+    }
+
+    /// Pads until `len + skip` is a multiple of `align`.
+    fn align(&mut self, align: usize, skip: usize) -> Result<(), ClientError> {
+        let pad = (align - (self.len + skip) % align) % align;
+        self.reserve(pad).map(|_| ())
+    }
+
+    fn bytes(&mut self, data: &[u8]) -> Result<usize, ClientError> {
+        let pos = self.reserve(data.len())?;
+        self.buf[pos..self.len].copy_from_slice(data);
+        Ok(pos)
+    }
+
+    fn vector_len(&mut self, len: usize) -> Result<usize, ClientError> {
+        let pos = self.reserve(4)?;
+        self.put_u32(pos, len as u32);
+        Ok(pos)
+    }
+
+    fn byte_vector(&mut self, data: &[u8]) -> Result<usize, ClientError> {
+        self.align(4, 0)?;
+        let pos = self.vector_len(data.len())?;
+        self.bytes(data)?;
+        Ok(pos)
+    }
+
+    fn string(&mut self, s: &str) -> Result<usize, ClientError> {
+        let pos = self.byte_vector(s.as_bytes())?;
+        // strings carry a nul terminator the verifier checks for
+        self.reserve(1)?;
+        Ok(pos)
+    }
+
+    /// Writes a vtable and the table after it. `fields` are `(slot, size)` pairs listed largest
+    /// first, which packs them without padding once the first one is aligned.
+    fn table(&mut self, fields: &[(u16, usize)]) -> Result<Table, ClientError> {
+        let slots = fields.iter().map(|(slot, _)| *slot + 1).max().unwrap_or(0) as usize;
+        let vtable_len = 4 + 2 * slots;
+        let table_len = 4 + fields.iter().map(|(_, size)| size).sum::<usize>();
+        let widest = fields.first().map_or(4, |(_, size)| (*size).max(4));
+
+        self.align(2, 0)?;
+        let vtable = self.reserve(vtable_len)?;
+        self.align(widest, 4)?;
+        let start = self.reserve(table_len)?;
+
+        self.put_u16(vtable, vtable_len as u16);
+        self.put_u16(vtable + 2, table_len as u16);
+        let mut offset = 4;
+        for (slot, size) in fields {
+            self.put_u16(vtable + 4 + 2 * *slot as usize, offset as u16);
+            offset += size;
+        }
+        self.put_u32(start, (start - vtable) as u32);
+        Ok(Table { vtable, start })
+    }
+
+    /// Marks a field absent, its bytes stay in the table as padding.
+    fn omit(&mut self, table: &Table, slot: u16) {
+        self.put_u16(table.vtable + 4 + 2 * slot as usize, 0);
+    }
+
+    fn patch_offset(&mut self, field: usize, target: usize) {
+        self.put_u32(field, (target - field) as u32);
+    }
+
+    fn put_u8(&mut self, pos: usize, value: u8) {
+        self.buf[pos] = value;
+    }
+
+    fn put_u16(&mut self, pos: usize, value: u16) {
+        self.buf[pos..pos + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u32(&mut self, pos: usize, value: u32) {
+        self.buf[pos..pos + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u64(&mut self, pos: usize, value: u64) {
+        self.buf[pos..pos + 8].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+#[cfg(all(test, feature = "solana-sdk"))]
+mod tests {
+    use super::*;
+    use crate::accounts::ExecutionRequest;
+    use crate::instructions::{CallbackConfig, ExecutionRequestBuilder, InputRef};
+    use bonsol_schema::parse_ix_data;
+    use solana_sdk::instruction::AccountMeta as SdkAccountMeta;
+
+    const IMAGE: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    fn decode(data: &[u8]) -> ExecutionRequest {
+        let ix = parse_ix_data(data).unwrap();
+        assert_eq!(ix.ix_type(), ChannelInstructionIxType::ExecuteV1);
+        ExecutionRequest::from_flatbuffer(&ix.execute_v1_verified().unwrap())
+    }
+
+    #[test]
+    fn test_ids_and_seeds_match_util() {
+        assert_eq!(ID, crate::ID.to_bytes());
+        let requester = [7; 32];
+        assert_eq!(
+            execution_address_seeds(&requester, b"exec").as_slice(),
+            crate::util::execution_address_seeds(&requester.into(), b"exec").as_slice()
+        );
+        let hash = crate::util::img_id_hash(IMAGE);
+        assert_eq!(
+            deployment_address_seeds(&hash.to_bytes()).as_slice(),
+            crate::util::deployment_address_seeds(&hash).as_slice()
+        );
+        assert_eq!(
+            execution_claim_address_seeds(&requester).as_slice(),
+            crate::util::execution_claim_address_seeds(&requester).as_slice()
+        );
+        assert_eq!(
+            prover_set_address_seeds(&requester, b"set").as_slice(),
+            crate::util::prover_set_address_seeds(&requester.into(), b"set").as_slice()
+        );
+    }
+
+    #[test]
+    fn test_minimal_request_matches_builder() {
+        let digest = [3; 32];
+        let mut buf = [0u8; 512];
+        let lean = ExecuteV1 {
+            tip: 12,
+            input_digest: Some(&digest),
+            inputs: &[Input::public(b"{}")],
+            ..ExecuteV1::new(IMAGE, "exec", 100)
+        }
+        .encode(&mut buf)
+        .unwrap()
+        .to_vec();
+        let builder = ExecutionRequestBuilder::new(IMAGE, "exec")
+            .input(InputRef::public(b"{}"))
+            .input_digest(&digest)
+            .unwrap()
+            .tip(12)
+            .expiry(100)
+            .unwrap()
+            .instruction_data()
+            .unwrap();
+        assert_eq!(decode(&lean), decode(&builder));
+    }
+
+    #[test]
+    fn test_full_request_matches_builder() {
+        let callback_program = [9; 32];
+        let extra = [5; 32];
+        let prover = [6; 32];
+        let prover_set = [8; 32];
+        let inputs = [
+            Input::public(b"{\"attestation\":\"test\"}"),
+            Input::url(b"https://example.com/input"),
+            Input::private(b"secret"),
+        ];
+        let mut buf = [0u8; 1024];
+        let lean = ExecuteV1 {
+            inputs: &inputs,
+            tip: u64::MAX,
+            verify_input_hash: false,
+            forward_output: true,
+            proof_system: ProofSystem::Sp1Groth16,
+            prover_version: ProverVersion::V3_0_3,
+            callback: Some(Callback {
+                program_id: &callback_program,
+                instruction_prefix: &[1, 2, 3],
+                extra_accounts: &[CallbackAccount {
+                    pubkey: &extra,
+                    writable: true,
+                }],
+            }),
+            authorized_provers: &[prover],
+            prover_set: Some(&prover_set),
+            compact_storage: true,
+            ..ExecuteV1::new(IMAGE, "full", u64::MAX - 1)
+        }
+        .encode(&mut buf)
+        .unwrap()
+        .to_vec();
+        let builder = ExecutionRequestBuilder::new(IMAGE, "full")
+            .inputs([
+                InputRef::public(b"{\"attestation\":\"test\"}"),
+                InputRef::url(b"https://example.com/input"),
+                InputRef::private(b"secret"),
+            ])
+            .tip(u64::MAX)
+            .verify_input_hash(false)
+            .forward_output(true)
+            .proof_system(ProofSystem::Sp1Groth16)
+            .unwrap()
+            .prover_version(ProverVersion::V3_0_3)
+            .callback(CallbackConfig {
+                program_id: callback_program.into(),
+                instruction_prefix: vec![1, 2, 3],
+                extra_accounts: vec![SdkAccountMeta::new(extra.into(), false)],
+            })
+            .unwrap()
+            .authorized_provers(vec![prover.into()])
+            .prover_set(prover_set.into())
+            .compact_storage(true)
+            .expiry(u64::MAX - 1)
+            .unwrap()
+            .instruction_data()
+            .unwrap();
+        assert_eq!(decode(&lean), decode(&builder));
+    }
+
+    #[test]
+    fn test_slots_match_generated_offsets() {
+        use bonsol_schema::{ChannelInstruction, ExecutionRequestV1};
+
+        let slot = |vt: u16| (vt - 4) / 2;
+        for (lean, generated) in [
+            (TIP, ExecutionRequestV1::VT_TIP),
+            (EXECUTION_ID, ExecutionRequestV1::VT_EXECUTION_ID),
+            (IMAGE_ID, ExecutionRequestV1::VT_IMAGE_ID),
+            (
+                CALLBACK_PROGRAM_ID,
+                ExecutionRequestV1::VT_CALLBACK_PROGRAM_ID,
+            ),
+            (
+                CALLBACK_INSTRUCTION_PREFIX,
+                ExecutionRequestV1::VT_CALLBACK_INSTRUCTION_PREFIX,
+            ),
+            (FORWARD_OUTPUT, ExecutionRequestV1::VT_FORWARD_OUTPUT),
+            (VERIFY_INPUT_HASH, ExecutionRequestV1::VT_VERIFY_INPUT_HASH),
+            (INPUT, ExecutionRequestV1::VT_INPUT),
+            (INPUT_DIGEST, ExecutionRequestV1::VT_INPUT_DIGEST),
+            (MAX_BLOCK_HEIGHT, ExecutionRequestV1::VT_MAX_BLOCK_HEIGHT),
+            (
+                CALLBACK_EXTRA_ACCOUNTS,
+                ExecutionRequestV1::VT_CALLBACK_EXTRA_ACCOUNTS,
+            ),
+            (PROVER_VERSION, ExecutionRequestV1::VT_PROVER_VERSION),
+            (
+                AUTHORIZED_PROVERS,
+                ExecutionRequestV1::VT_AUTHORIZED_PROVERS,
+            ),
+            (PROOF_SYSTEM, ExecutionRequestV1::VT_PROOF_SYSTEM),
+            (PROVER_SET, ExecutionRequestV1::VT_PROVER_SET),
+            (COMPACT_STORAGE, ExecutionRequestV1::VT_COMPACT_STORAGE),
+            (IX_TYPE, ChannelInstruction::VT_IX_TYPE),
+            (EXECUTE_V1, ChannelInstruction::VT_EXECUTE_V1),
+            (INPUT_TYPE, bonsol_schema::Input::VT_INPUT_TYPE),
+            (INPUT_DATA, bonsol_schema::Input::VT_DATA),
+        ] {
+            assert_eq!(lean, slot(generated));
+        }
+    }
+
+    // every field gets a value that differs from its default and from its neighbours, so a field
+    // written to the wrong slot reads back wrong through the generated accessors
+    #[test]
+    fn test_every_field_reads_back_through_generated_accessors() {
+        let digest = [3; 32];
+        let callback_program = [9; 32];
+        let extra = [5; 32];
+        let provers = [[6; 32], [7; 32]];
+        let prover_set = [8; 32];
+        let inputs = [Input::public(b"ab"), Input::public_account(&[4; 32])];
+        let mut buf = [0u8; 1024];
+        let data = ExecuteV1 {
+            inputs: &inputs,
+            tip: 0x0102_0304_0506_0708,
+            input_digest: Some(&digest),
+            forward_output: true,
+            proof_system: ProofSystem::Sp1Groth16,
+            prover_version: ProverVersion::V3_0_3,
+            callback: Some(Callback {
+                program_id: &callback_program,
+                instruction_prefix: &[1, 2, 3],
+                extra_accounts: &[CallbackAccount {
+                    pubkey: &extra,
+                    writable: true,
+                }],
+            }),
+            authorized_provers: &provers,
+            prover_set: Some(&prover_set),
+            compact_storage: true,
+            ..ExecuteV1::new(IMAGE, "every-field", 0x1112_1314_1516_1718)
+        }
+        .encode(&mut buf)
+        .unwrap()
+        .to_vec();
+
+        let ix = parse_ix_data(&data).unwrap();
+        assert_eq!(ix.ix_type(), ChannelInstructionIxType::ExecuteV1);
+        let er =
+            bonsol_schema::root_as_execution_request_v1(ix.execute_v1().unwrap().bytes()).unwrap();
+        assert_eq!(er.tip(), 0x0102_0304_0506_0708);
+        assert_eq!(er.execution_id(), Some("every-field"));
+        assert_eq!(er.image_id(), Some(IMAGE));
+        assert_eq!(
+            er.callback_program_id().unwrap().bytes(),
+            callback_program.as_slice()
+        );
+        assert_eq!(
+            er.callback_instruction_prefix().unwrap().bytes(),
+            &[1, 2, 3]
+        );
+        assert!(er.forward_output());
+        assert!(er.verify_input_hash());
+        let decoded_inputs = er.input().unwrap();
+        assert_eq!(decoded_inputs.len(), 2);
+        for (decoded, input) in decoded_inputs.iter().zip(inputs) {
+            assert_eq!(decoded.input_type(), input.input_type);
+            assert_eq!(decoded.data().unwrap().bytes(), input.data);
+        }
+        assert_eq!(er.input_digest().unwrap().bytes(), digest.as_slice());
+        assert_eq!(er.max_block_height(), 0x1112_1314_1516_1718);
+        let accounts = er.callback_extra_accounts().unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts.get(0).writable(), 1);
+        assert_eq!(<[u8; 32]>::from(accounts.get(0).pubkey()), extra);
+        assert_eq!(er.prover_version(), ProverVersion::V3_0_3);
+        let decoded_provers = er.authorized_provers().unwrap();
+        assert_eq!(decoded_provers.len(), 2);
+        for (decoded, prover) in decoded_provers.iter().zip(provers) {
+            assert_eq!(<[u8; 32]>::from(decoded.bytes()), prover);
+        }
+        assert_eq!(er.proof_system(), ProofSystem::Sp1Groth16);
+        assert_eq!(er.prover_set().unwrap().bytes(), prover_set.as_slice());
+        assert!(er.compact_storage());
+        assert!(er.request_digest().is_none());
+    }
+
+    #[test]
+    fn test_encode_checks_request_and_buffer() {
+        let mut buf = [0u8; 512];
+        assert!(matches!(
+            ExecuteV1::new(IMAGE, "exec", 100).encode(&mut buf),
+            Err(ClientError::InputDigestRequired)
+        ));
+        let request = ExecuteV1 {
+            verify_input_hash: false,
+            ..ExecuteV1::new(IMAGE, "exec", 100)
+        };
+        assert!(matches!(
+            request.encode(&mut buf[..64]),
+            Err(ClientError::InstructionBufferTooSmall)
+        ));
+        let len = request.encode(&mut buf).unwrap().len();
+        assert!(request.encode(&mut buf[..len]).is_ok());
+    }
+}
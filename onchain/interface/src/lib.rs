@@ -1,10 +1,38 @@
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
+pub mod accounts;
+#[cfg(feature = "anchor")]
+pub mod anchor;
 #[cfg(feature = "on-chain")]
 pub mod callback;
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
 pub mod claim_state;
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
+pub mod decoder;
 pub mod error;
+#[cfg(all(feature = "serde", any(feature = "on-chain", feature = "solana-sdk")))]
+pub mod idl;
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
 pub mod instructions;
+#[cfg(feature = "pinocchio")]
+pub mod lean;
+pub mod prover_set;
 pub mod prover_version;
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
 pub mod util;
 
 pub use bonsol_schema;
+use bonsol_schema::ProofSystem;
+#[cfg(any(feature = "on-chain", feature = "solana-sdk"))]
 pub use util::{ID, *};
+
+/// A status transaction carries the callback accounts next to the proof and the committed
+/// outputs, more than this leaves no room for them in a single packet.
+pub const MAX_CALLBACK_EXTRA_ACCOUNTS: usize = 16;
+
+/// Proof systems the program has a verifier for, requests for any other are rejected at execution.
+pub fn is_supported_proof_system(proof_system: ProofSystem) -> bool {
+    matches!(
+        proof_system,
+        ProofSystem::Risc0Groth16 | ProofSystem::Sp1Groth16
+    )
+}
@@ -0,0 +1,99 @@
+use crate::error::ClientError;
+
+/// Upper bound on the provers a single set can hold, keeps the claim time membership scan cheap.
+pub const MAX_PROVER_SET_LEN: usize = 64;
+
+/// Length of the seed a set id may be, it is used directly as a pda seed.
+pub const MAX_PROVER_SET_ID_LEN: usize = 32;
+
+/// Prefixes prover set account data, the last byte is the layout version. The program owns other
+/// accounts a request could name as its prover set and `load` rejects any without it.
+pub const PROVER_SET_V1_DISCRIMINATOR: [u8; 8] = *b"bnsps\0\0\x01";
+
+const HEADER_LEN: usize = PROVER_SET_V1_DISCRIMINATOR.len() + 32;
+
+/// A requester owned list of provers that execution requests reference by address instead of
+/// inlining `authorized_provers`. The account data is the discriminator and the owner followed by
+/// the prover keys.
+pub struct ProverSetV1<'a> {
+    pub owner: &'a [u8; 32],
+    pub provers: &'a [[u8; 32]],
+}
+
+impl<'a> ProverSetV1<'a> {
+    pub fn load(data: &'a [u8]) -> Result<Self, ClientError> {
+        if data.len() < HEADER_LEN || !data.starts_with(&PROVER_SET_V1_DISCRIMINATOR) {
+            return Err(ClientError::InvalidProverSetAccount);
+        }
+        let (header, provers) = data.split_at(HEADER_LEN);
+        let provers = bytemuck::try_cast_slice::<u8, [u8; 32]>(provers)
+            .map_err(|_| ClientError::InvalidProverSetAccount)?;
+        if provers.len() > MAX_PROVER_SET_LEN {
+            return Err(ClientError::InvalidProverSetAccount);
+        }
+        Ok(ProverSetV1 {
+            owner: arrayref::array_ref!(header, PROVER_SET_V1_DISCRIMINATOR.len(), 32),
+            provers,
+        })
+    }
+
+    pub fn contains(&self, prover: &[u8]) -> bool {
+        self.provers.iter().any(|p| p.as_slice() == prover)
+    }
+
+    pub const fn account_len(num_provers: usize) -> usize {
+        HEADER_LEN + num_provers * 32
+    }
+
+    /// Serializes a set, callers are expected to have deduplicated and bounded `provers`.
+    pub fn to_bytes(owner: &[u8; 32], provers: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::account_len(provers.len()));
+        data.extend_from_slice(&PROVER_SET_V1_DISCRIMINATOR);
+        data.extend_from_slice(owner);
+        data.extend_from_slice(bytemuck::cast_slice(provers));
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prover_set_roundtrip() {
+        let data = ProverSetV1::to_bytes(&[1u8; 32], &[[2u8; 32], [3u8; 32]]);
+        assert_eq!(data.len(), ProverSetV1::account_len(2));
+        let set = ProverSetV1::load(&data).unwrap();
+        assert_eq!(set.owner, &[1u8; 32]);
+        assert!(set.contains(&[3u8; 32]));
+        assert!(!set.contains(&[1u8; 32]));
+    }
+
+    #[test]
+    fn test_prover_set_empty() {
+        let data = ProverSetV1::to_bytes(&[1u8; 32], &[]);
+        let set = ProverSetV1::load(&data).unwrap();
+        assert!(set.provers.is_empty());
+    }
+
+    #[test]
+    fn test_prover_set_malformed() {
+        assert!(ProverSetV1::load(&PROVER_SET_V1_DISCRIMINATOR).is_err());
+        let mut data = ProverSetV1::to_bytes(&[1u8; 32], &[]);
+        data.push(0);
+        assert!(ProverSetV1::load(&data).is_err());
+        let mut too_many = vec![0u8; ProverSetV1::account_len(MAX_PROVER_SET_LEN + 1)];
+        too_many[..8].copy_from_slice(&PROVER_SET_V1_DISCRIMINATOR);
+        assert!(ProverSetV1::load(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_prover_set_requires_discriminator() {
+        // an owner and a prover key without the prefix, as another bonsol account could hold
+        let untagged = [[1u8; 32], [2u8; 32]].concat();
+        assert!(ProverSetV1::load(&untagged).is_err());
+        let mut other_version = ProverSetV1::to_bytes(&[1u8; 32], &[[2u8; 32]]);
+        other_version[7] = 2;
+        assert!(ProverSetV1::load(&other_version).is_err());
+    }
+}
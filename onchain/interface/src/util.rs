@@ -1,6 +1,7 @@
 #[cfg(feature = "on-chain")]
 use {
     solana_program::declare_id,
+    solana_program::hash,
     solana_program::pubkey::Pubkey,
     solana_program::{keccak, keccak::Hash},
 };
@@ -8,6 +9,7 @@ use {
 #[cfg(not(feature = "on-chain"))]
 use {
     solana_sdk::declare_id,
+    solana_sdk::hash,
     solana_sdk::pubkey::Pubkey,
     solana_sdk::{keccak, keccak::Hash},
 };
@@ -26,6 +28,10 @@ pub fn execution_claim_address_seeds(execution_address: &[u8]) -> Vec<&[u8]> {
     vec!["execution_claim".as_bytes(), execution_address]
 }
 
+pub fn prover_set_address_seeds<'a>(owner: &'a Pubkey, set_id: &'a [u8]) -> Vec<&'a [u8]> {
+    vec!["prover_set".as_bytes(), owner.as_ref(), set_id]
+}
+
 pub fn execution_address(requester: &Pubkey, execution_id: &[u8]) -> (Pubkey, u8) {
     Pubkey::find_program_address(&execution_address_seeds(requester, execution_id), &ID)
 }
@@ -43,6 +49,25 @@ pub fn img_id_hash(image_id: &str) -> Hash {
     keccak::hash(image_id.as_bytes())
 }
 
+/// Digest a compacted execution account commits to, taken over the nested `ExecutionRequestV1`
+/// bytes of the execute instruction.
+#[inline]
+pub fn execution_request_digest(execution_request: &[u8]) -> [u8; 32] {
+    hash::hash(execution_request).to_bytes()
+}
+
+/// The canonical input digest an execution request commits to with `verify_input_hash`.
+///
+/// It is the sha256 of the concatenated input bytes the guest reads, in request order: public data
+/// as submitted, url and account inputs as resolved, private inputs as served to the claiming
+/// prover. `PublicProof` inputs are proven as assumptions rather than read, they are covered by
+/// the assumption digest and left out. Guests commit this digest as the first 32 bytes of their
+/// journal, which is what the prover submits as `StatusV1::input_digest`.
+#[inline]
+pub fn input_digest(inputs: &[&[u8]]) -> [u8; 32] {
+    hash::hashv(inputs).to_bytes()
+}
+
 pub fn deployment_address(image_id: &str) -> (Pubkey, u8) {
     let hsh = img_id_hash(image_id);
     Pubkey::find_program_address(&deployment_address_seeds(&hsh), &ID)
@@ -51,3 +76,28 @@ pub fn deployment_address(image_id: &str) -> (Pubkey, u8) {
 pub fn execution_claim_address(execution_id: &[u8]) -> (Pubkey, u8) {
     Pubkey::find_program_address(&execution_claim_address_seeds(execution_id), &ID)
 }
+
+pub fn prover_set_address(owner: &Pubkey, set_id: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&prover_set_address_seeds(owner, set_id), &ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC: &[u8] = b"{\"attestation\":\"test\"}";
+    const PRIVATE: &[u8] = b"test";
+
+    #[test]
+    fn test_input_digest() {
+        // inputs are concatenated without framing, matching what guests hash
+        assert_eq!(
+            input_digest(&[PUBLIC, PRIVATE]),
+            input_digest(&[b"{\"attestation\":\"test\"}test".as_slice()])
+        );
+        assert_eq!(
+            hex::encode(input_digest(&[PUBLIC, PRIVATE])),
+            "53c3ed9828d07f91a13b1f59b6d954ee4da55f37a4f5cfdb185adeeae66f775d"
+        );
+    }
+}
@@ -5,6 +5,7 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use anyhow::Result;
 use arrayref::array_ref;
 use async_trait::async_trait;
+use bonsol_interface::util::input_digest;
 use bonsol_schema::{InputT, InputType, ProgramInputType};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
@@ -302,6 +303,20 @@ pub fn resolve_remote_public_data(
     )))
 }
 
+/// Canonical input digest of fully resolved inputs, in the order they are written to the guest.
+/// See `bonsol_interface::util::input_digest` for the scheme.
+pub fn resolved_input_digest(inputs: &[ProgramInput]) -> Result<[u8; 32]> {
+    let mut data = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        match input {
+            ProgramInput::Resolved(ri) if ri.input_type == ProgramInputType::PublicProof => {}
+            ProgramInput::Resolved(ri) => data.push(ri.data.as_slice()),
+            _ => return Err(anyhow::anyhow!("Unresolved input")),
+        }
+    }
+    Ok(input_digest(&data))
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PrivateInputRequest {
     identity: Pubkey,
@@ -457,4 +472,31 @@ mod test {
 
         mock.assert();
     }
+
+    #[test]
+    fn test_resolved_input_digest() {
+        let resolved = |index, data: &[u8], input_type| {
+            ProgramInput::Resolved(ResolvedInput {
+                index,
+                data: data.to_vec(),
+                input_type,
+            })
+        };
+        let inputs = vec![
+            resolved(0, b"{\"attestation\":\"test\"}", ProgramInputType::Public),
+            resolved(1, b"receipt", ProgramInputType::PublicProof),
+            resolved(2, b"test", ProgramInputType::Private),
+        ];
+        assert_eq!(
+            resolved_input_digest(&inputs).unwrap(),
+            input_digest(&[b"{\"attestation\":\"test\"}".as_slice(), b"test"])
+        );
+
+        let unresolved = vec![ProgramInput::Unresolved(UnresolvedInput {
+            index: 0,
+            url: Url::parse("https://example.com/private").unwrap(),
+            input_type: ProgramInputType::Private,
+        })];
+        assert!(resolved_input_digest(&unresolved).is_err());
+    }
 }
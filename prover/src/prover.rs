@@ -13,6 +13,18 @@ pub fn new_risc0_exec_env(
     sorted_inputs: Vec<ProgramInput>,
     stdout: LogShipper,
     stderr: LogShipper,
+) -> Result<ExecutorImpl<'static>> {
+    new_risc0_exec_env_with_limit(image, sorted_inputs, stdout, stderr, None)
+}
+
+/// Like `new_risc0_exec_env` but the session stops after `session_limit` cycles, and guest output
+/// can go to any writer. Used to execute a guest without proving it.
+pub fn new_risc0_exec_env_with_limit(
+    image: MemoryImage,
+    sorted_inputs: Vec<ProgramInput>,
+    stdout: impl Write + 'static,
+    stderr: impl Write + 'static,
+    session_limit: Option<u64>,
 ) -> Result<ExecutorImpl<'static>> {
     let mut env_builder = ExecutorEnv::builder();
     for input in sorted_inputs.into_iter() {
@@ -30,7 +42,10 @@ pub fn new_risc0_exec_env(
             }
         }
     }
-    env_builder.stderr(stderr).stdout(stdout);
+    env_builder
+        .stderr(stderr)
+        .stdout(stdout)
+        .session_limit(session_limit);
 
     let env = env_builder.build()?;
     ExecutorImpl::new(env, image)
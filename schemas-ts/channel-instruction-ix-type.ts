@@ -6,5 +6,6 @@ export enum ChannelInstructionIxType {
   ExecuteV1 = 0,
   StatusV1 = 1,
   DeployV1 = 2,
-  ClaimV1 = 3
+  ClaimV1 = 3,
+  ProverSetUpdateV1 = 5
 }
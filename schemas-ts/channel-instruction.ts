@@ -101,8 +101,23 @@ claimV1Array():Uint8Array|null {
   return offset ? new Uint8Array(this.bb!.bytes().buffer, this.bb!.bytes().byteOffset + this.bb!.__vector(this.bb_pos + offset), this.bb!.__vector_len(this.bb_pos + offset)) : null;
 }
 
+proverSetUpdateV1(index: number):number|null {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? this.bb!.readUint8(this.bb!.__vector(this.bb_pos + offset) + index) : 0;
+}
+
+proverSetUpdateV1Length():number {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+proverSetUpdateV1Array():Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? new Uint8Array(this.bb!.bytes().buffer, this.bb!.bytes().byteOffset + this.bb!.__vector(this.bb_pos + offset), this.bb!.__vector_len(this.bb_pos + offset)) : null;
+}
+
 static startChannelInstruction(builder:flatbuffers.Builder) {
-  builder.startObject(5);
+  builder.startObject(6);
 }
 
 static addIxType(builder:flatbuffers.Builder, ixType:ChannelInstructionIxType) {
@@ -173,6 +188,22 @@ static startClaimV1Vector(builder:flatbuffers.Builder, numElems:number) {
   builder.startVector(1, numElems, 1);
 }
 
+static addProverSetUpdateV1(builder:flatbuffers.Builder, proverSetUpdateV1Offset:flatbuffers.Offset) {
+  builder.addFieldOffset(5, proverSetUpdateV1Offset, 0);
+}
+
+static createProverSetUpdateV1Vector(builder:flatbuffers.Builder, data:number[]|Uint8Array):flatbuffers.Offset {
+  builder.startVector(1, data.length, 1);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addInt8(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startProverSetUpdateV1Vector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(1, numElems, 1);
+}
+
 static endChannelInstruction(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
@@ -186,13 +217,14 @@ static finishSizePrefixedChannelInstructionBuffer(builder:flatbuffers.Builder, o
   builder.finish(offset, undefined, true);
 }
 
-static createChannelInstruction(builder:flatbuffers.Builder, ixType:ChannelInstructionIxType, executeV1Offset:flatbuffers.Offset, statusV1Offset:flatbuffers.Offset, deployV1Offset:flatbuffers.Offset, claimV1Offset:flatbuffers.Offset):flatbuffers.Offset {
+static createChannelInstruction(builder:flatbuffers.Builder, ixType:ChannelInstructionIxType, executeV1Offset:flatbuffers.Offset, statusV1Offset:flatbuffers.Offset, deployV1Offset:flatbuffers.Offset, claimV1Offset:flatbuffers.Offset, proverSetUpdateV1Offset:flatbuffers.Offset):flatbuffers.Offset {
   ChannelInstruction.startChannelInstruction(builder);
   ChannelInstruction.addIxType(builder, ixType);
   ChannelInstruction.addExecuteV1(builder, executeV1Offset);
   ChannelInstruction.addStatusV1(builder, statusV1Offset);
   ChannelInstruction.addDeployV1(builder, deployV1Offset);
   ChannelInstruction.addClaimV1(builder, claimV1Offset);
+  ChannelInstruction.addProverSetUpdateV1(builder, proverSetUpdateV1Offset);
   return ChannelInstruction.endChannelInstruction(builder);
 }
 
@@ -202,7 +234,8 @@ unpack(): ChannelInstructionT {
     this.bb!.createScalarList<number>(this.executeV1.bind(this), this.executeV1Length()),
     this.bb!.createScalarList<number>(this.statusV1.bind(this), this.statusV1Length()),
     this.bb!.createScalarList<number>(this.deployV1.bind(this), this.deployV1Length()),
-    this.bb!.createScalarList<number>(this.claimV1.bind(this), this.claimV1Length())
+    this.bb!.createScalarList<number>(this.claimV1.bind(this), this.claimV1Length()),
+    this.bb!.createScalarList<number>(this.proverSetUpdateV1.bind(this), this.proverSetUpdateV1Length())
   );
 }
 
@@ -213,6 +246,7 @@ unpackTo(_o: ChannelInstructionT): void {
   _o.statusV1 = this.bb!.createScalarList<number>(this.statusV1.bind(this), this.statusV1Length());
   _o.deployV1 = this.bb!.createScalarList<number>(this.deployV1.bind(this), this.deployV1Length());
   _o.claimV1 = this.bb!.createScalarList<number>(this.claimV1.bind(this), this.claimV1Length());
+  _o.proverSetUpdateV1 = this.bb!.createScalarList<number>(this.proverSetUpdateV1.bind(this), this.proverSetUpdateV1Length());
 }
 }
 
@@ -222,7 +256,8 @@ constructor(
   public executeV1: (number)[] = [],
   public statusV1: (number)[] = [],
   public deployV1: (number)[] = [],
-  public claimV1: (number)[] = []
+  public claimV1: (number)[] = [],
+  public proverSetUpdateV1: (number)[] = []
 ){}
 
 
@@ -231,13 +266,15 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const statusV1 = ChannelInstruction.createStatusV1Vector(builder, this.statusV1);
   const deployV1 = ChannelInstruction.createDeployV1Vector(builder, this.deployV1);
   const claimV1 = ChannelInstruction.createClaimV1Vector(builder, this.claimV1);
+  const proverSetUpdateV1 = ChannelInstruction.createProverSetUpdateV1Vector(builder, this.proverSetUpdateV1);
 
   return ChannelInstruction.createChannelInstruction(builder,
     this.ixType,
     executeV1,
     statusV1,
     deployV1,
-    claimV1
+    claimV1,
+    proverSetUpdateV1
   );
 }
 }
@@ -11,6 +11,10 @@ export { ExecutionRequestV1, ExecutionRequestV1T } from './execution-request-v1.
 export { Input, InputT } from './input.js';
 export { InputType } from './input-type.js';
 export { ProgramInputType } from './program-input-type.js';
+export { ProofSystem } from './proof-system.js';
+export { ProverSetOp } from './prover-set-op.js';
+export { ProverSetUpdateV1, ProverSetUpdateV1T } from './prover-set-update-v1.js';
 export { ProverVersion } from './prover-version.js';
+export { PublicKey, PublicKeyT } from './public-key.js';
 export { StatusTypes } from './status-types.js';
 export { StatusV1, StatusV1T } from './status-v1.js';
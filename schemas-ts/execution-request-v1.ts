@@ -6,7 +6,9 @@ import * as flatbuffers from 'flatbuffers';
 
 import { Account, AccountT } from './account.js';
 import { Input, InputT } from './input.js';
+import { ProofSystem } from './proof-system.js';
 import { ProverVersion } from './prover-version.js';
+import { PublicKey, PublicKeyT } from './public-key.js';
 
 
 export class ExecutionRequestV1 implements flatbuffers.IUnpackableObject<ExecutionRequestV1T> {
@@ -186,8 +188,80 @@ mutate_prover_version(value:ProverVersion):boolean {
   return true;
 }
 
+authorizedProvers(index: number, obj?:PublicKey):PublicKey|null {
+  const offset = this.bb!.__offset(this.bb_pos, 28);
+  return offset ? (obj || new PublicKey()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 32, this.bb!) : null;
+}
+
+authorizedProversLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 28);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+proofSystem():ProofSystem {
+  const offset = this.bb!.__offset(this.bb_pos, 30);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : ProofSystem.Risc0Groth16;
+}
+
+mutate_proof_system(value:ProofSystem):boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 30);
+
+  if (offset === 0) {
+    return false;
+  }
+
+  this.bb!.writeUint8(this.bb_pos + offset, value);
+  return true;
+}
+
+proverSet(index: number):number|null {
+  const offset = this.bb!.__offset(this.bb_pos, 32);
+  return offset ? this.bb!.readUint8(this.bb!.__vector(this.bb_pos + offset) + index) : 0;
+}
+
+proverSetLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 32);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+proverSetArray():Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 32);
+  return offset ? new Uint8Array(this.bb!.bytes().buffer, this.bb!.bytes().byteOffset + this.bb!.__vector(this.bb_pos + offset), this.bb!.__vector_len(this.bb_pos + offset)) : null;
+}
+
+compactStorage():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 34);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+mutate_compact_storage(value:boolean):boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 34);
+
+  if (offset === 0) {
+    return false;
+  }
+
+  this.bb!.writeInt8(this.bb_pos + offset, +value);
+  return true;
+}
+
+requestDigest(index: number):number|null {
+  const offset = this.bb!.__offset(this.bb_pos, 36);
+  return offset ? this.bb!.readUint8(this.bb!.__vector(this.bb_pos + offset) + index) : 0;
+}
+
+requestDigestLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 36);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+requestDigestArray():Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 36);
+  return offset ? new Uint8Array(this.bb!.bytes().buffer, this.bb!.bytes().byteOffset + this.bb!.__vector(this.bb_pos + offset), this.bb!.__vector_len(this.bb_pos + offset)) : null;
+}
+
 static startExecutionRequestV1(builder:flatbuffers.Builder) {
-  builder.startObject(12);
+  builder.startObject(17);
 }
 
 static addTip(builder:flatbuffers.Builder, tip:bigint) {
@@ -290,6 +364,54 @@ static addProverVersion(builder:flatbuffers.Builder, proverVersion:ProverVersion
   builder.addFieldInt16(11, proverVersion, ProverVersion.DEFAULT);
 }
 
+static addAuthorizedProvers(builder:flatbuffers.Builder, authorizedProversOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(12, authorizedProversOffset, 0);
+}
+
+static startAuthorizedProversVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(32, numElems, 1);
+}
+
+static addProofSystem(builder:flatbuffers.Builder, proofSystem:ProofSystem) {
+  builder.addFieldInt8(13, proofSystem, ProofSystem.Risc0Groth16);
+}
+
+static addProverSet(builder:flatbuffers.Builder, proverSetOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(14, proverSetOffset, 0);
+}
+
+static createProverSetVector(builder:flatbuffers.Builder, data:number[]|Uint8Array):flatbuffers.Offset {
+  builder.startVector(1, data.length, 1);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addInt8(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startProverSetVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(1, numElems, 1);
+}
+
+static addCompactStorage(builder:flatbuffers.Builder, compactStorage:boolean) {
+  builder.addFieldInt8(15, +compactStorage, +false);
+}
+
+static addRequestDigest(builder:flatbuffers.Builder, requestDigestOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(16, requestDigestOffset, 0);
+}
+
+static createRequestDigestVector(builder:flatbuffers.Builder, data:number[]|Uint8Array):flatbuffers.Offset {
+  builder.startVector(1, data.length, 1);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addInt8(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startRequestDigestVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(1, numElems, 1);
+}
+
 static endExecutionRequestV1(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
@@ -303,7 +425,7 @@ static finishSizePrefixedExecutionRequestV1Buffer(builder:flatbuffers.Builder, o
   builder.finish(offset, undefined, true);
 }
 
-static createExecutionRequestV1(builder:flatbuffers.Builder, tip:bigint, executionIdOffset:flatbuffers.Offset, imageIdOffset:flatbuffers.Offset, callbackProgramIdOffset:flatbuffers.Offset, callbackInstructionPrefixOffset:flatbuffers.Offset, forwardOutput:boolean, verifyInputHash:boolean, inputOffset:flatbuffers.Offset, inputDigestOffset:flatbuffers.Offset, maxBlockHeight:bigint, callbackExtraAccountsOffset:flatbuffers.Offset, proverVersion:ProverVersion):flatbuffers.Offset {
+static createExecutionRequestV1(builder:flatbuffers.Builder, tip:bigint, executionIdOffset:flatbuffers.Offset, imageIdOffset:flatbuffers.Offset, callbackProgramIdOffset:flatbuffers.Offset, callbackInstructionPrefixOffset:flatbuffers.Offset, forwardOutput:boolean, verifyInputHash:boolean, inputOffset:flatbuffers.Offset, inputDigestOffset:flatbuffers.Offset, maxBlockHeight:bigint, callbackExtraAccountsOffset:flatbuffers.Offset, proverVersion:ProverVersion, authorizedProversOffset:flatbuffers.Offset, proofSystem:ProofSystem, proverSetOffset:flatbuffers.Offset, compactStorage:boolean, requestDigestOffset:flatbuffers.Offset):flatbuffers.Offset {
   ExecutionRequestV1.startExecutionRequestV1(builder);
   ExecutionRequestV1.addTip(builder, tip);
   ExecutionRequestV1.addExecutionId(builder, executionIdOffset);
@@ -317,6 +439,11 @@ static createExecutionRequestV1(builder:flatbuffers.Builder, tip:bigint, executi
   ExecutionRequestV1.addMaxBlockHeight(builder, maxBlockHeight);
   ExecutionRequestV1.addCallbackExtraAccounts(builder, callbackExtraAccountsOffset);
   ExecutionRequestV1.addProverVersion(builder, proverVersion);
+  ExecutionRequestV1.addAuthorizedProvers(builder, authorizedProversOffset);
+  ExecutionRequestV1.addProofSystem(builder, proofSystem);
+  ExecutionRequestV1.addProverSet(builder, proverSetOffset);
+  ExecutionRequestV1.addCompactStorage(builder, compactStorage);
+  ExecutionRequestV1.addRequestDigest(builder, requestDigestOffset);
   return ExecutionRequestV1.endExecutionRequestV1(builder);
 }
 
@@ -333,7 +460,12 @@ unpack(): ExecutionRequestV1T {
     this.bb!.createScalarList<number>(this.inputDigest.bind(this), this.inputDigestLength()),
     this.maxBlockHeight(),
     this.bb!.createObjList<Account, AccountT>(this.callbackExtraAccounts.bind(this), this.callbackExtraAccountsLength()),
-    this.proverVersion()
+    this.proverVersion(),
+    this.bb!.createObjList<PublicKey, PublicKeyT>(this.authorizedProvers.bind(this), this.authorizedProversLength()),
+    this.proofSystem(),
+    this.bb!.createScalarList<number>(this.proverSet.bind(this), this.proverSetLength()),
+    this.compactStorage(),
+    this.bb!.createScalarList<number>(this.requestDigest.bind(this), this.requestDigestLength())
   );
 }
 
@@ -351,6 +483,11 @@ unpackTo(_o: ExecutionRequestV1T): void {
   _o.maxBlockHeight = this.maxBlockHeight();
   _o.callbackExtraAccounts = this.bb!.createObjList<Account, AccountT>(this.callbackExtraAccounts.bind(this), this.callbackExtraAccountsLength());
   _o.proverVersion = this.proverVersion();
+  _o.authorizedProvers = this.bb!.createObjList<PublicKey, PublicKeyT>(this.authorizedProvers.bind(this), this.authorizedProversLength());
+  _o.proofSystem = this.proofSystem();
+  _o.proverSet = this.bb!.createScalarList<number>(this.proverSet.bind(this), this.proverSetLength());
+  _o.compactStorage = this.compactStorage();
+  _o.requestDigest = this.bb!.createScalarList<number>(this.requestDigest.bind(this), this.requestDigestLength());
 }
 }
 
@@ -367,7 +504,12 @@ constructor(
   public inputDigest: (number)[] = [],
   public maxBlockHeight: bigint = BigInt('0'),
   public callbackExtraAccounts: (AccountT)[] = [],
-  public proverVersion: ProverVersion = ProverVersion.DEFAULT
+  public proverVersion: ProverVersion = ProverVersion.DEFAULT,
+  public authorizedProvers: (PublicKeyT)[] = [],
+  public proofSystem: ProofSystem = ProofSystem.Risc0Groth16,
+  public proverSet: (number)[] = [],
+  public compactStorage: boolean = false,
+  public requestDigest: (number)[] = []
 ){}
 
 
@@ -379,6 +521,9 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
   const input = ExecutionRequestV1.createInputVector(builder, builder.createObjectOffsetList(this.input));
   const inputDigest = ExecutionRequestV1.createInputDigestVector(builder, this.inputDigest);
   const callbackExtraAccounts = builder.createStructOffsetList(this.callbackExtraAccounts, ExecutionRequestV1.startCallbackExtraAccountsVector);
+  const authorizedProvers = builder.createStructOffsetList(this.authorizedProvers, ExecutionRequestV1.startAuthorizedProversVector);
+  const proverSet = ExecutionRequestV1.createProverSetVector(builder, this.proverSet);
+  const requestDigest = ExecutionRequestV1.createRequestDigestVector(builder, this.requestDigest);
 
   return ExecutionRequestV1.createExecutionRequestV1(builder,
     this.tip,
@@ -392,7 +537,12 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     inputDigest,
     this.maxBlockHeight,
     callbackExtraAccounts,
-    this.proverVersion
+    this.proverVersion,
+    authorizedProvers,
+    this.proofSystem,
+    proverSet,
+    this.compactStorage,
+    requestDigest
   );
 }
 }
@@ -7,4 +7,6 @@ export { ExecutionRequestV1, ExecutionRequestV1T } from './execution-request-v1.
 export { Input, InputT } from './input.js';
 export { InputType } from './input-type.js';
 export { ProgramInputType } from './program-input-type.js';
+export { ProofSystem } from './proof-system.js';
 export { ProverVersion } from './prover-version.js';
+export { PublicKey, PublicKeyT } from './public-key.js';
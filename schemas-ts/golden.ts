@@ -0,0 +1,136 @@
+// Encodes one instance of each root type and prints `<name> <hex>` lines, the Rust crate checks
+// its own encoding against them in schemas/tests/golden.rs. Fields are added in the order the
+// generated Rust `create` adds them (largest first, last declared first) so the bytes line up.
+//
+//   pnpm --filter bonsol-schemas run golden
+//
+// Only fields this package knows about are set, regenerate it with bin/gen.sh before adding more.
+
+import * as flatbuffers from 'flatbuffers';
+import { Account } from './account.js';
+import { ChannelInstruction } from './channel-instruction.js';
+import { ChannelInstructionIxType } from './channel-instruction-ix-type.js';
+import { ClaimV1 } from './claim-v1.js';
+import { DeployV1 } from './deploy-v1.js';
+import { ExecutionRequestV1 } from './execution-request-v1.js';
+import { Input } from './input.js';
+import { InputType } from './input-type.js';
+import { ProgramInputType } from './program-input-type.js';
+import { ProverVersion } from './prover-version.js';
+import { StatusTypes } from './status-types.js';
+import { StatusV1 } from './status-v1.js';
+
+const IMAGE_ID = '68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960';
+const utf8 = (s: string) => Array.from(s, (c) => c.charCodeAt(0));
+const fill = (n: number, v: number) => new Array<number>(n).fill(v);
+const range = (n: number) => Array.from({ length: n }, (_, i) => i);
+const hex = (b: Uint8Array) => Array.from(b, (x) => x.toString(16).padStart(2, '0')).join('');
+
+function finish(builder: flatbuffers.Builder, root: flatbuffers.Offset): Uint8Array {
+  builder.finish(root);
+  return builder.asUint8Array();
+}
+
+function input(builder: flatbuffers.Builder, inputType: InputType, data: string): flatbuffers.Offset {
+  const dataOffset = Input.createDataVector(builder, utf8(data));
+  Input.startInput(builder);
+  Input.addData(builder, dataOffset);
+  Input.addInputType(builder, inputType);
+  return Input.endInput(builder);
+}
+
+function executionRequest(): Uint8Array {
+  const builder = new flatbuffers.Builder();
+  const executionId = builder.createString('golden');
+  const imageId = builder.createString(IMAGE_ID);
+  const callbackProgramId = ExecutionRequestV1.createCallbackProgramIdVector(builder, range(32));
+  const prefix = ExecutionRequestV1.createCallbackInstructionPrefixVector(builder, [1, 2, 3, 4]);
+  const inputs = ExecutionRequestV1.createInputVector(builder, [
+    input(builder, InputType.PublicData, 'hello'),
+    input(builder, InputType.PublicUrl, 'https://example.com/input'),
+  ]);
+  const inputDigest = ExecutionRequestV1.createInputDigestVector(builder, fill(32, 0xab));
+  ExecutionRequestV1.startCallbackExtraAccountsVector(builder, 2);
+  Account.createAccount(builder, 0, fill(32, 10));
+  Account.createAccount(builder, 1, fill(32, 9));
+  const accounts = builder.endVector();
+  ExecutionRequestV1.startExecutionRequestV1(builder);
+  ExecutionRequestV1.addMaxBlockHeight(builder, BigInt(1000000));
+  ExecutionRequestV1.addTip(builder, BigInt(12000));
+  ExecutionRequestV1.addCallbackExtraAccounts(builder, accounts);
+  ExecutionRequestV1.addInputDigest(builder, inputDigest);
+  ExecutionRequestV1.addInput(builder, inputs);
+  ExecutionRequestV1.addCallbackInstructionPrefix(builder, prefix);
+  ExecutionRequestV1.addCallbackProgramId(builder, callbackProgramId);
+  ExecutionRequestV1.addImageId(builder, imageId);
+  ExecutionRequestV1.addExecutionId(builder, executionId);
+  ExecutionRequestV1.addProverVersion(builder, ProverVersion.V3_0_3);
+  ExecutionRequestV1.addVerifyInputHash(builder, false);
+  ExecutionRequestV1.addForwardOutput(builder, true);
+  return finish(builder, ExecutionRequestV1.endExecutionRequestV1(builder));
+}
+
+function deploy(): Uint8Array {
+  const builder = new flatbuffers.Builder();
+  const owner = DeployV1.createOwnerVector(builder, fill(32, 7));
+  const imageId = builder.createString(IMAGE_ID);
+  const programName = builder.createString('simple');
+  const url = builder.createString('https://example.com/simple');
+  const inputs = DeployV1.createInputsVector(builder, [
+    ProgramInputType.Public,
+    ProgramInputType.Private,
+  ]);
+  DeployV1.startDeployV1(builder);
+  DeployV1.addSize(builder, BigInt(266072));
+  DeployV1.addInputs(builder, inputs);
+  DeployV1.addUrl(builder, url);
+  DeployV1.addProgramName(builder, programName);
+  DeployV1.addImageId(builder, imageId);
+  DeployV1.addOwner(builder, owner);
+  return finish(builder, DeployV1.endDeployV1(builder));
+}
+
+function status(): Uint8Array {
+  const builder = new flatbuffers.Builder();
+  const executionId = builder.createString('golden');
+  const proof = StatusV1.createProofVector(builder, range(256).map((i) => i % 251));
+  const executionDigest = StatusV1.createExecutionDigestVector(builder, fill(32, 2));
+  const inputDigest = StatusV1.createInputDigestVector(builder, fill(32, 0xab));
+  const committedOutputs = StatusV1.createCommittedOutputsVector(builder, [1, 0, 0, 0]);
+  const assumptionDigest = StatusV1.createAssumptionDigestVector(builder, fill(32, 0));
+  StatusV1.startStatusV1(builder);
+  StatusV1.addExitCodeUser(builder, 3);
+  StatusV1.addExitCodeSystem(builder, 0);
+  StatusV1.addAssumptionDigest(builder, assumptionDigest);
+  StatusV1.addCommittedOutputs(builder, committedOutputs);
+  StatusV1.addInputDigest(builder, inputDigest);
+  StatusV1.addExecutionDigest(builder, executionDigest);
+  StatusV1.addProof(builder, proof);
+  StatusV1.addExecutionId(builder, executionId);
+  StatusV1.addStatus(builder, StatusTypes.Completed);
+  return finish(builder, StatusV1.endStatusV1(builder));
+}
+
+function claim(): Uint8Array {
+  const builder = new flatbuffers.Builder();
+  const executionId = builder.createString('golden');
+  ClaimV1.startClaimV1(builder);
+  ClaimV1.addBlockCommitment(builder, BigInt(4200));
+  ClaimV1.addExecutionId(builder, executionId);
+  return finish(builder, ClaimV1.endClaimV1(builder));
+}
+
+function channelInstruction(): Uint8Array {
+  const builder = new flatbuffers.Builder();
+  const deployV1 = ChannelInstruction.createDeployV1Vector(builder, deploy());
+  ChannelInstruction.startChannelInstruction(builder);
+  ChannelInstruction.addDeployV1(builder, deployV1);
+  ChannelInstruction.addIxType(builder, ChannelInstructionIxType.DeployV1);
+  return finish(builder, ChannelInstruction.endChannelInstruction(builder));
+}
+
+console.log(`execution_request_v1 ${hex(executionRequest())}`);
+console.log(`deploy_v1 ${hex(deploy())}`);
+console.log(`status_v1 ${hex(status())}`);
+console.log(`claim_v1 ${hex(claim())}`);
+console.log(`channel_instruction ${hex(channelInstruction())}`);
@@ -4,6 +4,8 @@ export * from './claim_v1';
 export * from './deploy_v1';
 export * from './execution_request_v1';
 export * from './status_v1';
+export * from './proof_system';
+export * from './prover_set_update_v1';
 
 export enum ExitCode {
   Success = 0,
@@ -0,0 +1,137 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ProverSetOp } from './prover-set-op.js';
+import { PublicKey, PublicKeyT } from './public-key.js';
+
+
+export class ProverSetUpdateV1 implements flatbuffers.IUnpackableObject<ProverSetUpdateV1T> {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ProverSetUpdateV1 {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsProverSetUpdateV1(bb:flatbuffers.ByteBuffer, obj?:ProverSetUpdateV1):ProverSetUpdateV1 {
+  return (obj || new ProverSetUpdateV1()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsProverSetUpdateV1(bb:flatbuffers.ByteBuffer, obj?:ProverSetUpdateV1):ProverSetUpdateV1 {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ProverSetUpdateV1()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+setId():string|null
+setId(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+setId(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+op():ProverSetOp {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : ProverSetOp.Add;
+}
+
+mutate_op(value:ProverSetOp):boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+
+  if (offset === 0) {
+    return false;
+  }
+
+  this.bb!.writeUint8(this.bb_pos + offset, value);
+  return true;
+}
+
+provers(index: number, obj?:PublicKey):PublicKey|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? (obj || new PublicKey()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 32, this.bb!) : null;
+}
+
+proversLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+static startProverSetUpdateV1(builder:flatbuffers.Builder) {
+  builder.startObject(3);
+}
+
+static addSetId(builder:flatbuffers.Builder, setIdOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, setIdOffset, 0);
+}
+
+static addOp(builder:flatbuffers.Builder, op:ProverSetOp) {
+  builder.addFieldInt8(1, op, ProverSetOp.Add);
+}
+
+static addProvers(builder:flatbuffers.Builder, proversOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, proversOffset, 0);
+}
+
+static startProversVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(32, numElems, 1);
+}
+
+static endProverSetUpdateV1(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static finishProverSetUpdateV1Buffer(builder:flatbuffers.Builder, offset:flatbuffers.Offset) {
+  builder.finish(offset);
+}
+
+static finishSizePrefixedProverSetUpdateV1Buffer(builder:flatbuffers.Builder, offset:flatbuffers.Offset) {
+  builder.finish(offset, undefined, true);
+}
+
+static createProverSetUpdateV1(builder:flatbuffers.Builder, setIdOffset:flatbuffers.Offset, op:ProverSetOp, proversOffset:flatbuffers.Offset):flatbuffers.Offset {
+  ProverSetUpdateV1.startProverSetUpdateV1(builder);
+  ProverSetUpdateV1.addSetId(builder, setIdOffset);
+  ProverSetUpdateV1.addOp(builder, op);
+  ProverSetUpdateV1.addProvers(builder, proversOffset);
+  return ProverSetUpdateV1.endProverSetUpdateV1(builder);
+}
+
+unpack(): ProverSetUpdateV1T {
+  return new ProverSetUpdateV1T(
+    this.setId(),
+    this.op(),
+    this.bb!.createObjList<PublicKey, PublicKeyT>(this.provers.bind(this), this.proversLength())
+  );
+}
+
+
+unpackTo(_o: ProverSetUpdateV1T): void {
+  _o.setId = this.setId();
+  _o.op = this.op();
+  _o.provers = this.bb!.createObjList<PublicKey, PublicKeyT>(this.provers.bind(this), this.proversLength());
+}
+}
+
+export class ProverSetUpdateV1T implements flatbuffers.IGeneratedObject {
+constructor(
+  public setId: string|Uint8Array|null = null,
+  public op: ProverSetOp = ProverSetOp.Add,
+  public provers: (PublicKeyT)[] = []
+){}
+
+
+pack(builder:flatbuffers.Builder): flatbuffers.Offset {
+  const setId = (this.setId !== null ? builder.createString(this.setId!) : 0);
+  const provers = builder.createStructOffsetList(this.provers, ProverSetUpdateV1.startProversVector);
+
+  return ProverSetUpdateV1.createProverSetUpdateV1(builder,
+    setId,
+    this.op,
+    provers
+  );
+}
+}
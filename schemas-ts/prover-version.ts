@@ -5,5 +5,7 @@
 export enum ProverVersion {
   DEFAULT = 0,
   V1_0_1 = 1,
-  V1_2_1 = 9
+  V1_2_1 = 9,
+  V2_3_1 = 10,
+  V3_0_3 = 11
 }
@@ -0,0 +1,14 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+/* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
+
+export { Account, AccountT } from './account.js';
+export { ExecutionRequestV1, ExecutionRequestV1T } from './execution-request-v1.js';
+export { Input, InputT } from './input.js';
+export { InputType } from './input-type.js';
+export { ProgramInputType } from './program-input-type.js';
+export { ProofSystem } from './proof-system.js';
+export { ProverSetOp } from './prover-set-op.js';
+export { ProverSetUpdateV1, ProverSetUpdateV1T } from './prover-set-update-v1.js';
+export { ProverVersion } from './prover-version.js';
+export { PublicKey, PublicKeyT } from './public-key.js';
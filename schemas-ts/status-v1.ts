@@ -4,6 +4,7 @@
 
 import * as flatbuffers from 'flatbuffers';
 
+import { ProofSystem } from './proof-system.js';
 import { StatusTypes } from './status-types.js';
 
 
@@ -155,8 +156,24 @@ mutate_exit_code_user(value:number):boolean {
   return true;
 }
 
+proofSystem():ProofSystem {
+  const offset = this.bb!.__offset(this.bb_pos, 22);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : ProofSystem.Risc0Groth16;
+}
+
+mutate_proof_system(value:ProofSystem):boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 22);
+
+  if (offset === 0) {
+    return false;
+  }
+
+  this.bb!.writeUint8(this.bb_pos + offset, value);
+  return true;
+}
+
 static startStatusV1(builder:flatbuffers.Builder) {
-  builder.startObject(9);
+  builder.startObject(10);
 }
 
 static addExecutionId(builder:flatbuffers.Builder, executionIdOffset:flatbuffers.Offset) {
@@ -255,6 +272,10 @@ static addExitCodeUser(builder:flatbuffers.Builder, exitCodeUser:number) {
   builder.addFieldInt32(8, exitCodeUser, 0);
 }
 
+static addProofSystem(builder:flatbuffers.Builder, proofSystem:ProofSystem) {
+  builder.addFieldInt8(9, proofSystem, ProofSystem.Risc0Groth16);
+}
+
 static endStatusV1(builder:flatbuffers.Builder):flatbuffers.Offset {
   const offset = builder.endObject();
   return offset;
@@ -268,7 +289,7 @@ static finishSizePrefixedStatusV1Buffer(builder:flatbuffers.Builder, offset:flat
   builder.finish(offset, undefined, true);
 }
 
-static createStatusV1(builder:flatbuffers.Builder, executionIdOffset:flatbuffers.Offset, status:StatusTypes, proofOffset:flatbuffers.Offset, executionDigestOffset:flatbuffers.Offset, inputDigestOffset:flatbuffers.Offset, committedOutputsOffset:flatbuffers.Offset, assumptionDigestOffset:flatbuffers.Offset, exitCodeSystem:number, exitCodeUser:number):flatbuffers.Offset {
+static createStatusV1(builder:flatbuffers.Builder, executionIdOffset:flatbuffers.Offset, status:StatusTypes, proofOffset:flatbuffers.Offset, executionDigestOffset:flatbuffers.Offset, inputDigestOffset:flatbuffers.Offset, committedOutputsOffset:flatbuffers.Offset, assumptionDigestOffset:flatbuffers.Offset, exitCodeSystem:number, exitCodeUser:number, proofSystem:ProofSystem):flatbuffers.Offset {
   StatusV1.startStatusV1(builder);
   StatusV1.addExecutionId(builder, executionIdOffset);
   StatusV1.addStatus(builder, status);
@@ -279,6 +300,7 @@ static createStatusV1(builder:flatbuffers.Builder, executionIdOffset:flatbuffers
   StatusV1.addAssumptionDigest(builder, assumptionDigestOffset);
   StatusV1.addExitCodeSystem(builder, exitCodeSystem);
   StatusV1.addExitCodeUser(builder, exitCodeUser);
+  StatusV1.addProofSystem(builder, proofSystem);
   return StatusV1.endStatusV1(builder);
 }
 
@@ -292,7 +314,8 @@ unpack(): StatusV1T {
     this.bb!.createScalarList<number>(this.committedOutputs.bind(this), this.committedOutputsLength()),
     this.bb!.createScalarList<number>(this.assumptionDigest.bind(this), this.assumptionDigestLength()),
     this.exitCodeSystem(),
-    this.exitCodeUser()
+    this.exitCodeUser(),
+    this.proofSystem()
   );
 }
 
@@ -307,6 +330,7 @@ unpackTo(_o: StatusV1T): void {
   _o.assumptionDigest = this.bb!.createScalarList<number>(this.assumptionDigest.bind(this), this.assumptionDigestLength());
   _o.exitCodeSystem = this.exitCodeSystem();
   _o.exitCodeUser = this.exitCodeUser();
+  _o.proofSystem = this.proofSystem();
 }
 }
 
@@ -320,7 +344,8 @@ constructor(
   public committedOutputs: (number)[] = [],
   public assumptionDigest: (number)[] = [],
   public exitCodeSystem: number = 0,
-  public exitCodeUser: number = 0
+  public exitCodeUser: number = 0,
+  public proofSystem: ProofSystem = ProofSystem.Risc0Groth16
 ){}
 
 
@@ -341,7 +366,8 @@ pack(builder:flatbuffers.Builder): flatbuffers.Offset {
     committedOutputs,
     assumptionDigest,
     this.exitCodeSystem,
-    this.exitCodeUser
+    this.exitCodeUser,
+    this.proofSystem
   );
 }
 }
@@ -2,5 +2,6 @@
 
 /* eslint-disable @typescript-eslint/no-unused-vars, @typescript-eslint/no-explicit-any, @typescript-eslint/no-non-null-assertion */
 
+export { ProofSystem } from './proof-system.js';
 export { StatusTypes } from './status-types.js';
 export { StatusV1, StatusV1T } from './status-v1.js';
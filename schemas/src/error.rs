@@ -4,4 +4,8 @@ use thiserror::Error;
 pub enum ChannelSchemaError {
     #[error("Invalid Instruction")]
     InvalidInstruction,
+    #[error("Invalid Flatbuffer")]
+    InvalidFlatbuffer,
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
 }
@@ -0,0 +1,395 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+// @generated
+
+use crate::execution_request_v1_generated::*;
+use crate::input_type_generated::*;
+use crate::proof_system_generated::*;
+use core::cmp::Ordering;
+use core::mem;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_PROVER_SET_OP: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_PROVER_SET_OP: u8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PROVER_SET_OP: [ProverSetOp; 2] = [ProverSetOp::Add, ProverSetOp::Remove];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ProverSetOp(pub u8);
+#[allow(non_upper_case_globals)]
+impl ProverSetOp {
+    pub const Add: Self = Self(0);
+    pub const Remove: Self = Self(1);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Add, Self::Remove];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Add => Some("Add"),
+            Self::Remove => Some("Remove"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for ProverSetOp {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for ProverSetOp {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for ProverSetOp {
+    type Output = ProverSetOp;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ProverSetOp {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ProverSetOp {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ProverSetOp {}
+pub enum ProverSetUpdateV1Offset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ProverSetUpdateV1<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ProverSetUpdateV1<'a> {
+    type Inner = ProverSetUpdateV1<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ProverSetUpdateV1<'a> {
+    pub const VT_SET_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_OP: flatbuffers::VOffsetT = 6;
+    pub const VT_PROVERS: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ProverSetUpdateV1 { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ProverSetUpdateV1Args<'args>,
+    ) -> flatbuffers::WIPOffset<ProverSetUpdateV1<'bldr>> {
+        let mut builder = ProverSetUpdateV1Builder::new(_fbb);
+        if let Some(x) = args.provers {
+            builder.add_provers(x);
+        }
+        if let Some(x) = args.set_id {
+            builder.add_set_id(x);
+        }
+        builder.add_op(args.op);
+        builder.finish()
+    }
+
+    pub fn unpack(&self) -> ProverSetUpdateV1T {
+        let set_id = self.set_id().map(|x| x.to_string());
+        let op = self.op();
+        let provers = self
+            .provers()
+            .map(|x| x.iter().map(|t| t.unpack()).collect());
+        ProverSetUpdateV1T {
+            set_id,
+            op,
+            provers,
+        }
+    }
+
+    #[inline]
+    pub fn set_id(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(ProverSetUpdateV1::VT_SET_ID, None)
+        }
+    }
+    #[inline]
+    pub fn op(&self) -> ProverSetOp {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ProverSetOp>(ProverSetUpdateV1::VT_OP, Some(ProverSetOp::Add))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn provers(&self) -> Option<flatbuffers::Vector<'a, PublicKey>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PublicKey>>>(
+                    ProverSetUpdateV1::VT_PROVERS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ProverSetUpdateV1<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("set_id", Self::VT_SET_ID, false)?
+            .visit_field::<ProverSetOp>("op", Self::VT_OP, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PublicKey>>>(
+                "provers",
+                Self::VT_PROVERS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ProverSetUpdateV1Args<'a> {
+    pub set_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub op: ProverSetOp,
+    pub provers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PublicKey>>>,
+}
+impl<'a> Default for ProverSetUpdateV1Args<'a> {
+    #[inline]
+    fn default() -> Self {
+        ProverSetUpdateV1Args {
+            set_id: None,
+            op: ProverSetOp::Add,
+            provers: None,
+        }
+    }
+}
+
+pub struct ProverSetUpdateV1Builder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ProverSetUpdateV1Builder<'a, 'b, A> {
+    #[inline]
+    pub fn add_set_id(&mut self, set_id: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ProverSetUpdateV1::VT_SET_ID, set_id);
+    }
+    #[inline]
+    pub fn add_op(&mut self, op: ProverSetOp) {
+        self.fbb_
+            .push_slot::<ProverSetOp>(ProverSetUpdateV1::VT_OP, op, ProverSetOp::Add);
+    }
+    #[inline]
+    pub fn add_provers(
+        &mut self,
+        provers: flatbuffers::WIPOffset<flatbuffers::Vector<'b, PublicKey>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ProverSetUpdateV1::VT_PROVERS, provers);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ProverSetUpdateV1Builder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ProverSetUpdateV1Builder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ProverSetUpdateV1<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ProverSetUpdateV1<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ProverSetUpdateV1");
+        ds.field("set_id", &self.set_id());
+        ds.field("op", &self.op());
+        ds.field("provers", &self.provers());
+        ds.finish()
+    }
+}
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProverSetUpdateV1T {
+    pub set_id: Option<String>,
+    pub op: ProverSetOp,
+    pub provers: Option<Vec<PublicKeyT>>,
+}
+impl Default for ProverSetUpdateV1T {
+    fn default() -> Self {
+        Self {
+            set_id: None,
+            op: ProverSetOp::Add,
+            provers: None,
+        }
+    }
+}
+impl ProverSetUpdateV1T {
+    pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+        &self,
+        _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+    ) -> flatbuffers::WIPOffset<ProverSetUpdateV1<'b>> {
+        let set_id = self.set_id.as_ref().map(|x| _fbb.create_string(x));
+        let op = self.op;
+        let provers = self.provers.as_ref().map(|x| {
+            let w: Vec<_> = x.iter().map(|t| t.pack()).collect();
+            _fbb.create_vector(&w)
+        });
+        ProverSetUpdateV1::create(
+            _fbb,
+            &ProverSetUpdateV1Args {
+                set_id,
+                op,
+                provers,
+            },
+        )
+    }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ProverSetUpdateV1`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_prover_set_update_v1_unchecked`.
+pub fn root_as_prover_set_update_v1(
+    buf: &[u8],
+) -> Result<ProverSetUpdateV1, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::root::<ProverSetUpdateV1>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ProverSetUpdateV1` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_prover_set_update_v1_unchecked`.
+pub fn size_prefixed_root_as_prover_set_update_v1(
+    buf: &[u8],
+) -> Result<ProverSetUpdateV1, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::size_prefixed_root::<ProverSetUpdateV1>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ProverSetUpdateV1` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_prover_set_update_v1_unchecked`.
+pub fn root_as_prover_set_update_v1_with_opts<'b, 'o>(
+    opts: &'o flatbuffers::VerifierOptions,
+    buf: &'b [u8],
+) -> Result<ProverSetUpdateV1<'b>, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::root_with_opts::<ProverSetUpdateV1<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ProverSetUpdateV1` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_prover_set_update_v1_unchecked`.
+pub fn size_prefixed_root_as_prover_set_update_v1_with_opts<'b, 'o>(
+    opts: &'o flatbuffers::VerifierOptions,
+    buf: &'b [u8],
+) -> Result<ProverSetUpdateV1<'b>, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::size_prefixed_root_with_opts::<ProverSetUpdateV1<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ProverSetUpdateV1 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ProverSetUpdateV1`.
+pub unsafe fn root_as_prover_set_update_v1_unchecked(buf: &[u8]) -> ProverSetUpdateV1 {
+    flatbuffers::root_unchecked::<ProverSetUpdateV1>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ProverSetUpdateV1 and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ProverSetUpdateV1`.
+pub unsafe fn size_prefixed_root_as_prover_set_update_v1_unchecked(
+    buf: &[u8],
+) -> ProverSetUpdateV1 {
+    flatbuffers::size_prefixed_root_unchecked::<ProverSetUpdateV1>(buf)
+}
+#[inline]
+pub fn finish_prover_set_update_v1_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ProverSetUpdateV1<'a>>,
+) {
+    fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_prover_set_update_v1_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ProverSetUpdateV1<'a>>,
+) {
+    fbb.finish_size_prefixed(root, None);
+}
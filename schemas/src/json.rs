@@ -0,0 +1,536 @@
+//! Stable JSON forms of the object API types, enabled with the `serde` feature.
+//!
+//! Field names are the schema names in camelCase, enums are written by their schema names, byte
+//! fields are `0x` prefixed hex and 32 byte keys are base58. Fields left out of a document take
+//! their schema defaults, so a request only needs the fields it sets:
+//!
+//! ```json
+//! {
+//!   "executionId": "my-execution",
+//!   "imageId": "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960",
+//!   "tip": 1000,
+//!   "maxBlockHeight": 284000000,
+//!   "input": [{ "inputType": "PublicData", "data": "0x7b7d" }],
+//!   "inputDigest": "0x44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+//! }
+//! ```
+//!
+//! `json_to_flatbuffer` and `flatbuffer_to_json` convert between a document and the bytes of the
+//! matching root type.
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ChannelSchemaError;
+use crate::*;
+
+/// A root type that converts between its JSON form and a finished flatbuffer.
+pub trait JsonFlatbuffer: Serialize + DeserializeOwned {
+    fn to_flatbuffer(&self) -> Vec<u8>;
+
+    fn from_flatbuffer(data: &[u8]) -> Result<Self, ChannelSchemaError>;
+}
+
+macro_rules! json_flatbuffer {
+    ($($ty:ty => $root:ident),* $(,)?) => {$(
+        impl JsonFlatbuffer for $ty {
+            fn to_flatbuffer(&self) -> Vec<u8> {
+                let mut fbb = flatbuffers::FlatBufferBuilder::new();
+                let root = self.pack(&mut fbb);
+                fbb.finish(root, None);
+                fbb.finished_data().to_vec()
+            }
+
+            fn from_flatbuffer(data: &[u8]) -> Result<Self, ChannelSchemaError> {
+                $root(data)
+                    .map(|root| root.unpack())
+                    .map_err(|_| ChannelSchemaError::InvalidFlatbuffer)
+            }
+        }
+    )*};
+}
+
+json_flatbuffer!(
+    ExecutionRequestV1T => root_as_execution_request_v1,
+    DeployV1T => root_as_deploy_v1,
+    StatusV1T => root_as_status_v1,
+    ClaimV1T => root_as_claim_v1,
+    ProverSetUpdateV1T => root_as_prover_set_update_v1,
+);
+
+pub fn json_to_flatbuffer<T: JsonFlatbuffer>(json: &str) -> Result<Vec<u8>, ChannelSchemaError> {
+    let value: T =
+        serde_json::from_str(json).map_err(|e| ChannelSchemaError::InvalidJson(e.to_string()))?;
+    Ok(value.to_flatbuffer())
+}
+
+pub fn flatbuffer_to_json<T: JsonFlatbuffer>(data: &[u8]) -> Result<String, ChannelSchemaError> {
+    serde_json::to_string_pretty(&T::from_flatbuffer(data)?)
+        .map_err(|e| ChannelSchemaError::InvalidJson(e.to_string()))
+}
+
+/// Enums are written by name, values the schema does not name are written as numbers so a newer
+/// producer's documents still round trip.
+macro_rules! named_enum {
+    ($($ty:ident),* $(,)?) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self.variant_name() {
+                    Some(name) => serializer.serialize_str(name),
+                    None => self.0.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                match Named::deserialize(deserializer)? {
+                    Named::Name(name) => $ty::ENUM_VALUES
+                        .iter()
+                        .find(|v| v.variant_name() == Some(name.as_str()))
+                        .copied()
+                        .ok_or_else(|| {
+                            D::Error::custom(format!(
+                                concat!("unknown ", stringify!($ty), " {}"),
+                                name
+                            ))
+                        }),
+                    Named::Value(value) => value.try_into().map($ty).map_err(|_| {
+                        D::Error::custom(format!(
+                            concat!(stringify!($ty), " {} is out of range"),
+                            value
+                        ))
+                    }),
+                }
+            }
+        }
+    )*};
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Named {
+    Name(String),
+    Value(u64),
+}
+
+named_enum!(
+    ChannelInstructionIxType,
+    InputType,
+    ProgramInputType,
+    ProofSystem,
+    ProverSetOp,
+    ProverVersion,
+    StatusTypes,
+);
+
+impl Serialize for PublicKeyT {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&bs58::encode(self.bytes).into_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKeyT {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        key::deserialize(deserializer).map(|bytes| PublicKeyT { bytes })
+    }
+}
+
+macro_rules! remote_serde {
+    ($($ty:ident => $def:ident),* $(,)?) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $def::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $def::deserialize(deserializer)
+            }
+        }
+    )*};
+}
+
+remote_serde!(
+    InputT => InputDef,
+    AccountT => AccountDef,
+    ExecutionRequestV1T => ExecutionRequestV1Def,
+    DeployV1T => DeployV1Def,
+    StatusV1T => StatusV1Def,
+    ClaimV1T => ClaimV1Def,
+    ProverSetUpdateV1T => ProverSetUpdateV1Def,
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "InputT", rename_all = "camelCase")]
+struct InputDef {
+    #[serde(default = "default_input_type")]
+    input_type: InputType,
+    #[serde(default, with = "bytes")]
+    data: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "AccountT", rename_all = "camelCase")]
+struct AccountDef {
+    #[serde(with = "key")]
+    pubkey: [u8; 32],
+    #[serde(with = "flag")]
+    writable: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "ExecutionRequestV1T", rename_all = "camelCase")]
+struct ExecutionRequestV1Def {
+    #[serde(default)]
+    tip: u64,
+    #[serde(default)]
+    execution_id: Option<String>,
+    #[serde(default)]
+    image_id: Option<String>,
+    #[serde(default, with = "opt_key")]
+    callback_program_id: Option<Vec<u8>>,
+    #[serde(default, with = "bytes")]
+    callback_instruction_prefix: Option<Vec<u8>>,
+    #[serde(default)]
+    forward_output: bool,
+    #[serde(default = "default_true")]
+    verify_input_hash: bool,
+    #[serde(default)]
+    input: Option<Vec<InputT>>,
+    #[serde(default, with = "bytes")]
+    input_digest: Option<Vec<u8>>,
+    #[serde(default)]
+    max_block_height: u64,
+    #[serde(default)]
+    callback_extra_accounts: Option<Vec<AccountT>>,
+    #[serde(default = "default_prover_version")]
+    prover_version: ProverVersion,
+    #[serde(default)]
+    authorized_provers: Option<Vec<PublicKeyT>>,
+    #[serde(default = "default_proof_system")]
+    proof_system: ProofSystem,
+    #[serde(default, with = "opt_key")]
+    prover_set: Option<Vec<u8>>,
+    #[serde(default)]
+    compact_storage: bool,
+    #[serde(default, with = "bytes")]
+    request_digest: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "DeployV1T", rename_all = "camelCase")]
+struct DeployV1Def {
+    #[serde(default, with = "opt_key")]
+    owner: Option<Vec<u8>>,
+    #[serde(default)]
+    image_id: Option<String>,
+    #[serde(default)]
+    program_name: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default, rename = "size")]
+    size_: u64,
+    #[serde(default)]
+    inputs: Option<Vec<ProgramInputType>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "StatusV1T", rename_all = "camelCase")]
+struct StatusV1Def {
+    #[serde(default)]
+    execution_id: Option<String>,
+    #[serde(default = "default_status")]
+    status: StatusTypes,
+    #[serde(default, with = "bytes")]
+    proof: Option<Vec<u8>>,
+    #[serde(default, with = "bytes")]
+    execution_digest: Option<Vec<u8>>,
+    #[serde(default, with = "bytes")]
+    input_digest: Option<Vec<u8>>,
+    #[serde(default, with = "bytes")]
+    committed_outputs: Option<Vec<u8>>,
+    #[serde(default, with = "bytes")]
+    assumption_digest: Option<Vec<u8>>,
+    #[serde(default)]
+    exit_code_system: u32,
+    #[serde(default)]
+    exit_code_user: u32,
+    #[serde(default = "default_proof_system")]
+    proof_system: ProofSystem,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "ClaimV1T", rename_all = "camelCase")]
+struct ClaimV1Def {
+    #[serde(default)]
+    execution_id: Option<String>,
+    #[serde(default)]
+    block_commitment: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "ProverSetUpdateV1T", rename_all = "camelCase")]
+struct ProverSetUpdateV1Def {
+    #[serde(default)]
+    set_id: Option<String>,
+    #[serde(default = "default_prover_set_op")]
+    op: ProverSetOp,
+    #[serde(default)]
+    provers: Option<Vec<PublicKeyT>>,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+const fn default_input_type() -> InputType {
+    InputType::PublicData
+}
+
+const fn default_prover_version() -> ProverVersion {
+    ProverVersion::DEFAULT
+}
+
+const fn default_proof_system() -> ProofSystem {
+    ProofSystem::Risc0Groth16
+}
+
+const fn default_status() -> StatusTypes {
+    StatusTypes::Unknown
+}
+
+const fn default_prover_set_op() -> ProverSetOp {
+    ProverSetOp::Add
+}
+
+/// Byte fields as `0x` prefixed hex. Hex without the prefix and arrays of numbers, as older
+/// request files wrote instruction prefixes, are read too.
+mod bytes {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Bytes {
+        Hex(String),
+        Array(Vec<u8>),
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(bytes) => serializer.serialize_str(&format!("0x{}", hex::encode(bytes))),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        match Option::<Bytes>::deserialize(deserializer)? {
+            Some(Bytes::Hex(s)) => hex::decode(s.strip_prefix("0x").unwrap_or(&s))
+                .map(Some)
+                .map_err(|e| D::Error::custom(format!("invalid hex {s}: {e}"))),
+            Some(Bytes::Array(bytes)) => Ok(Some(bytes)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// 32 byte keys as base58.
+mod key {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&bs58::encode(value).into_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let mut key = [0u8; 32];
+        match bs58::decode(&s).onto(&mut key) {
+            Ok(32) => Ok(key),
+            _ => Err(D::Error::custom(format!("invalid public key {s}"))),
+        }
+    }
+}
+
+/// Keys stored as byte vectors, which the program requires to be 32 bytes long.
+mod opt_key {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(bytes) => serializer.serialize_str(&bs58::encode(bytes).into_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        #[derive(Deserialize)]
+        struct Key(#[serde(with = "key")] [u8; 32]);
+        Ok(Option::<Key>::deserialize(deserializer)?.map(|k| k.0.to_vec()))
+    }
+}
+
+/// `Account::writable` is a byte in the schema and a bool in JSON.
+mod flag {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(*value != 0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+        bool::deserialize(deserializer).map(u8::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+    fn request() -> ExecutionRequestV1T {
+        ExecutionRequestV1T {
+            tip: 1000,
+            execution_id: Some("exec".to_string()),
+            image_id: Some(IMAGE_ID.to_string()),
+            callback_program_id: Some(vec![7; 32]),
+            callback_instruction_prefix: Some(vec![1, 2]),
+            forward_output: true,
+            verify_input_hash: true,
+            input: Some(vec![
+                InputT::public(b"{}".to_vec()),
+                InputT::url(b"a".to_vec()),
+            ]),
+            input_digest: Some(vec![3; 32]),
+            max_block_height: 100,
+            callback_extra_accounts: Some(vec![AccountT {
+                writable: 1,
+                pubkey: [9; 32],
+            }]),
+            prover_version: ProverVersion::V3_0_3,
+            authorized_provers: Some(vec![PublicKeyT { bytes: [5; 32] }]),
+            proof_system: ProofSystem::Sp1Groth16,
+            prover_set: None,
+            compact_storage: true,
+            request_digest: None,
+        }
+    }
+
+    #[test]
+    fn test_execution_request_round_trip() {
+        let request = request();
+        let json = serde_json::to_string(&request).unwrap();
+        let flatbuffer = json_to_flatbuffer::<ExecutionRequestV1T>(&json).unwrap();
+        assert_eq!(flatbuffer, request.to_flatbuffer());
+        let back = flatbuffer_to_json::<ExecutionRequestV1T>(&flatbuffer).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ExecutionRequestV1T>(&back).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn test_stable_form() {
+        let value = serde_json::to_value(request()).unwrap();
+        assert_eq!(value["executionId"], "exec");
+        assert_eq!(value["callbackInstructionPrefix"], "0x0102");
+        assert_eq!(value["input"][0]["inputType"], "PublicData");
+        assert_eq!(value["input"][0]["data"], "0x7b7d");
+        assert_eq!(value["proverVersion"], "V3_0_3");
+        assert_eq!(value["proofSystem"], "Sp1Groth16");
+        assert_eq!(
+            value["callbackExtraAccounts"][0],
+            serde_json::json!({
+                "pubkey": bs58::encode([9u8; 32]).into_string(),
+                "writable": true,
+            })
+        );
+        assert_eq!(
+            value["authorizedProvers"][0],
+            bs58::encode([5u8; 32]).into_string()
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_take_schema_defaults() {
+        let request: ExecutionRequestV1T = serde_json::from_str(
+            r#"{"imageId": "abc", "input": [{"data": [1, 2]}, {"inputType": 4, "data": "0303"}]}"#,
+        )
+        .unwrap();
+        assert!(request.verify_input_hash);
+        assert_eq!(request.proof_system, ProofSystem::Risc0Groth16);
+        assert_eq!(request.prover_version, ProverVersion::DEFAULT);
+        let inputs = request.input.unwrap();
+        assert_eq!(inputs[0], InputT::public(vec![1, 2]));
+        assert_eq!(inputs[1], InputT::url(vec![3, 3]));
+        let empty: ExecutionRequestV1T = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, ExecutionRequestV1T::default());
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        for json in [
+            r#"{"proofSystem": "Plonk"}"#,
+            r#"{"proverVersion": 70000}"#,
+            r#"{"inputDigest": "0xzz"}"#,
+            r#"{"proverSet": "not a key"}"#,
+        ] {
+            assert!(matches!(
+                json_to_flatbuffer::<ExecutionRequestV1T>(json),
+                Err(ChannelSchemaError::InvalidJson(_))
+            ));
+        }
+        assert_eq!(
+            flatbuffer_to_json::<ExecutionRequestV1T>(&[1, 2, 3]),
+            Err(ChannelSchemaError::InvalidFlatbuffer)
+        );
+    }
+
+    #[test]
+    fn test_other_root_types() {
+        let deploy = DeployV1T {
+            owner: Some(vec![1; 32]),
+            image_id: Some(IMAGE_ID.to_string()),
+            program_name: Some("simple".to_string()),
+            url: Some("https://example.com".to_string()),
+            size_: 10,
+            inputs: Some(vec![ProgramInputType::Public, ProgramInputType::Private]),
+        };
+        let value = serde_json::to_value(&deploy).unwrap();
+        assert_eq!(value["inputs"], serde_json::json!(["Public", "Private"]));
+        let data = json_to_flatbuffer::<DeployV1T>(&value.to_string()).unwrap();
+        assert_eq!(DeployV1T::from_flatbuffer(&data).unwrap(), deploy);
+
+        let status: StatusV1T = serde_json::from_str(
+            r#"{"executionId": "exec", "status": "Completed", "proof": "0x00ff"}"#,
+        )
+        .unwrap();
+        assert_eq!(status.status, StatusTypes::Completed);
+        assert_eq!(status.proof, Some(vec![0, 255]));
+        let data = status.to_flatbuffer();
+        assert_eq!(StatusV1T::from_flatbuffer(&data).unwrap(), status);
+
+        let claim: ClaimV1T =
+            serde_json::from_str(r#"{"executionId": "exec", "blockCommitment": 12}"#).unwrap();
+        assert_eq!(claim.block_commitment, 12);
+        let update: ProverSetUpdateV1T = serde_json::from_str(&format!(
+            r#"{{"setId": "set", "op": "Remove", "provers": ["{}"]}}"#,
+            bs58::encode([5u8; 32]).into_string()
+        ))
+        .unwrap();
+        assert_eq!(update.op, ProverSetOp::Remove);
+        assert_eq!(update.provers.unwrap(), vec![PublicKeyT { bytes: [5; 32] }]);
+    }
+}
@@ -1,49 +1,50 @@
+// Generated by flatc from flatbuffers/*.fbs and checked in, see tests/generated.rs to regenerate.
 #[allow(warnings)]
-pub mod channel_instruction_generated {
-    include!(concat!(
-        env!("OUT_DIR"),
-        "/channel_instruction_generated.rs"
-    ));
-}
+#[path = "generated/channel_instruction_generated.rs"]
+pub mod channel_instruction_generated;
 
 #[allow(warnings)]
-pub mod claim_v1_generated {
-    include!(concat!(env!("OUT_DIR"), "/claim_v1_generated.rs"));
-}
+#[path = "generated/claim_v1_generated.rs"]
+pub mod claim_v1_generated;
 
 #[allow(warnings)]
-pub mod deploy_v1_generated {
-    include!(concat!(env!("OUT_DIR"), "/deploy_v1_generated.rs"));
-}
+#[path = "generated/deploy_v1_generated.rs"]
+pub mod deploy_v1_generated;
 
 #[allow(warnings)]
-pub mod execution_request_v1_generated {
-    include!(concat!(
-        env!("OUT_DIR"),
-        "/execution_request_v1_generated.rs"
-    ));
-}
+#[path = "generated/execution_request_v1_generated.rs"]
+pub mod execution_request_v1_generated;
 
 #[allow(warnings)]
-pub mod input_type_generated {
-    include!(concat!(env!("OUT_DIR"), "/input_type_generated.rs"));
-}
+#[path = "generated/input_type_generated.rs"]
+pub mod input_type_generated;
 
 #[allow(warnings)]
-pub mod status_v1_generated {
-    include!(concat!(env!("OUT_DIR"), "/status_v1_generated.rs"));
-}
+#[path = "generated/proof_system_generated.rs"]
+pub mod proof_system_generated;
+
+#[allow(warnings)]
+#[path = "generated/prover_set_update_v1_generated.rs"]
+pub mod prover_set_update_v1_generated;
+
+#[allow(warnings)]
+#[path = "generated/status_v1_generated.rs"]
+pub mod status_v1_generated;
 
 use std::fmt::Display;
 
 use error::ChannelSchemaError;
 use num_derive::{FromPrimitive, ToPrimitive};
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod json;
 pub use channel_instruction_generated::*;
 pub use claim_v1_generated::*;
 pub use deploy_v1_generated::*;
 pub use execution_request_v1_generated::*;
 pub use input_type_generated::*;
+pub use proof_system_generated::*;
+pub use prover_set_update_v1_generated::*;
 pub use status_v1_generated::*;
 
 pub fn parse_ix_data(ix_data: &'_ [u8]) -> Result<ChannelInstruction<'_>, ChannelSchemaError> {
@@ -52,7 +53,36 @@ pub fn parse_ix_data(ix_data: &'_ [u8]) -> Result<ChannelInstruction<'_>, Channe
     Ok(instruction)
 }
 
-#[derive(ToPrimitive, FromPrimitive, PartialEq)]
+/// The generated `*_nested_flatbuffer` accessors follow the nested bytes without verifying them
+/// and `parse_ix_data` only verifies the outer instruction, use these on untrusted data instead.
+impl<'a> ChannelInstruction<'a> {
+    pub fn execute_v1_verified(&self) -> Option<ExecutionRequestV1<'a>> {
+        self.execute_v1()
+            .and_then(|data| root_as_execution_request_v1(data.bytes()).ok())
+    }
+
+    pub fn status_v1_verified(&self) -> Option<StatusV1<'a>> {
+        self.status_v1()
+            .and_then(|data| root_as_status_v1(data.bytes()).ok())
+    }
+
+    pub fn deploy_v1_verified(&self) -> Option<DeployV1<'a>> {
+        self.deploy_v1()
+            .and_then(|data| root_as_deploy_v1(data.bytes()).ok())
+    }
+
+    pub fn claim_v1_verified(&self) -> Option<ClaimV1<'a>> {
+        self.claim_v1()
+            .and_then(|data| root_as_claim_v1(data.bytes()).ok())
+    }
+
+    pub fn prover_set_update_v1_verified(&self) -> Option<ProverSetUpdateV1<'a>> {
+        self.prover_set_update_v1()
+            .and_then(|data| root_as_prover_set_update_v1(data.bytes()).ok())
+    }
+}
+
+#[derive(ToPrimitive, FromPrimitive, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum ExitCode {
     Success = 0,
@@ -0,0 +1,112 @@
+//! The generated code under `src/generated` is checked in so building the crate does not need
+//! flatc. This test runs flatc and rustfmt over `flatbuffers/*.fbs` and fails when the checked in
+//! code is stale. It needs flatc 24.3.25 so it is ignored by default, CI runs it with
+//! `cargo test -p bonsol-schema --test generated -- --ignored`.
+//!
+//! Run with `BONSOL_REGENERATE_SCHEMA=1` to rewrite `src/generated` after changing a schema.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const FLATC_VERSION: &str = "24.3.25";
+
+fn flatc_version() -> Option<String> {
+    let output = Command::new("flatc").arg("--version").output().ok()?;
+    String::from_utf8(output.stdout).ok()
+}
+
+fn schema_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .expect("Schema directory not found")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension().and_then(|ext| ext.to_str()) == Some("fbs")).then_some(path)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+#[ignore = "requires flatc 24.3.25"]
+fn test_generated_code_is_up_to_date() {
+    let version = flatc_version().expect("flatc not found, install flatc 24.3.25");
+    assert!(
+        version.contains(FLATC_VERSION),
+        "Expected flatc version {}, found {}",
+        FLATC_VERSION,
+        version
+    );
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let checked_in = root.join("src/generated");
+    let out_dir = std::env::temp_dir().join(format!("bonsol-schema-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&out_dir);
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let files = schema_files(&root.join("flatbuffers"));
+    let status = Command::new("flatc")
+        .args([
+            "--gen-mutable",
+            "--gen-object-api",
+            "--reflect-names",
+            "--rust",
+            "-o",
+        ])
+        .arg(&out_dir)
+        .args(&files)
+        .status()
+        .expect("Failed to execute flatc command");
+    assert!(
+        status.success(),
+        "flatc failed to compile schema files: {:?}",
+        files
+    );
+    // the checked in code is formatted with the workspace rustfmt config like the rest of the crate
+    let generated: Vec<_> = fs::read_dir(&out_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021", "--config-path"])
+        .arg(root.join("../rustfmt.toml"))
+        .args(&generated)
+        .status()
+        .expect("Failed to execute rustfmt");
+    assert!(status.success(), "rustfmt failed on the generated code");
+
+    let regenerate = std::env::var_os("BONSOL_REGENERATE_SCHEMA").is_some();
+    let mut stale = vec![];
+    for file in &files {
+        let name = format!(
+            "{}_generated.rs",
+            file.file_stem().unwrap().to_str().unwrap()
+        );
+        let generated = fs::read_to_string(out_dir.join(&name)).unwrap();
+        if regenerate {
+            fs::write(checked_in.join(&name), &generated).unwrap();
+        }
+        if fs::read_to_string(checked_in.join(&name)).ok().as_ref() != Some(&generated) {
+            stale.push(name);
+        }
+    }
+    for entry in fs::read_dir(&checked_in).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_string();
+        if !out_dir.join(&name).exists() {
+            if regenerate {
+                fs::remove_file(&path).unwrap();
+            } else {
+                stale.push(name);
+            }
+        }
+    }
+    let _ = fs::remove_dir_all(&out_dir);
+    assert!(
+        stale.is_empty(),
+        "src/generated is stale ({}), rerun with BONSOL_REGENERATE_SCHEMA=1",
+        stale.join(", ")
+    );
+}
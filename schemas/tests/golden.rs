@@ -0,0 +1,129 @@
+//! The object API encodes the same bytes as the TypeScript builders in `schemas-ts`, the vectors in
+//! `golden.txt` come from `pnpm --filter bonsol-schemas run golden` (see `schemas-ts/golden.ts`).
+//! Both sides must be changed together.
+
+use bonsol_schema::*;
+use flatbuffers::FlatBufferBuilder;
+
+const IMAGE_ID: &str = "68f4b0c5f9ce034aa60ceb264a18d6c410a3af68fafd931bcfd9ebe7c1e42960";
+
+fn golden(name: &str) -> Vec<u8> {
+    let vectors = include_str!("golden.txt");
+    let hex = vectors
+        .lines()
+        .find_map(|l| l.strip_prefix(name)?.strip_prefix(' '))
+        .unwrap_or_else(|| panic!("no golden vector for {}", name));
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn input(input_type: InputType, data: &str) -> InputT {
+    let mut input = InputT::default();
+    input.input_type = input_type;
+    input.data = Some(data.as_bytes().to_vec());
+    input
+}
+
+fn execution_request() -> Vec<u8> {
+    let mut er = ExecutionRequestV1T::default();
+    er.tip = 12000;
+    er.execution_id = Some("golden".to_string());
+    er.image_id = Some(IMAGE_ID.to_string());
+    er.callback_program_id = Some((0..32).collect());
+    er.callback_instruction_prefix = Some(vec![1, 2, 3, 4]);
+    er.forward_output = true;
+    er.verify_input_hash = false;
+    er.input = Some(vec![
+        input(InputType::PublicData, "hello"),
+        input(InputType::PublicUrl, "https://example.com/input"),
+    ]);
+    er.input_digest = Some(vec![0xab; 32]);
+    er.max_block_height = 1_000_000;
+    er.callback_extra_accounts = Some(vec![
+        AccountT {
+            writable: 1,
+            pubkey: [9; 32],
+        },
+        AccountT {
+            writable: 0,
+            pubkey: [10; 32],
+        },
+    ]);
+    er.prover_version = ProverVersion::V3_0_3;
+    let mut fbb = FlatBufferBuilder::new();
+    let root = er.pack(&mut fbb);
+    fbb.finish(root, None);
+    fbb.finished_data().to_vec()
+}
+
+fn deploy() -> Vec<u8> {
+    let mut deploy = DeployV1T::default();
+    deploy.owner = Some(vec![7; 32]);
+    deploy.image_id = Some(IMAGE_ID.to_string());
+    deploy.program_name = Some("simple".to_string());
+    deploy.url = Some("https://example.com/simple".to_string());
+    deploy.size_ = 266072;
+    deploy.inputs = Some(vec![ProgramInputType::Public, ProgramInputType::Private]);
+    let mut fbb = FlatBufferBuilder::new();
+    let root = deploy.pack(&mut fbb);
+    fbb.finish(root, None);
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_execution_request_v1() {
+    let bytes = execution_request();
+    assert_eq!(bytes, golden("execution_request_v1"));
+    let er = root_as_execution_request_v1(&bytes).unwrap();
+    assert_eq!(er.execution_id(), Some("golden"));
+    assert_eq!(er.callback_extra_accounts().unwrap().get(1).writable(), 0);
+}
+
+#[test]
+fn test_deploy_v1() {
+    assert_eq!(deploy(), golden("deploy_v1"));
+}
+
+#[test]
+fn test_status_v1() {
+    let mut st = StatusV1T::default();
+    st.execution_id = Some("golden".to_string());
+    st.status = StatusTypes::Completed;
+    st.proof = Some((0..256).map(|i| (i % 251) as u8).collect());
+    st.execution_digest = Some(vec![2; 32]);
+    st.input_digest = Some(vec![0xab; 32]);
+    st.committed_outputs = Some(vec![1, 0, 0, 0]);
+    st.assumption_digest = Some(vec![0; 32]);
+    st.exit_code_user = 3;
+    let mut fbb = FlatBufferBuilder::new();
+    let root = st.pack(&mut fbb);
+    fbb.finish(root, None);
+    assert_eq!(fbb.finished_data(), golden("status_v1"));
+}
+
+#[test]
+fn test_claim_v1() {
+    let mut claim = ClaimV1T::default();
+    claim.execution_id = Some("golden".to_string());
+    claim.block_commitment = 4200;
+    let mut fbb = FlatBufferBuilder::new();
+    let root = claim.pack(&mut fbb);
+    fbb.finish(root, None);
+    assert_eq!(fbb.finished_data(), golden("claim_v1"));
+}
+
+#[test]
+fn test_channel_instruction() {
+    let mut ix = ChannelInstructionT::default();
+    ix.ix_type = ChannelInstructionIxType::DeployV1;
+    ix.deploy_v1 = Some(deploy());
+    let mut fbb = FlatBufferBuilder::new();
+    let root = ix.pack(&mut fbb);
+    fbb.finish(root, None);
+    let bytes = fbb.finished_data();
+    assert_eq!(bytes, golden("channel_instruction"));
+    let deploy = parse_ix_data(bytes).unwrap().deploy_v1_verified().unwrap();
+    assert_eq!(deploy.program_name(), Some("simple"));
+}
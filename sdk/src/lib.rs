@@ -4,7 +4,6 @@ use anyhow::Result;
 
 use bytes::Bytes;
 use futures_util::TryFutureExt;
-use num_traits::FromPrimitive;
 
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::config::RpcSendTransactionConfig;
@@ -19,16 +18,18 @@ use solana_sdk::transaction::VersionedTransaction;
 
 use tokio::time::Instant;
 
-use bonsol_interface::bonsol_schema::{root_as_deploy_v1, root_as_execution_request_v1};
+use bonsol_interface::accounts::{BonsolAccount, ExecutionAccount};
+use bonsol_interface::bonsol_schema::root_as_deploy_v1;
 pub use bonsol_interface::bonsol_schema::{
     ClaimV1T, DeployV1T, ExecutionRequestV1T, ExitCode, InputT, InputType, ProgramInputType,
-    StatusTypes,
+    ProofSystem, StatusTypes,
 };
 use bonsol_interface::claim_state::ClaimStateHolder;
+use bonsol_interface::prover_set::ProverSetV1;
 use bonsol_interface::prover_version::ProverVersion;
 pub use bonsol_interface::util::*;
 pub use bonsol_interface::{instructions, ID};
-use instructions::{CallbackConfig, ExecutionConfig, InputRef};
+use instructions::{CallbackConfig, ExecutionConfig, ExecutionRequestBuilder, InputRef};
 
 pub use flatbuffers;
 
@@ -86,14 +87,28 @@ impl BonsolClient {
             .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))?
             .value
             .ok_or(anyhow::anyhow!("Invalid execution request account"))?;
-        if account.data.len() == 1 {
-            let ec =
-                ExitCode::from_u8(account.data[0]).ok_or(anyhow::anyhow!("Invalid exit code"))?;
-            return Ok(ExecutionAccountStatus::Completed(ec));
+        match ExecutionAccount::load(&account.data)
+            .map_err(|_| anyhow::anyhow!("Invalid execution request account"))?
+        {
+            ExecutionAccount::Completed(ec) => Ok(ExecutionAccountStatus::Completed(ec)),
+            ExecutionAccount::Pending(er) => Ok(ExecutionAccountStatus::Pending(er.unpack())),
+        }
+    }
+
+    /// Decodes any account owned by the bonsol program, `None` if it does not exist.
+    pub async fn get_bonsol_account(&self, address: &Pubkey) -> Result<Option<BonsolAccount>> {
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(address, CommitmentConfig::confirmed())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))?;
+        match account.value {
+            Some(account) if account.owner == ID => {
+                Ok(Some(BonsolAccount::decode(address, &account.data)?))
+            }
+            Some(_) => Err(anyhow::anyhow!("Account is not owned by bonsol")),
+            None => Ok(None),
         }
-        let er = root_as_execution_request_v1(&account.data)
-            .map_err(|_| anyhow::anyhow!("Invalid execution request account"))?;
-        Ok(ExecutionAccountStatus::Pending(er.unpack()))
     }
 
     pub async fn get_claim_state_v1<'a>(
@@ -113,6 +128,28 @@ impl BonsolClient {
         Ok(ClaimStateHolder::new(account.data))
     }
 
+    /// Returns the provers in a prover set, `None` if the set has not been created.
+    pub async fn get_prover_set_v1(
+        &self,
+        owner: &Pubkey,
+        set_id: &str,
+    ) -> Result<Option<Vec<Pubkey>>> {
+        let (prover_set, _) = prover_set_address(owner, set_id.as_bytes());
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&prover_set, CommitmentConfig::confirmed())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get account: {:?}", e))?;
+        match account.value {
+            Some(account) => {
+                let set = ProverSetV1::load(&account.data)
+                    .map_err(|_| anyhow::anyhow!("Invalid prover set account"))?;
+                Ok(Some(set.provers.iter().map(|p| Pubkey::from(*p)).collect()))
+            }
+            None => Ok(None),
+        }
+    }
+
     pub async fn download_program(&self, image_id: &str) -> Result<Bytes> {
         let deployment = self.get_deployment_v1(image_id).await?;
         let url = deployment
@@ -207,6 +244,46 @@ impl BonsolClient {
         Ok(vec![compute, compute_price, instruction])
     }
 
+    /// Instructions for a request built with `ExecutionRequestBuilder`, the signer is both the
+    /// requester and the payer.
+    pub async fn execute(
+        &self,
+        signer: &Pubkey,
+        request: &ExecutionRequestBuilder<'_>,
+    ) -> Result<Vec<Instruction>> {
+        let compute_price_val = self.get_fees(signer).await?;
+        let instruction = request.instruction(signer, signer)?;
+        let compute = ComputeBudgetInstruction::set_compute_unit_limit(20_000);
+        let compute_price = ComputeBudgetInstruction::set_compute_unit_price(compute_price_val);
+        Ok(vec![compute, compute_price, instruction])
+    }
+
+    pub async fn add_provers_v1(
+        &self,
+        signer: &Pubkey,
+        set_id: &str,
+        provers: &[Pubkey],
+    ) -> Result<Vec<Instruction>> {
+        let compute_price_val = self.get_fees(signer).await?;
+        let instruction = instructions::add_provers_v1(signer, signer, set_id, provers)?;
+        let compute = ComputeBudgetInstruction::set_compute_unit_limit(20_000);
+        let compute_price = ComputeBudgetInstruction::set_compute_unit_price(compute_price_val);
+        Ok(vec![compute, compute_price, instruction])
+    }
+
+    pub async fn remove_provers_v1(
+        &self,
+        signer: &Pubkey,
+        set_id: &str,
+        provers: &[Pubkey],
+    ) -> Result<Vec<Instruction>> {
+        let compute_price_val = self.get_fees(signer).await?;
+        let instruction = instructions::remove_provers_v1(signer, signer, set_id, provers)?;
+        let compute = ComputeBudgetInstruction::set_compute_unit_limit(20_000);
+        let compute_price = ComputeBudgetInstruction::set_compute_unit_price(compute_price_val);
+        Ok(vec![compute, compute_price, instruction])
+    }
+
     pub async fn send_txn_standard(
         &self,
         signer: impl Signer,
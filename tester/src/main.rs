@@ -8,7 +8,6 @@ use rand::Rng;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::config::RpcSendTransactionConfig;
 use solana_sdk::commitment_config::CommitmentConfig;
-use solana_sdk::hash::hashv;
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::message::{v0, VersionedMessage};
 use solana_sdk::pubkey::Pubkey;
@@ -17,8 +16,10 @@ use solana_sdk::signer::Signer;
 use solana_sdk::system_program;
 use solana_sdk::transaction::VersionedTransaction;
 
-use bonsol_sdk::instructions::{CallbackConfig, ExecutionConfig, InputRef};
-use bonsol_sdk::{deployment_address, execution_address, BonsolClient, ExitCode, InputType};
+use bonsol_sdk::instructions::{CallbackConfig, ExecutionRequestBuilder, InputRef};
+use bonsol_sdk::{
+    deployment_address, execution_address, input_digest, BonsolClient, ExitCode, InputType,
+};
 use std::env;
 
 #[tokio::main]
@@ -51,30 +52,18 @@ async fn example_sdk_no_callback_test(
     let execution_id = rand_id(16);
     let input_1 = "{\"attestation\":\"test\"}";
     let input_2 = "https://echoserver.dev/server?response=N4IgFgpghgJhBOBnEAuA2mkBjA9gOwBcJCBaAgTwAcIQAaEIgDwIHpKAbKASzxAF0+9AEY4Y5VKArVUDCMzogYUAlBlFEBEAF96G5QFdkKAEwAGU1qA";
-    let input_hash = hashv(&[input_1.as_bytes(), input_2.as_bytes()]);
+    // the guest hashes the private input as served, the url above serves `test`
+    let input_hash = input_digest(&[input_1.as_bytes(), b"test"]);
     println!("Execution expiry {}", expiration);
     let slot = bonsol_client.get_current_slot().await?;
-    let ixs = bonsol_client
-        .execute_v1(
-            &signer.pubkey(),
-            SIMPLE_IMAGE_ID,
-            &execution_id,
-            vec![
-                InputRef::new(InputType::PublicData, input_1.as_bytes()),
-                InputRef::new(InputType::Private, input_2.as_bytes()),
-            ],
-            10000,
-            slot + expiration,
-            ExecutionConfig {
-                verify_input_hash: true,
-                input_hash: Some(input_hash.as_ref()),
-                forward_output: true,
-            },
-            None,
-            None,
-            vec![],
-        )
-        .await?;
+    let request = ExecutionRequestBuilder::new(SIMPLE_IMAGE_ID, &execution_id)
+        .input(InputRef::new(InputType::PublicData, input_1.as_bytes()))
+        .input(InputRef::new(InputType::Private, input_2.as_bytes()))
+        .input_digest(&input_hash)?
+        .tip(10000)
+        .expiry(slot + expiration)?
+        .forward_output(true);
+    let ixs = bonsol_client.execute(&signer.pubkey(), &request).await?;
     let bh = client.get_latest_blockhash().await?;
     let tsx = v0::Message::try_compile(
         &signer.pubkey(),
@@ -123,38 +112,27 @@ async fn example_sdk_test(
     let execution_id = rand_id(16);
     let input_1 = "{\"attestation\":\"test\"}";
     let input_2 = "https://echoserver.dev/server?response=N4IgFgpghgJhBOBnEAuA2mkBjA9gOwBcJCBaAgTwAcIQAaEIgDwIHpKAbKASzxAF0+9AEY4Y5VKArVUDCMzogYUAlBlFEBEAF96G5QFdkKAEwAGU1qA";
-    let input_hash = hashv(&[input_1.as_bytes(), input_2.as_bytes()]);
+    // the guest hashes the private input as served, the url above serves `test`
+    let input_hash = input_digest(&[input_1.as_bytes(), b"test"]);
     println!("Execution expiry {}", expiration);
     let slot = bonsol_client.get_current_slot().await?;
-    let ixs = bonsol_client
-        .execute_v1(
-            &signer.pubkey(),
-            SIMPLE_IMAGE_ID,
-            &execution_id,
-            vec![
-                InputRef::new(InputType::PublicData, input_1.as_bytes()),
-                InputRef::new(InputType::Private, input_2.as_bytes()),
+    let request = ExecutionRequestBuilder::new(SIMPLE_IMAGE_ID, &execution_id)
+        .input(InputRef::new(InputType::PublicData, input_1.as_bytes()))
+        .input(InputRef::new(InputType::Private, input_2.as_bytes()))
+        .input_digest(&input_hash)?
+        .tip(10000)
+        .expiry(slot + expiration)?
+        .forward_output(true)
+        .callback(CallbackConfig {
+            program_id: example_program,
+            instruction_prefix: vec![2],
+            extra_accounts: vec![
+                AccountMeta::new(ea1, false),
+                AccountMeta::new_readonly(ea2, false),
+                AccountMeta::new_readonly(ea3, false),
             ],
-            10000,
-            slot + expiration,
-            ExecutionConfig {
-                verify_input_hash: true,
-                input_hash: Some(input_hash.as_ref()),
-                forward_output: true,
-            },
-            Some(CallbackConfig {
-                program_id: example_program,
-                instruction_prefix: vec![2],
-                extra_accounts: vec![
-                    AccountMeta::new(ea1, false),
-                    AccountMeta::new_readonly(ea2, false),
-                    AccountMeta::new_readonly(ea3, false),
-                ],
-            }),
-            None,
-            vec![],
-        )
-        .await?;
+        })?;
+    let ixs = bonsol_client.execute(&signer.pubkey(), &request).await?;
     let bh = client.get_latest_blockhash().await?;
     let tsx = v0::Message::try_compile(
         &signer.pubkey(),
@@ -203,7 +181,8 @@ async fn example_bonsol_program_test(
         Pubkey::find_program_address(&[execution_id.as_bytes()], &example_program);
     let input_1 = "{\"attestation\":\"test\"}";
     let input_2 = "https://echoserver.dev/server?response=N4IgFgpghgJhBOBnEAuA2mkBjA9gOwBcJCBaAgTwAcIQAaEIgDwIHpKAbKASzxAF0+9AEY4Y5VKArVUDCMzogYUAlBlFEBEAF96G5QFdkKAEwAGU1qA";
-    let input_hash = hashv(&[input_1.as_bytes(), input_2.as_bytes()]);
+    // the guest hashes the private input as served, the url above serves `test`
+    let input_hash = input_digest(&[input_1.as_bytes(), b"test"]);
     let execution_account = execution_address(&requester, execution_id.as_bytes()).0;
     let deployment_account = deployment_address(SIMPLE_IMAGE_ID).0;
     let ix = Instruction {